@@ -3,10 +3,26 @@ use std::io::BufReader;
 
 use serde::Deserialize;
 
+use crate::instrument::interestrate::capfloor::{CapFloorGenerator, CapFloorGeneratorLoader};
+use crate::instrument::interestrate::crosscurrencyswap::{
+    CrossCurrencySwapGenerator,
+    CrossCurrencySwapGeneratorLoader,
+};
 use crate::instrument::interestrate::deposit::DepositGenerator;
 use crate::instrument::interestrate::deposit::DepositGeneratorLoader;
+use crate::instrument::interestrate::fixedratebond::{FixedRateBondGenerator, FixedRateBondGeneratorLoader};
+use crate::instrument::interestrate::floatingratenote::{FloatingRateNoteGenerator, FloatingRateNoteGeneratorLoader};
+use crate::instrument::interestrate::forwardrateagreement::{FraGenerator, FraGeneratorLoader};
+use crate::instrument::interestrate::fximplieddeposit::{
+    FxImpliedDepositGenerator,
+    FxImpliedDepositGeneratorLoader,
+};
 use crate::instrument::interestrate::interestrateswap::InterestRateSwapGenerator;
 use crate::instrument::interestrate::interestrateswap::InterestRateSwapGeneratorLoader;
+use crate::instrument::interestrate::overnightindexfuture::{
+    OvernightIndexFutureGenerator,
+    OvernightIndexFutureGeneratorLoader,
+};
 use crate::instrument::leg::legcharactersgeneratorloader::InterestRateInstrumentSupports;
 use crate::interestrate::index::interestrateindex::InterestRateIndex;
 use crate::interestrate::index::interestrateindexmanager::InterestRateIndexLoader;
@@ -15,9 +31,19 @@ use crate::manager::manager::{
     JsonLoader,
     ManagerBuilder,
 };
-use crate::manager::managererror::ManagerError;
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
+use crate::market::fxmarket::{FxMarketLoader, FxMatket};
 use crate::market::market::Market;
 use crate::market::singlecurrencymarket::SingleCurrencyMarketLoader;
+use crate::model::interestrate::smithwilsoncurve::{
+    SmithWilsonCurveGenerator,
+    SmithWilsonCurveGeneratorLoader,
+};
+use crate::model::interestrate::stepforwardcurve::{
+    MeetingDateCurveGenerator,
+    MeetingDateCurveGeneratorLoader,
+};
 use crate::time::calendar::holidaycalendar::HolidayCalendar;
 use crate::time::calendar::holidaycalendarmanager::HolidayCalendarLoader;
 use crate::time::daycounter::daycounter::DayCounterGenerator;
@@ -29,6 +55,13 @@ use crate::time::schedule::schedulegeneratormanager::ScheduleGeneratorManager;
 pub struct InterestRateInstrumentGeneratorCollection {
     pub deposit_generator_manager: FrozenManager<DepositGenerator>,
     pub swap_generator_manager:    FrozenManager<InterestRateSwapGenerator>,
+    pub future_generator_manager:  FrozenManager<OvernightIndexFutureGenerator>,
+    pub fra_generator_manager:     FrozenManager<FraGenerator>,
+    pub bond_generator_manager:    FrozenManager<FixedRateBondGenerator>,
+    pub frn_generator_manager:     FrozenManager<FloatingRateNoteGenerator>,
+    pub xccy_swap_generator_manager: FrozenManager<CrossCurrencySwapGenerator>,
+    pub fx_implied_deposit_generator_manager: FrozenManager<FxImpliedDepositGenerator>,
+    pub cap_floor_generator_manager: FrozenManager<CapFloorGenerator>,
 }
 
 
@@ -37,15 +70,51 @@ pub struct InstrumentGeneratorCollection {
 }
 
 
+pub struct InterestRateCurveGeneratorCollection {
+    pub smith_wilson_generator_manager: FrozenManager<SmithWilsonCurveGenerator>,
+    pub meeting_date_generator_manager: FrozenManager<MeetingDateCurveGenerator>,
+}
+
+
 #[derive(Deserialize)]
 struct ConfigurationJsonProp {
     holiday_calendar:      Vec<serde_json::Value>,
     schedule:              Vec<serde_json::Value>,
     day_count:             Vec<serde_json::Value>,
     market:                Vec<serde_json::Value>,
+    /// 選填，省略時為空。
+    #[serde(default)]
+    fx_market:             Vec<serde_json::Value>,
     interest_rate_index:   Vec<serde_json::Value>,
     deposit_generator:     Vec<serde_json::Value>,
     swap_generator:        Vec<serde_json::Value>,
+    /// 選填，省略時為空。
+    #[serde(default)]
+    future_generator:      Vec<serde_json::Value>,
+    /// 選填，省略時為空。
+    #[serde(default)]
+    fra_generator:         Vec<serde_json::Value>,
+    /// 選填，省略時為空。
+    #[serde(default)]
+    bond_generator:        Vec<serde_json::Value>,
+    /// 選填，省略時為空。
+    #[serde(default)]
+    frn_generator:         Vec<serde_json::Value>,
+    /// 選填，省略時為空。
+    #[serde(default)]
+    xccy_swap_generator:   Vec<serde_json::Value>,
+    /// 選填，省略時為空。
+    #[serde(default)]
+    fx_implied_deposit_generator: Vec<serde_json::Value>,
+    /// 選填，省略時為空。
+    #[serde(default)]
+    cap_floor_generator:   Vec<serde_json::Value>,
+    /// 選填，省略時為空。
+    #[serde(default)]
+    smith_wilson_curve_generator: Vec<serde_json::Value>,
+    /// 選填，省略時為空。
+    #[serde(default)]
+    meeting_date_curve_generator: Vec<serde_json::Value>,
 }
 
 /// 系統設定容器。
@@ -60,15 +129,21 @@ struct ConfigurationJsonProp {
 /// 1. `holiday_calendar`
 /// 2. `schedule` / `day_count`（互不相依，順序可調換）
 /// 3. `interest_rate_index`（依賴 calendar、day_count）
-/// 4. `market`（依賴 calendar）
-/// 5. `deposit_generator` / `swap_generator`（依賴 market、calendar、schedule、day_count、index）
+/// 4. `market` / `fx_market`（依賴 calendar；FX market 同時以 `dyn Market` 註冊）
+/// 5. `deposit_generator` / `swap_generator` / `future_generator` / `fra_generator` /
+///    `bond_generator` / `frn_generator` / `cap_floor_generator` / `xccy_swap_generator` /
+///    `fx_implied_deposit_generator`
+///    （依賴 market、calendar、schedule、day_count、index；後兩者另依賴 fx_market）
+/// 6. `smith_wilson_curve_generator` / `meeting_date_curve_generator`（依賴 day_count）
 pub struct Configuration {
     holiday_calendar_manager:      FrozenManager<dyn HolidayCalendar + Send + Sync>,
     schedule_generator_manager:    FrozenManager<ScheduleGenerator>,
     day_counter_generator_manager: FrozenManager<DayCounterGenerator>,
     market_manager:                FrozenManager<dyn Market>,
+    fx_market_manager:             FrozenManager<FxMatket>,
     interest_rate_index_manager:   FrozenManager<dyn InterestRateIndex + Send + Sync>,
     instrument_generator_collection: InstrumentGeneratorCollection,
+    curve_generator_collection:      InterestRateCurveGeneratorCollection,
 }
 
 impl Configuration {
@@ -104,6 +179,22 @@ impl Configuration {
                 &json_prop.market,
                 &&holiday_calendar_manager,
             )?;
+
+        let mut fx_mkt_builder: ManagerBuilder<FxMatket> = ManagerBuilder::new();
+        FxMarketLoader
+            .insert_obj_from_json_vec(
+                &mut fx_mkt_builder,
+                &json_prop.fx_market,
+                &&holiday_calendar_manager,
+            )?;
+        let fx_market_manager = fx_mkt_builder.build();
+
+        // FX market 也是 Market：以同名註冊，讓商品的 P&L market 可以指向 FX market
+        for json_value in &json_prop.fx_market {
+            let named: Named<serde_json::Value> = parse_json_value(json_value.clone())?;
+            let fx_market = fx_market_manager.get(&named.name)?;
+            mkt_builder.insert(named.name, fx_market);
+        }
         let market_manager = mkt_builder.build();
 
         // ── 5. Interest Rate Index ────────────────────────────────────────────
@@ -145,20 +236,116 @@ impl Configuration {
             )?;
         let swap_generator_manager = swap_builder.build();
 
+        let mut future_builder: ManagerBuilder<OvernightIndexFutureGenerator> = ManagerBuilder::new();
+        OvernightIndexFutureGeneratorLoader
+            .insert_obj_from_json_vec(
+                &mut future_builder,
+                &json_prop.future_generator,
+                &ir_supports,
+            )?;
+        let future_generator_manager = future_builder.build();
+
+        let mut fra_builder: ManagerBuilder<FraGenerator> = ManagerBuilder::new();
+        FraGeneratorLoader
+            .insert_obj_from_json_vec(
+                &mut fra_builder,
+                &json_prop.fra_generator,
+                &ir_supports,
+            )?;
+        let fra_generator_manager = fra_builder.build();
+
+        let mut bond_builder: ManagerBuilder<FixedRateBondGenerator> = ManagerBuilder::new();
+        FixedRateBondGeneratorLoader
+            .insert_obj_from_json_vec(
+                &mut bond_builder,
+                &json_prop.bond_generator,
+                &ir_supports,
+            )?;
+        let bond_generator_manager = bond_builder.build();
+
+        let mut frn_builder: ManagerBuilder<FloatingRateNoteGenerator> = ManagerBuilder::new();
+        FloatingRateNoteGeneratorLoader
+            .insert_obj_from_json_vec(
+                &mut frn_builder,
+                &json_prop.frn_generator,
+                &ir_supports,
+            )?;
+        let frn_generator_manager = frn_builder.build();
+
+        let mut cap_floor_builder: ManagerBuilder<CapFloorGenerator> = ManagerBuilder::new();
+        CapFloorGeneratorLoader
+            .insert_obj_from_json_vec(
+                &mut cap_floor_builder,
+                &json_prop.cap_floor_generator,
+                &ir_supports,
+            )?;
+        let cap_floor_generator_manager = cap_floor_builder.build();
+
+        let mut xccy_builder: ManagerBuilder<CrossCurrencySwapGenerator> = ManagerBuilder::new();
+        CrossCurrencySwapGeneratorLoader
+            .insert_obj_from_json_vec(
+                &mut xccy_builder,
+                &json_prop.xccy_swap_generator,
+                &(&ir_supports, &fx_market_manager),
+            )?;
+        let xccy_swap_generator_manager = xccy_builder.build();
+
+        let mut fx_implied_builder: ManagerBuilder<FxImpliedDepositGenerator> = ManagerBuilder::new();
+        FxImpliedDepositGeneratorLoader
+            .insert_obj_from_json_vec(
+                &mut fx_implied_builder,
+                &json_prop.fx_implied_deposit_generator,
+                &(&market_manager, &fx_market_manager),
+            )?;
+        let fx_implied_deposit_generator_manager = fx_implied_builder.build();
+
         let instrument_generator_collection = InstrumentGeneratorCollection {
             interest_rate: InterestRateInstrumentGeneratorCollection {
                 deposit_generator_manager,
                 swap_generator_manager,
+                future_generator_manager,
+                fra_generator_manager,
+                bond_generator_manager,
+                frn_generator_manager,
+                xccy_swap_generator_manager,
+                fx_implied_deposit_generator_manager,
+                cap_floor_generator_manager,
             },
         };
 
+        // ── 7. Curve Generators ───────────────────────────────────────────────
+        let mut sw_builder: ManagerBuilder<SmithWilsonCurveGenerator> = ManagerBuilder::new();
+        SmithWilsonCurveGeneratorLoader
+            .insert_obj_from_json_vec(
+                &mut sw_builder,
+                &json_prop.smith_wilson_curve_generator,
+                &day_counter_generator_manager,
+            )?;
+        let smith_wilson_generator_manager = sw_builder.build();
+
+        let mut meeting_builder: ManagerBuilder<MeetingDateCurveGenerator> = ManagerBuilder::new();
+        MeetingDateCurveGeneratorLoader
+            .insert_obj_from_json_vec(
+                &mut meeting_builder,
+                &json_prop.meeting_date_curve_generator,
+                &day_counter_generator_manager,
+            )?;
+        let meeting_date_generator_manager = meeting_builder.build();
+
+        let curve_generator_collection = InterestRateCurveGeneratorCollection {
+            smith_wilson_generator_manager,
+            meeting_date_generator_manager,
+        };
+
         Ok(Configuration {
             holiday_calendar_manager,
             schedule_generator_manager,
             day_counter_generator_manager,
             market_manager,
+            fx_market_manager,
             interest_rate_index_manager,
             instrument_generator_collection,
+            curve_generator_collection,
         })
     }
 
@@ -178,6 +365,10 @@ impl Configuration {
         &self.market_manager
     }
 
+    pub fn fx_market_manager(&self) -> &FrozenManager<FxMatket> {
+        &self.fx_market_manager
+    }
+
     pub fn interest_rate_index_manager(
         &self,
     ) -> &FrozenManager<dyn InterestRateIndex + Send + Sync> {
@@ -188,6 +379,10 @@ impl Configuration {
         &self.instrument_generator_collection
     }
 
+    pub fn curve_generator_collection(&self) -> &InterestRateCurveGeneratorCollection {
+        &self.curve_generator_collection
+    }
+
     /// 取出利率商品的 [`InterestRateInstrumentSupports`]，供 quote loader 使用。
     pub fn interest_rate_instrument_supports(&self) -> InterestRateInstrumentSupports {
         (
@@ -0,0 +1,136 @@
+// ── fxforward.rs ──────────────────────────────────────────────────────────────
+//
+// FX outright forward：於交割日 T 以約定匯率 K（ccy2 per ccy1）交換兩幣別本金。
+//
+// # 方向
+//
+// Buy = 買入 ccy1、賣出 ccy2；令 p 為 position 方向，N 為 ccy1 名目本金：
+//
+//   ccy1：T   +p N
+//   ccy2：T   −p N K
+//
+// # 評價（幣別 ccy2，見 FxForwardPricer）
+//
+//   V(h) = p N (F(T) − K) D_d(T) / D_d(h)
+//
+// F(T) 由 FxForwardCurve（spot + domestic / foreign discount curve）推算。
+// 交割日已過時兩筆本金皆已交割，ccy1 部位以 horizon 匯率 X₀ 換算計入 econ P&L。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::instrument::instrument::{CurveFunction, Instrument, Position};
+use crate::market::fxmarket::FxMatket;
+use crate::market::market::Market;
+use crate::pricingcondition::PricingCondition;
+use crate::time::period::Period;
+
+
+/// FX 商品共用的 curve 對應：ccy1 → ReceiveForward，ccy2 → PayForward / ProfitAndLossDiscount。
+pub(crate) fn fx_curve_name_map(fx_market: &FxMatket) -> HashMap<CurveFunction, String> {
+    let mut curve_name_map: HashMap<CurveFunction, String> = HashMap::new();
+    curve_name_map.insert(
+        CurveFunction::ProfitAndLossDiscount,
+        fx_market.domestic_discount_curve_name().to_string(),
+    );
+    curve_name_map.insert(CurveFunction::ReceiveForward, fx_market.foreign_discount_curve_name().to_string());
+    curve_name_map.insert(CurveFunction::PayForward, fx_market.domestic_discount_curve_name().to_string());
+    curve_name_map
+}
+
+/// 交割日是否仍屬未來 flow（與各利率商品的 horizon flow 規則一致）。
+pub(crate) fn is_projected(date: NaiveDate, pricing_condition: &PricingCondition) -> bool {
+    let horizon = *pricing_condition.horizon();
+    date > horizon || (date == horizon && *pricing_condition.include_horizon_flow())
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxForward
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FxForward {
+    position:               Position,
+    fx_market:              Arc<FxMatket>,
+    profit_and_loss_market: Arc<dyn Market>,
+    /// ccy1 名目本金。
+    nominal:                f64,
+    /// 約定匯率（ccy2 per ccy1）。
+    contract_rate:          f64,
+    value_date:             NaiveDate,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl FxForward {
+    pub fn new(
+        position:      Position,
+        fx_market:     Arc<FxMatket>,
+        nominal:       f64,
+        contract_rate: f64,
+        value_date:    NaiveDate,
+    ) -> Self {
+        let curve_name_map = fx_curve_name_map(&fx_market);
+        let profit_and_loss_market: Arc<dyn Market> = fx_market.clone();
+        Self {
+            position,
+            fx_market,
+            profit_and_loss_market,
+            nominal,
+            contract_rate,
+            value_date,
+            curve_name_map,
+        }
+    }
+
+    /// 交割日為 horizon 的 spot date 加上 `tenor`（見 `FxMatket::value_date`）。
+    pub fn with_tenor(
+        position:      Position,
+        fx_market:     Arc<FxMatket>,
+        nominal:       f64,
+        contract_rate: f64,
+        trade_date:    NaiveDate,
+        tenor:         Period,
+    ) -> Self {
+        let value_date = fx_market.value_date(trade_date, tenor);
+        Self::new(position, fx_market, nominal, contract_rate, value_date)
+    }
+
+    pub fn fx_market(&self) -> &Arc<FxMatket> { &self.fx_market }
+    pub fn nominal(&self) -> f64 { self.nominal }
+    pub fn contract_rate(&self) -> f64 { self.contract_rate }
+    pub fn value_date(&self) -> NaiveDate { self.value_date }
+
+    /// ccy2 名目本金 N K。
+    pub fn counter_nominal(&self) -> f64 {
+        self.nominal * self.contract_rate
+    }
+
+    pub fn sign(&self) -> f64 {
+        self.position as i32 as f64
+    }
+}
+
+
+impl Instrument for FxForward {
+    fn max_date(&self) -> NaiveDate {
+        self.value_date
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
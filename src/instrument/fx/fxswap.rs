@@ -0,0 +1,128 @@
+// ── fxswap.rs ─────────────────────────────────────────────────────────────────
+//
+// FX swap：方向相反的 near / far 兩筆 outright forward。
+//
+// Buy = near 買入 ccy1、far 賣出 ccy1（buy / sell swap）；Sell 則相反。
+// near leg 可為 spot（value date = spot date），far leg 交割日必須晚於 near leg。
+//
+// swap points（未乘上報價的 point 單位）：
+//   約定：K_far − K_near
+//   市場：F(T_far) − F(T_near)
+//
+// 評價為兩筆 FxForward 的加總（見 FxForwardPricer）。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::instrument::fx::fxforward::{fx_curve_name_map, FxForward};
+use crate::instrument::instrument::{CurveFunction, Instrument, Position};
+use crate::market::fxmarket::FxMatket;
+use crate::market::market::Market;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxSwapError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum FxSwapError {
+    #[error("FX swap far value date {far_value_date} must be after near value date {near_value_date}")]
+    FarNotAfterNear {
+        near_value_date: NaiveDate,
+        far_value_date:  NaiveDate,
+    },
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxSwapLeg
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 單一 leg 的約定條件：ccy1 名目本金、約定匯率（ccy2 per ccy1）、交割日。
+#[derive(Debug, Clone, Copy)]
+pub struct FxSwapLeg {
+    pub nominal:       f64,
+    pub contract_rate: f64,
+    pub value_date:    NaiveDate,
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxSwap
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FxSwap {
+    position:               Position,
+    profit_and_loss_market: Arc<dyn Market>,
+    near:                   FxForward,
+    far:                    FxForward,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl FxSwap {
+    /// # Errors
+    ///
+    /// far leg 交割日不晚於 near leg。
+    pub fn new(
+        position:  Position,
+        fx_market: Arc<FxMatket>,
+        near:      FxSwapLeg,
+        far:       FxSwapLeg,
+    ) -> Result<Self, FxSwapError> {
+        if far.value_date <= near.value_date {
+            return Err(FxSwapError::FarNotAfterNear {
+                near_value_date: near.value_date,
+                far_value_date:  far.value_date,
+            });
+        }
+
+        let far_position = match position {
+            Position::Buy  => Position::Sell,
+            Position::Sell => Position::Buy,
+        };
+        let curve_name_map = fx_curve_name_map(&fx_market);
+        let profit_and_loss_market: Arc<dyn Market> = fx_market.clone();
+        Ok(Self {
+            position,
+            profit_and_loss_market,
+            near: FxForward::new(position, fx_market.clone(), near.nominal, near.contract_rate, near.value_date),
+            far:  FxForward::new(far_position, fx_market, far.nominal, far.contract_rate, far.value_date),
+            curve_name_map,
+        })
+    }
+
+    pub fn fx_market(&self) -> &Arc<FxMatket> { self.near.fx_market() }
+    pub fn near(&self) -> &FxForward { &self.near }
+    pub fn far(&self) -> &FxForward { &self.far }
+
+    /// 約定 swap points：K_far − K_near。
+    pub fn contract_swap_points(&self) -> f64 {
+        self.far.contract_rate() - self.near.contract_rate()
+    }
+}
+
+
+impl Instrument for FxSwap {
+    fn max_date(&self) -> NaiveDate {
+        self.far.value_date()
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
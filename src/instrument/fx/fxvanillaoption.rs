@@ -0,0 +1,167 @@
+// ── fxvanillaoption.rs ────────────────────────────────────────────────────────
+//
+// FX European option（physical delivery）：到期日 T_e 依 strike K（ccy2 per ccy1）
+// 決定是否履約，履約後於交割日 T_d 交換本金。
+//
+// Call = 以 K 買入 ccy1 的權利；Put = 以 K 賣出 ccy1 的權利；N 為 ccy1 名目本金。
+// 評價見 FxVanillaOptionPricer（Garman-Kohlhagen）。
+//
+// # 日期（FxVanillaOptionGenerator）
+//
+// 由 tenor 產生到期日與交割日時委派 OptionDateGenerator（Clark §1.5）：
+//   - Days / Weeks：expiry = horizon + tenor，delivery = expiry 的 spot date
+//   - Months / Years：delivery = spot + tenor（end-end），expiry = delivery 往前推 spot lag
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::instrument::fx::fxforward::fx_curve_name_map;
+use crate::instrument::instrument::{CurveFunction, Instrument, OptionType, Position};
+use crate::market::fxmarket::FxMatket;
+use crate::market::market::Market;
+use crate::time::businessdayadjuster::{BusinessDayAdjuster, BusinessDayConvention};
+use crate::time::optiondategenerator::{ExpiryRule, OptionDateGenerator};
+use crate::time::period::{Period, TimeUnit};
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxVanillaOption
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FxVanillaOption {
+    position:               Position,
+    fx_market:              Arc<FxMatket>,
+    profit_and_loss_market: Arc<dyn Market>,
+    option_type:            OptionType,
+    /// 履約匯率（ccy2 per ccy1）。
+    strike:                 f64,
+    /// ccy1 名目本金。
+    nominal:                f64,
+    expiry_date:            NaiveDate,
+    delivery_date:          NaiveDate,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl FxVanillaOption {
+    pub fn new(
+        position:      Position,
+        fx_market:     Arc<FxMatket>,
+        option_type:   OptionType,
+        strike:        f64,
+        nominal:       f64,
+        expiry_date:   NaiveDate,
+        delivery_date: NaiveDate,
+    ) -> Self {
+        let curve_name_map = fx_curve_name_map(&fx_market);
+        let profit_and_loss_market: Arc<dyn Market> = fx_market.clone();
+        Self {
+            position,
+            fx_market,
+            profit_and_loss_market,
+            option_type,
+            strike,
+            nominal,
+            expiry_date,
+            delivery_date,
+            curve_name_map,
+        }
+    }
+
+    pub fn fx_market(&self) -> &Arc<FxMatket> { &self.fx_market }
+    pub fn option_type(&self) -> OptionType { self.option_type }
+    pub fn strike(&self) -> f64 { self.strike }
+    pub fn nominal(&self) -> f64 { self.nominal }
+    pub fn expiry_date(&self) -> NaiveDate { self.expiry_date }
+    pub fn delivery_date(&self) -> NaiveDate { self.delivery_date }
+
+    pub fn sign(&self) -> f64 {
+        self.position as i32 as f64
+    }
+}
+
+
+impl Instrument for FxVanillaOption {
+    fn max_date(&self) -> NaiveDate {
+        self.delivery_date
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        false
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxVanillaOptionGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FxVanillaOptionGenerator {
+    fx_market:      Arc<FxMatket>,
+    date_generator: OptionDateGenerator,
+}
+
+impl FxVanillaOptionGenerator {
+    pub fn new(fx_market: Arc<FxMatket>, date_generator: OptionDateGenerator) -> Self {
+        Self { fx_market, date_generator }
+    }
+
+    /// FX 市場慣例：Days / Weeks 由到期日推交割日（Following），
+    /// Months / Years 由交割日推到期日（Modified Following + end-end）。
+    pub fn with_defaults(fx_market: Arc<FxMatket>) -> Self {
+        let market: Arc<dyn Market> = fx_market.clone();
+        let date_generator = OptionDateGenerator::new(
+            market,
+            ExpiryRule::ExpiryToDelivery,
+            BusinessDayAdjuster::new(BusinessDayConvention::Following, false),
+            ExpiryRule::DeliveryToExpiry,
+            BusinessDayAdjuster::new(BusinessDayConvention::ModifiedFollowing, true),
+            HashSet::from([TimeUnit::Days, TimeUnit::Weeks]),
+        );
+        Self::new(fx_market, date_generator)
+    }
+
+    pub fn fx_market(&self) -> &Arc<FxMatket> { &self.fx_market }
+    pub fn date_generator(&self) -> &OptionDateGenerator { &self.date_generator }
+
+    pub fn expiry_date(&self, horizon: NaiveDate, tenor: Period) -> NaiveDate {
+        self.date_generator.generate_expiry(horizon, tenor)
+    }
+
+    pub fn delivery_date(&self, horizon: NaiveDate, tenor: Period) -> NaiveDate {
+        self.date_generator.generate_delivery(horizon, tenor)
+    }
+
+    pub fn generate_with_tenor(
+        &self,
+        position:    Position,
+        option_type: OptionType,
+        strike:      f64,
+        nominal:     f64,
+        horizon:     NaiveDate,
+        tenor:       Period,
+    ) -> FxVanillaOption {
+        FxVanillaOption::new(
+            position,
+            self.fx_market.clone(),
+            option_type,
+            strike,
+            nominal,
+            self.expiry_date(horizon, tenor),
+            self.delivery_date(horizon, tenor),
+        )
+    }
+}
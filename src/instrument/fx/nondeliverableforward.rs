@@ -0,0 +1,108 @@
+// ── nondeliverableforward.rs ──────────────────────────────────────────────────
+//
+// 無本金交割遠期（NDF）：不交換本金，只在交割日 T 以 ccy2 結算差額。
+//
+// # 日期
+//
+// fixing date 由交割日以 FX market 的 expiry calendar 往前推 settlement_days 個營業日
+// （例如 USDTWD：T − 2 個台北營業日）。
+//
+// # 結算（幣別 ccy2）
+//
+// 令 p 為 position 方向（Buy = 買入 ccy1），N 為 ccy1 名目本金，K 為約定匯率，
+// R 為 fixing source 於 fixing date 公布的匯率：
+//
+//   T   p N (R − K)
+//
+// # Fixing
+//
+// fixing 以 `fixing_source` 為 key 至 FxMarketData 查詢（例如 "TAIFX1"）；
+// fixing date 尚未到達時以 F(spot date of fixing date) 估計（見 FxForwardPricer）。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::instrument::fx::fxforward::fx_curve_name_map;
+use crate::instrument::instrument::{CurveFunction, Instrument, Position};
+use crate::market::fxmarket::FxMatket;
+use crate::market::market::Market;
+
+
+pub struct NonDeliverableForward {
+    position:               Position,
+    fx_market:              Arc<FxMatket>,
+    profit_and_loss_market: Arc<dyn Market>,
+    /// ccy1 名目本金。
+    nominal:                f64,
+    /// 約定匯率（ccy2 per ccy1）。
+    contract_rate:          f64,
+    fixing_date:            NaiveDate,
+    value_date:             NaiveDate,
+    fixing_source:          String,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl NonDeliverableForward {
+    pub fn new(
+        position:      Position,
+        fx_market:     Arc<FxMatket>,
+        nominal:       f64,
+        contract_rate: f64,
+        value_date:    NaiveDate,
+        fixing_source: impl Into<String>,
+    ) -> Self {
+        let fixing_date = fx_market
+            .expiry_calendar()
+            .shift_n_business_day(value_date, -(fx_market.settlement_days() as i32));
+        let curve_name_map = fx_curve_name_map(&fx_market);
+        let profit_and_loss_market: Arc<dyn Market> = fx_market.clone();
+        Self {
+            position,
+            fx_market,
+            profit_and_loss_market,
+            nominal,
+            contract_rate,
+            fixing_date,
+            value_date,
+            fixing_source: fixing_source.into(),
+            curve_name_map,
+        }
+    }
+
+    pub fn fx_market(&self) -> &Arc<FxMatket> { &self.fx_market }
+    pub fn nominal(&self) -> f64 { self.nominal }
+    pub fn contract_rate(&self) -> f64 { self.contract_rate }
+    pub fn fixing_date(&self) -> NaiveDate { self.fixing_date }
+    pub fn value_date(&self) -> NaiveDate { self.value_date }
+    pub fn fixing_source(&self) -> &str { &self.fixing_source }
+
+    /// 以 fixing 匯率 R 計算的 ccy2 結算金額 p N (R − K)。
+    pub fn settlement_amount(&self, fixing_rate: f64) -> f64 {
+        self.position as i32 as f64 * self.nominal * (fixing_rate - self.contract_rate)
+    }
+}
+
+
+impl Instrument for NonDeliverableForward {
+    fn max_date(&self) -> NaiveDate {
+        self.value_date
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
@@ -17,6 +17,24 @@ pub enum Position {
 }
 
 
+/// 選擇權類型；call = 買入 underlying 的權利。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+pub enum OptionType {
+    Call,
+    Put,
+}
+
+impl OptionType {
+    /// ω：Call = +1，Put = −1。
+    pub fn sign(&self) -> f64 {
+        match self {
+            OptionType::Call =>  1.0,
+            OptionType::Put  => -1.0,
+        }
+    }
+}
+
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CurveFunction {
     ReceiveForward,
@@ -0,0 +1,418 @@
+// ── capfloor.rs ───────────────────────────────────────────────────────────────
+//
+// Cap / floor：以 FloatingRateLegCharacters 的每一期為一個 caplet / floorlet。
+//
+// 第 i 期（計息期間 [S_i, E_i]、付款日 T_i、year fraction τ_i）的 payoff：
+//
+//   N τ_i ω (R_i − K)⁺，R_i = leverage · L_i + spread，ω = +1（cap）/ −1（floor）
+//
+// 等價於 index 上的選擇權：N τ_i leverage · ω (L_i − K′)⁺，K′ = (K − spread) / leverage
+// （leverage 須為正）。payoff 以 Simple 計息，不受 leg compounding 影響。
+//
+// # Index 與到期日
+//
+//   TermRate（IBOR / term SOFR）：L_i 於 fixing date 決定，caplet 到期日 = fixing date
+//   CompoundingRate（SOFR 後置複利）：L_i 為 [S_i, E_i] 逐日複利，至 E_i 才完全決定，
+//     caplet 到期日 = E_i；計息期間內 vol 逐日遞減（見 CapFloorPricer 的 variance time）
+//
+// 已決定的 caplet 依 fixing（或已實現的複利利率）計算內含價值。
+//
+// # 第一期
+//
+// Term rate cap 的第一期通常在交易日即已 fixing，市場報價不含該期（exclude_first_caplet）；
+// 後置複利 cap 的第一期仍具選擇權價值，一般包含在內。
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::instrument::instrument::{CurveFunction, Instrument, OptionType, Position};
+use crate::instrument::leg::fixingratecalculator::termratecalculator::{
+    StubRateConvention,
+    TermRateCalculatorGenerator,
+};
+use crate::instrument::leg::floatingratelegcharacters::{
+    FloatingRateLegCharacters,
+    FloatingRateLegCharactersGenerator,
+};
+use crate::instrument::leg::legcharacters::{LegCharacters, LegCharactersGenerator, LegCharactersSetter};
+use crate::instrument::leg::legcharactersgeneratorloader::InterestRateInstrumentSupports;
+use crate::interestrate::compounding::Compounding;
+use crate::interestrate::index::interestrateindex::InterestRateIndexType;
+use crate::manager::manager::{JsonLoader, ManagerBuilder};
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
+use crate::market::market::Market;
+use crate::time::period::Period;
+use crate::time::schedule::schedule::Schedule;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Conventions
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapFloorType {
+    Cap,
+    Floor,
+}
+
+impl CapFloorType {
+    /// Cap = 利率的 call，Floor = put。
+    pub fn option_type(&self) -> OptionType {
+        match self {
+            CapFloorType::Cap   => OptionType::Call,
+            CapFloorType::Floor => OptionType::Put,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CapFloorConventions {
+    pub nominal:              f64,
+    /// 是否排除第一期（term rate cap 的市場慣例）。
+    pub exclude_first_caplet: bool,
+}
+
+impl Default for CapFloorConventions {
+    fn default() -> Self {
+        Self {
+            nominal:              DEFAULT_CAP_FLOOR_NOMINAL,
+            exclude_first_caplet: false,
+        }
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CapFloor
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct CapFloor {
+    position:               Position,
+    cap_floor_type:         CapFloorType,
+    strike:                 f64,
+    profit_and_loss_market: Arc<dyn Market>,
+    leg_characters:         FloatingRateLegCharacters,
+    conventions:            CapFloorConventions,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl CapFloor {
+    pub fn new(
+        position:               Position,
+        cap_floor_type:         CapFloorType,
+        strike:                 f64,
+        profit_and_loss_market: Arc<dyn Market>,
+        leg_characters:         FloatingRateLegCharacters,
+        conventions:            CapFloorConventions,
+    ) -> Self {
+        let reference_curve_name = leg_characters.index().reference_curve_name().clone();
+        let mut curve_name_map: HashMap<CurveFunction, String> = HashMap::new();
+        curve_name_map.insert(
+            CurveFunction::ProfitAndLossDiscount,
+            profit_and_loss_market.discount_curve_name().to_string(),
+        );
+        curve_name_map.insert(CurveFunction::ReceiveForward, reference_curve_name);
+
+        Self {
+            position,
+            cap_floor_type,
+            strike,
+            profit_and_loss_market,
+            leg_characters,
+            conventions,
+            curve_name_map,
+        }
+    }
+
+    pub fn cap_floor_type(&self) -> CapFloorType { self.cap_floor_type }
+    pub fn strike(&self) -> f64 { self.strike }
+    pub fn leg_characters(&self) -> &FloatingRateLegCharacters { &self.leg_characters }
+    pub fn conventions(&self) -> &CapFloorConventions { &self.conventions }
+    pub fn nominal(&self) -> f64 { self.conventions.nominal }
+
+    pub fn sign(&self) -> f64 {
+        self.position as i32 as f64
+    }
+
+    fn schedule(&self) -> &Schedule {
+        self.leg_characters.generic_characters().schedule()
+    }
+
+    /// 納入評價的 caplet 期數。
+    pub fn caplet_indices(&self) -> Range<usize> {
+        let first = usize::from(self.conventions.exclude_first_caplet);
+        first.min(self.schedule().len())..self.schedule().len()
+    }
+
+    /// index 為逐日複利（後置）時為 true。
+    pub fn is_in_arrears(&self) -> bool {
+        self.leg_characters.index().index_type() == InterestRateIndexType::CompoundingRate
+    }
+
+    /// index 上的 strike K′ = (K − spread) / leverage。
+    pub fn index_strike(&self) -> f64 {
+        (self.strike - self.leg_characters.spread()) / self.leg_characters.leverage()
+    }
+
+    pub fn accrual_start_date(&self, i: usize) -> NaiveDate {
+        self.schedule().schedule_periods()[i].calculation_period().start_date()
+    }
+
+    pub fn accrual_end_date(&self, i: usize) -> NaiveDate {
+        self.schedule().schedule_periods()[i].calculation_period().end_date()
+    }
+
+    pub fn payment_date(&self, i: usize) -> NaiveDate {
+        self.schedule().schedule_periods()[i].payment_date()
+    }
+
+    /// caplet 利率完全決定的日期：term rate 為 fixing date，後置複利為計息期末。
+    pub fn caplet_expiry_date(&self, i: usize) -> NaiveDate {
+        if self.is_in_arrears() {
+            self.accrual_end_date(i)
+        } else {
+            self.schedule().schedule_periods()[i].fixing_date()
+        }
+    }
+
+    /// 第一個納入評價的 caplet 起息日。
+    pub fn start_date(&self) -> NaiveDate {
+        self.accrual_start_date(self.caplet_indices().start)
+    }
+
+    pub fn maturity_date(&self) -> NaiveDate {
+        self.leg_characters.generic_characters().maturity_date()
+    }
+}
+
+
+impl Instrument for CapFloor {
+    fn max_date(&self) -> NaiveDate {
+        self.leg_characters.max_date()
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        false
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CapFloorGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// leverage / spread 存在 leg generator 的 setter；strike 與 cap / floor 於產生時指定。
+
+pub struct CapFloorGenerator {
+    profit_and_loss_market: Arc<dyn Market>,
+    leg_character_genrator: Arc<FloatingRateLegCharactersGenerator>,
+    conventions:            CapFloorConventions,
+}
+
+impl CapFloorGenerator {
+    pub fn new(
+        profit_and_loss_market: Arc<dyn Market>,
+        leg_character_genrator: Arc<FloatingRateLegCharactersGenerator>,
+        conventions:            CapFloorConventions,
+    ) -> Self {
+        Self { profit_and_loss_market, leg_character_genrator, conventions }
+    }
+
+    pub fn with_defaults(
+        profit_and_loss_market: Arc<dyn Market>,
+        leg_character_genrator: Arc<FloatingRateLegCharactersGenerator>,
+    ) -> Self {
+        Self::new(profit_and_loss_market, leg_character_genrator, CapFloorConventions::default())
+    }
+
+    pub fn profit_and_loss_market(&self) -> &Arc<dyn Market> { &self.profit_and_loss_market }
+    pub fn leg_character_genrator(&self) -> &Arc<FloatingRateLegCharactersGenerator> { &self.leg_character_genrator }
+    pub fn conventions(&self) -> &CapFloorConventions { &self.conventions }
+
+    fn build(
+        &self,
+        position:       Position,
+        cap_floor_type: CapFloorType,
+        strike:         f64,
+        schedule:       Schedule,
+    ) -> CapFloor {
+        CapFloor::new(
+            position,
+            cap_floor_type,
+            strike,
+            self.profit_and_loss_market.clone(),
+            self.leg_character_genrator.generate_floating_with_schedule(schedule),
+            self.conventions,
+        )
+    }
+
+    pub fn generate_with_maturity_date(
+        &self,
+        position:       Position,
+        cap_floor_type: CapFloorType,
+        strike:         f64,
+        trade_date:     NaiveDate,
+        maturity_date:  NaiveDate,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<CapFloor, String> {
+        let leg = &self.leg_character_genrator;
+        let schedule = leg
+            .schedule_generator()
+            .generate_with_maturity_date(
+                trade_date,
+                maturity_date,
+                leg.calendar(),
+                leg.fixing_calendar(),
+                leg.payment_calendar(),
+                start_date_opt,
+            )
+            .ok_or_else(|| format!("failed to generate cap/floor schedule to {maturity_date}"))?;
+        Ok(self.build(position, cap_floor_type, strike, schedule))
+    }
+
+    pub fn generate_with_maturity_tenor(
+        &self,
+        position:       Position,
+        cap_floor_type: CapFloorType,
+        strike:         f64,
+        trade_date:     NaiveDate,
+        maturity_tenor: Period,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<CapFloor, String> {
+        let leg = &self.leg_character_genrator;
+        let schedule = leg
+            .schedule_generator()
+            .generate_from_maturity_tenor(
+                trade_date,
+                maturity_tenor,
+                leg.calendar(),
+                leg.fixing_calendar(),
+                leg.payment_calendar(),
+                start_date_opt,
+            )
+            .ok_or_else(|| format!("failed to generate cap/floor schedule for tenor {maturity_tenor}"))?;
+        Ok(self.build(position, cap_floor_type, strike, schedule))
+    }
+}
+
+
+// ═════════════════════════════════════════════════════════════════════════════
+// CapFloorGeneratorLoader
+// ═════════════════════════════════════════════════════════════════════════════
+//
+// JSON 範例（3M term rate cap，排除第一期）：
+//   {
+//     "name": "USD_TERM_CAP",
+//     "market": "USD_MARKET",
+//     "calendar": "NewYorkBank",
+//     "schedule_generator": "3MModifiedFollowing",
+//     "day_counter_generator": "ACT360",
+//     "index": "USD_TERM_3M",
+//     "nominal": 1000000.0,
+//     "exclude_first_caplet": true
+//   }
+//
+// SOFR 後置複利 cap：`index` 指向 CompoundingRate index（lookback 等設定在 index 上），
+// `exclude_first_caplet` 省略（false）。
+// `fixing_calendar` / `payment_calendar` 省略時同 `calendar`；`leverage` 省略時為 1.0、
+// `spread` 省略時為 0.0。
+
+const DEFAULT_CAP_FLOOR_NOMINAL: f64 = 1_000_000.0;
+
+fn default_cap_floor_nominal() -> f64 {
+    DEFAULT_CAP_FLOOR_NOMINAL
+}
+
+fn default_leverage() -> f64 {
+    1.0
+}
+
+#[derive(Deserialize)]
+struct CapFloorGeneratorJsonProp {
+    market:                String,
+    calendar:              String,
+    #[serde(default)]
+    fixing_calendar:       Option<String>,
+    #[serde(default)]
+    payment_calendar:      Option<String>,
+    schedule_generator:    String,
+    day_counter_generator: String,
+    index:                 String,
+    #[serde(default)]
+    spread:                f64,
+    #[serde(default = "default_leverage")]
+    leverage:              f64,
+    #[serde(default)]
+    stub_rate_convention:  StubRateConvention,
+    #[serde(default = "default_cap_floor_nominal")]
+    nominal:               f64,
+    #[serde(default)]
+    exclude_first_caplet:  bool,
+}
+
+pub struct CapFloorGeneratorLoader;
+
+impl<'a> JsonLoader<CapFloorGenerator, InterestRateInstrumentSupports<'a>> for CapFloorGeneratorLoader {
+    fn insert_obj_from_json(
+        &self,
+        builder:    &mut ManagerBuilder<CapFloorGenerator>,
+        json_value: serde_json::Value,
+        supports:   &InterestRateInstrumentSupports<'a>,
+    ) -> Result<(), ManagerError> {
+        let named: Named<CapFloorGeneratorJsonProp> = parse_json_value(json_value)?;
+        let prop = named.inner;
+
+        let market  = supports.0.get(&prop.market)?;
+        let cal     = supports.1.get(&prop.calendar)?;
+        let fix_cal = match &prop.fixing_calendar {
+            Some(name) => supports.1.get(name)?,
+            None       => cal.clone(),
+        };
+        let pay_cal = match &prop.payment_calendar {
+            Some(name) => supports.1.get(name)?,
+            None       => cal.clone(),
+        };
+        let sched   = supports.2.get(&prop.schedule_generator)?;
+        let dcg     = supports.3.get(&prop.day_counter_generator)?;
+        let index   = supports.4.get(&prop.index)?;
+
+        if prop.leverage <= 0.0 {
+            return Err(ManagerError::InvalidValue(format!(
+                "cap/floor generator {} must have a positive leverage, got {}",
+                named.name, prop.leverage,
+            )));
+        }
+
+        let setter = LegCharactersSetter::new();
+        setter.set_spread(prop.spread);
+        setter.set_leverage(prop.leverage);
+        let calc_gen = Arc::new(TermRateCalculatorGenerator::new(index.clone(), prop.stub_rate_convention));
+        let leg = FloatingRateLegCharactersGenerator::new(
+            cal, fix_cal, pay_cal, sched, dcg, Compounding::Simple, setter, index, calc_gen,
+        );
+
+        let conventions = CapFloorConventions {
+            nominal:              prop.nominal,
+            exclude_first_caplet: prop.exclude_first_caplet,
+        };
+        builder.insert(named.name, Arc::new(CapFloorGenerator::new(market, Arc::new(leg), conventions)));
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+// ── crosscurrencybasisswap.rs ─────────────────────────────────────────────────
+//
+// CrossCurrencySwap 的單一幣別視圖，供擔保折現曲線校準（xccy basis swap 報價）使用。
+//
+// # 表示方式
+//
+// 令 X 為 P&L market 幣別（被校準曲線的幣別），C 為另一條 leg 的幣別（擔保幣別）：
+//
+//   - X leg：flows 原樣保留，forward 由該 leg 的 projection curve 推算，
+//     以 P&L discount curve（被校準曲線）折現
+//   - C leg：以 C leg 自己的 reference curve（擔保幣別 OIS）折現到起始日 s，
+//     再以期初匯率 S（= X 名目本金 / C 名目本金）換成單一 X flow 放在 s：
+//
+//       flow_X(s) = S Σ c_i D_C(t_i) / D_C(s)
+//
+// 以 X 曲線折現後即為 C leg 以 horizon 匯率 S D_X(s) / D_C(s) 換算的現值，
+// 與 CrossCurrencySwapPricer 一致。
+//
+// # 限制
+//
+// - C leg 必須為浮動 leg，且其 index 的 reference curve 即 C 的擔保折現曲線（例如 SOFR leg）
+// - 不支援 MTM reset（reset 名目本金依賴尚未校準的 X 曲線）
+// - 起始日已過時，C leg 的剩餘 flows 集中在 horizon
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::instrument::instrument::{
+    CurveFunction,
+    Instrument,
+    InstrumentWithLinearFlows,
+    Position,
+    SimpleInstrument,
+};
+use crate::instrument::interestrate::crosscurrencyswap::{CrossCurrencySwap, CrossCurrencySwapLegSide};
+use crate::market::market::Market;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::pricingcondition::PricingCondition;
+use crate::value::cashflows::CashFlows;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CrossCurrencyBasisSwapError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum CrossCurrencyBasisSwapError {
+    #[error("profit and loss currency {0} matches neither leg")]
+    ProfitAndLossCurrencyNotInLegs(String),
+
+    #[error("collateral leg in {0} must be a floating leg projected on its collateral curve")]
+    CollateralLegNotFloating(String),
+
+    #[error("MTM notional resets are not supported by the single-currency basis swap view")]
+    MtmResetNotSupported,
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CrossCurrencyBasisSwap
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct CrossCurrencyBasisSwap {
+    swap:            Arc<CrossCurrencySwap>,
+    /// P&L 幣別的 leg；另一條為擔保幣別的 leg。
+    local_side:      CrossCurrencySwapLegSide,
+    /// 期初匯率：X 每單位 C。
+    conversion:      f64,
+    start_date:      NaiveDate,
+    curve_name_map:  HashMap<CurveFunction, String>,
+}
+
+impl CrossCurrencyBasisSwap {
+    /// # Errors
+    ///
+    /// 見 [`CrossCurrencyBasisSwapError`]。
+    pub fn new(swap: Arc<CrossCurrencySwap>) -> Result<Self, CrossCurrencyBasisSwapError> {
+        if swap.conventions().mtm_reset_leg.is_some() {
+            return Err(CrossCurrencyBasisSwapError::MtmResetNotSupported);
+        }
+
+        let currency = swap.profit_and_loss_market().settlement_currency().code();
+        let (local_side, collateral_side) = if swap.receive_leg().currency().code() == currency {
+            (CrossCurrencySwapLegSide::ReceiveLeg, CrossCurrencySwapLegSide::PayLeg)
+        } else if swap.pay_leg().currency().code() == currency {
+            (CrossCurrencySwapLegSide::PayLeg, CrossCurrencySwapLegSide::ReceiveLeg)
+        } else {
+            return Err(CrossCurrencyBasisSwapError::ProfitAndLossCurrencyNotInLegs(currency));
+        };
+
+        let local_leg = swap.leg(local_side);
+        let collateral_leg = swap.leg(collateral_side);
+        if collateral_leg.leg_characters().reference_curve_name().is_none() {
+            return Err(CrossCurrencyBasisSwapError::CollateralLegNotFloating(collateral_leg.currency().code()));
+        }
+
+        let conversion = local_leg.nominal() / collateral_leg.nominal();
+        let start_date = collateral_leg
+            .leg_characters()
+            .generic_characters()
+            .schedule()
+            .schedule_periods()[0]
+            .calculation_period()
+            .start_date();
+        let curve_name_map = swap.curve_name_map().clone();
+
+        Ok(Self {
+            swap,
+            local_side,
+            conversion,
+            start_date,
+            curve_name_map,
+        })
+    }
+
+    pub fn swap(&self) -> &Arc<CrossCurrencySwap> { &self.swap }
+
+    fn nominals(&self, side: CrossCurrencySwapLegSide) -> Vec<f64> {
+        let periods = self.swap.leg(side).leg_characters().generic_characters().schedule().schedule_periods().len();
+        vec![self.swap.leg(side).nominal(); periods]
+    }
+
+    /// 指定方向（receive / pay）的 flows，帶 position 與收付正負號。
+    fn side_flows(
+        &self,
+        side:              CrossCurrencySwapLegSide,
+        projected:         bool,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        let nominals = self.nominals(side);
+        let flows = self.swap.leg_flows(side, projected, forward_curve_opt, &nominals, pricing_condition);
+        if side == self.local_side {
+            return flows;
+        }
+
+        if !projected {
+            return flows * self.conversion;
+        }
+
+        // C leg 集中到起始日（已過則為 horizon），reference curve 同時作為折現曲線
+        let collapse_date = self.start_date.max(*pricing_condition.horizon());
+        let mut cash_flows = CashFlows::new();
+        cash_flows[&collapse_date] += forward_curve_opt.map_or(f64::NAN, |curve| {
+            self.conversion * flows.npv(curve, Some(collapse_date))
+        });
+        cash_flows
+    }
+}
+
+
+impl Instrument for CrossCurrencyBasisSwap {
+    fn max_date(&self) -> NaiveDate {
+        self.swap.max_date()
+    }
+
+    fn position(&self) -> Position {
+        self.swap.position()
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        self.swap.profit_and_loss_market()
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+
+impl InstrumentWithLinearFlows for CrossCurrencyBasisSwap {
+    fn past_pay_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        -self.side_flows(CrossCurrencySwapLegSide::PayLeg, false, None, pricing_condition)
+    }
+
+    fn past_receive_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        self.side_flows(CrossCurrencySwapLegSide::ReceiveLeg, false, None, pricing_condition)
+    }
+
+    fn projected_pay_flows(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        self.side_flows(CrossCurrencySwapLegSide::PayLeg, true, forward_curve_opt, pricing_condition)
+    }
+
+    fn projected_receive_flows(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        self.side_flows(CrossCurrencySwapLegSide::ReceiveLeg, true, forward_curve_opt, pricing_condition)
+    }
+}
+
+impl SimpleInstrument for CrossCurrencyBasisSwap {}
@@ -0,0 +1,610 @@
+// ── crosscurrencyswap.rs ──────────────────────────────────────────────────────
+//
+// 跨幣別交換（Cross Currency Swap）：兩條 leg 各自屬於 FX market 貨幣對中的一個幣別。
+//
+// # Leg 與 market
+//
+// 每條 leg 帶有自己的 Market：幣別 = settlement_currency，
+// 折現曲線 = discount_curve_name（該幣別在 swap CSA 下的 collateral-adjusted curve）。
+// 各 leg 先以自己的曲線折現成 leg 幣別現值，再以 horizon 匯率 X₀
+// （見 FxForwardCurve）換算成 profit_and_loss_market 的幣別。
+//
+// # 本金交換
+//
+// 令 σ = +1（receive leg）/ −1（pay leg），p 為 position 方向，N_i 為第 i 期名目本金：
+//
+//   期初交換：start_0       −σ p N_0
+//   MTM reset：start_k      −σ p (N_k − N_{k−1})      （k ≥ 1，僅 resetting leg）
+//   期末交換：payment_last  +σ p N_last
+//   票息    ：payment_i     +σ p N_i c_i
+//
+// # MTM reset
+//
+// resetting leg 的名目本金隨另一條（固定名目本金）leg 以 FX 重設：
+//
+//   N_k = N_other × FX(fixing_k)   （換算到 resetting leg 的幣別；N_0 為期初約定值）
+//
+// fixing_k 為 resetting leg 第 k 期的 fixing date；尚未發生的 fixing 以
+// 交割日 = FX market spot date 的 FX forward 推算，已發生的使用歷史 fixing。
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::instrument::instrument::{CurveFunction, Instrument, Position};
+use crate::instrument::leg::legcharacters::{LegCharacters, LegCharactersGenerator};
+use crate::instrument::leg::legcharactersgeneratorloader::{
+    build_leg_characters_generator,
+    InterestRateInstrumentSupports,
+    LegJsonProp,
+};
+use crate::manager::manager::{FrozenManager, JsonLoader, ManagerBuilder};
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
+use crate::market::currency::Currency;
+use crate::market::fxmarket::FxMatket;
+use crate::market::market::Market;
+use crate::math::round::round;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::pricingcondition::PricingCondition;
+use crate::time::period::Period;
+use crate::value::cashflows::CashFlows;
+
+
+/// 名目本金變動小於此值視為無本金交換（MTM reset 後的浮點誤差）。
+const EXCHANGE_EPSILON: f64 = 1e-8;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CrossCurrencySwapError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum CrossCurrencySwapError {
+    #[error("currency {currency} is not part of the FX pair {pair}")]
+    CurrencyNotInPair {
+        currency: String,
+        pair:     String,
+    },
+
+    #[error("both legs are in {0}; a cross currency swap needs one leg in each currency")]
+    SameLegCurrency(String),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Conventions
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum CrossCurrencySwapLegSide {
+    PayLeg,
+    ReceiveLeg,
+}
+
+impl CrossCurrencySwapLegSide {
+    fn sign(self) -> f64 {
+        match self {
+            Self::ReceiveLeg => 1.0,
+            Self::PayLeg     => -1.0,
+        }
+    }
+
+    fn other(self) -> Self {
+        match self {
+            Self::ReceiveLeg => Self::PayLeg,
+            Self::PayLeg     => Self::ReceiveLeg,
+        }
+    }
+}
+
+/// 本金交換與 MTM reset 設定。
+#[derive(Debug, Clone, Copy)]
+pub struct CrossCurrencySwapConventions {
+    pub initial_exchange: bool,
+    pub final_exchange:   bool,
+    /// None 表示兩條 leg 名目本金皆固定。
+    pub mtm_reset_leg:    Option<CrossCurrencySwapLegSide>,
+}
+
+impl Default for CrossCurrencySwapConventions {
+    fn default() -> Self {
+        Self {
+            initial_exchange: true,
+            final_exchange:   true,
+            mtm_reset_leg:    None,
+        }
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CrossCurrencySwapLeg
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct CrossCurrencySwapLeg {
+    market:         Arc<dyn Market>,
+    leg_characters: Arc<dyn LegCharacters>,
+    /// 期初名目本金（leg 幣別）。
+    nominal:        f64,
+}
+
+impl CrossCurrencySwapLeg {
+    pub fn new(market: Arc<dyn Market>, leg_characters: Arc<dyn LegCharacters>, nominal: f64) -> Self {
+        Self { market, leg_characters, nominal }
+    }
+
+    pub fn market(&self) -> &Arc<dyn Market> { &self.market }
+    pub fn leg_characters(&self) -> &Arc<dyn LegCharacters> { &self.leg_characters }
+    pub fn nominal(&self) -> f64 { self.nominal }
+
+    pub fn currency(&self) -> &Currency {
+        self.market.settlement_currency()
+    }
+
+    pub fn discount_curve_name(&self) -> &String {
+        self.market.discount_curve_name()
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CrossCurrencySwap
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct CrossCurrencySwap {
+    position:               Position,
+    profit_and_loss_market: Arc<dyn Market>,
+    fx_market:              Arc<FxMatket>,
+    receive_leg:            CrossCurrencySwapLeg,
+    pay_leg:                CrossCurrencySwapLeg,
+    conventions:            CrossCurrencySwapConventions,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl CrossCurrencySwap {
+    /// # Errors
+    ///
+    /// - 任一 leg 或 P&L market 的幣別不屬於 FX market 的貨幣對
+    /// - 兩條 leg 幣別相同
+    pub fn new(
+        position:               Position,
+        profit_and_loss_market: Arc<dyn Market>,
+        fx_market:              Arc<FxMatket>,
+        receive_leg:            CrossCurrencySwapLeg,
+        pay_leg:                CrossCurrencySwapLeg,
+        conventions:            CrossCurrencySwapConventions,
+    ) -> Result<Self, CrossCurrencySwapError> {
+        let pair = fx_market.currency_pair();
+        for currency in [receive_leg.currency(), pay_leg.currency(), profit_and_loss_market.settlement_currency()] {
+            if currency.code() != pair.ccy1().code() && currency.code() != pair.ccy2().code() {
+                return Err(CrossCurrencySwapError::CurrencyNotInPair {
+                    currency: currency.code(),
+                    pair:     pair.code(),
+                });
+            }
+        }
+        if receive_leg.currency().code() == pay_leg.currency().code() {
+            return Err(CrossCurrencySwapError::SameLegCurrency(receive_leg.currency().code()));
+        }
+
+        let mut curve_name_map: HashMap<CurveFunction, String> = HashMap::new();
+        curve_name_map.insert(
+            CurveFunction::ProfitAndLossDiscount,
+            profit_and_loss_market.discount_curve_name().to_string(),
+        );
+        if let Some(name) = pay_leg.leg_characters.reference_curve_name() {
+            curve_name_map.insert(CurveFunction::PayForward, name.to_string());
+        }
+        if let Some(name) = receive_leg.leg_characters.reference_curve_name() {
+            curve_name_map.insert(CurveFunction::ReceiveForward, name.to_string());
+        }
+
+        Ok(Self {
+            position,
+            profit_and_loss_market,
+            fx_market,
+            receive_leg,
+            pay_leg,
+            conventions,
+            curve_name_map,
+        })
+    }
+
+    pub fn fx_market(&self) -> &Arc<FxMatket> { &self.fx_market }
+    pub fn receive_leg(&self) -> &CrossCurrencySwapLeg { &self.receive_leg }
+    pub fn pay_leg(&self) -> &CrossCurrencySwapLeg { &self.pay_leg }
+    pub fn conventions(&self) -> &CrossCurrencySwapConventions { &self.conventions }
+
+    pub fn leg(&self, side: CrossCurrencySwapLegSide) -> &CrossCurrencySwapLeg {
+        match side {
+            CrossCurrencySwapLegSide::ReceiveLeg => &self.receive_leg,
+            CrossCurrencySwapLegSide::PayLeg     => &self.pay_leg,
+        }
+    }
+
+    fn sign(&self) -> f64 {
+        self.position as i32 as f64
+    }
+
+    /// 把 `from` 幣別金額換成 `to` 幣別的乘數；`fx_rate` 為 FX market 報價（ccy2 per ccy1）。
+    pub fn conversion_factor(&self, from: &Currency, to: &Currency, fx_rate: f64) -> f64 {
+        if from.code() == to.code() {
+            1.0
+        } else if from.code() == self.fx_market.currency_pair().ccy1().code() {
+            fx_rate
+        } else {
+            1.0 / fx_rate
+        }
+    }
+
+    /// 各期名目本金（leg 幣別）。
+    ///
+    /// `fx_fixing` 回傳 fixing date 的 FX 報價（ccy2 per ccy1），只有 resetting leg 會呼叫；
+    /// 無法取得時該期（及其後的本金交換）為 NaN。
+    pub fn leg_nominals(
+        &self,
+        side:      CrossCurrencySwapLegSide,
+        fx_fixing: &dyn Fn(NaiveDate) -> Option<f64>,
+    ) -> Vec<f64> {
+        let leg = self.leg(side);
+        let periods = leg.leg_characters.generic_characters().schedule().schedule_periods();
+        if self.conventions.mtm_reset_leg != Some(side) {
+            return vec![leg.nominal; periods.len()];
+        }
+
+        let other = self.leg(side.other());
+        periods
+            .iter()
+            .enumerate()
+            .map(|(k, period)| {
+                if k == 0 {
+                    return leg.nominal;
+                }
+                fx_fixing(period.fixing_date()).map_or(f64::NAN, |fx_rate| {
+                    other.nominal * self.conversion_factor(other.currency(), leg.currency(), fx_rate)
+                })
+            })
+            .collect()
+    }
+
+    fn is_projected(date: NaiveDate, pricing_condition: &PricingCondition) -> bool {
+        let horizon = *pricing_condition.horizon();
+        date > horizon || (date == horizon && *pricing_condition.include_horizon_flow())
+    }
+
+    /// 單一 leg 的 flows（leg 幣別，含 position 與收付方向）。
+    ///
+    /// `projected` 為 true 時取 horizon 之後的 flows，否則取已發生的 flows。
+    /// 浮動 leg 需要 `forward_curve_opt`（已發生的期間仍由 index 的 past fixings 決定）。
+    pub fn leg_flows(
+        &self,
+        side:              CrossCurrencySwapLegSide,
+        projected:         bool,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        nominals:          &[f64],
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        let leg = self.leg(side);
+        let sign = side.sign() * self.sign();
+        let digits = leg.currency().digits();
+        let is_floating = leg.leg_characters.reference_curve_name().is_some();
+        let (coupon_rounding, index_rounding) = if is_floating {
+            (
+                pricing_condition.floating_flow_rounding_digits(digits),
+                pricing_condition.floating_index_rounding_digits(digits),
+            )
+        } else {
+            (pricing_condition.fixed_flow_rounding_digits(digits), None)
+        };
+        let exchange_rounding = pricing_condition.fixed_flow_rounding_digits(digits);
+        let rounded = |flow: f64, digits_opt: Option<u32>| digits_opt.map_or(flow, |d| round(flow, d));
+
+        let mut cash_flows = CashFlows::new();
+        let periods = leg.leg_characters.generic_characters().schedule().schedule_periods();
+        for (i, period) in periods.iter().enumerate() {
+            let payment_date = period.payment_date();
+            if Self::is_projected(payment_date, pricing_condition) == projected {
+                let coupon = if is_floating && forward_curve_opt.is_none() {
+                    f64::NAN
+                } else {
+                    leg.leg_characters.evaluate_flow(i, forward_curve_opt, pricing_condition, index_rounding)
+                };
+                cash_flows[&payment_date] += rounded(sign * nominals[i] * coupon, coupon_rounding);
+            }
+
+            let start_date = period.calculation_period().start_date();
+            let exchange = match i {
+                0 if self.conventions.initial_exchange => nominals[0],
+                0 => 0.0,
+                _ => nominals[i] - nominals[i - 1],
+            };
+            if exchange.abs() > EXCHANGE_EPSILON && Self::is_projected(start_date, pricing_condition) == projected {
+                cash_flows[&start_date] -= rounded(sign * exchange, exchange_rounding);
+            }
+        }
+
+        let maturity_date = periods[periods.len() - 1].payment_date();
+        if self.conventions.final_exchange && Self::is_projected(maturity_date, pricing_condition) == projected {
+            cash_flows[&maturity_date] += rounded(sign * nominals[nominals.len() - 1], exchange_rounding);
+        }
+        cash_flows
+    }
+}
+
+
+impl Instrument for CrossCurrencySwap {
+    fn max_date(&self) -> NaiveDate {
+        self.receive_leg.leg_characters.max_date().max(self.pay_leg.leg_characters.max_date())
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CrossCurrencySwapGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// 名目本金以 FX market 的 ccy1 表示；ccy2 leg 的名目本金 = nominal × fx_rate，
+// fx_rate（期初匯率）以 RwLock 保存，可在產生商品前以當日 spot 設定。
+// 基差 spread 透過 leg generator 的 setter 設定。
+
+pub struct CrossCurrencySwapLegGenerator {
+    market:                 Arc<dyn Market>,
+    leg_character_genrator: Arc<dyn LegCharactersGenerator>,
+}
+
+impl CrossCurrencySwapLegGenerator {
+    pub fn new(market: Arc<dyn Market>, leg_character_genrator: Arc<dyn LegCharactersGenerator>) -> Self {
+        Self { market, leg_character_genrator }
+    }
+
+    pub fn market(&self) -> &Arc<dyn Market> { &self.market }
+    pub fn leg_character_genrator(&self) -> &Arc<dyn LegCharactersGenerator> { &self.leg_character_genrator }
+}
+
+pub struct CrossCurrencySwapGenerator {
+    profit_and_loss_market: Arc<dyn Market>,
+    fx_market:              Arc<FxMatket>,
+    receive_leg:            CrossCurrencySwapLegGenerator,
+    pay_leg:                CrossCurrencySwapLegGenerator,
+    conventions:            CrossCurrencySwapConventions,
+    nominal:                f64,
+    fx_rate:                RwLock<f64>,
+}
+
+impl CrossCurrencySwapGenerator {
+    pub fn new(
+        profit_and_loss_market: Arc<dyn Market>,
+        fx_market:              Arc<FxMatket>,
+        receive_leg:            CrossCurrencySwapLegGenerator,
+        pay_leg:                CrossCurrencySwapLegGenerator,
+        conventions:            CrossCurrencySwapConventions,
+        nominal:                f64,
+        fx_rate:                f64,
+    ) -> Self {
+        Self {
+            profit_and_loss_market,
+            fx_market,
+            receive_leg,
+            pay_leg,
+            conventions,
+            nominal,
+            fx_rate: RwLock::new(fx_rate),
+        }
+    }
+
+    pub fn profit_and_loss_market(&self) -> &Arc<dyn Market> { &self.profit_and_loss_market }
+    pub fn fx_market(&self) -> &Arc<FxMatket> { &self.fx_market }
+    pub fn conventions(&self) -> &CrossCurrencySwapConventions { &self.conventions }
+    pub fn nominal(&self) -> f64 { self.nominal }
+
+    pub fn leg(&self, side: CrossCurrencySwapLegSide) -> &CrossCurrencySwapLegGenerator {
+        match side {
+            CrossCurrencySwapLegSide::ReceiveLeg => &self.receive_leg,
+            CrossCurrencySwapLegSide::PayLeg     => &self.pay_leg,
+        }
+    }
+
+    pub fn fx_rate(&self) -> f64 {
+        *self.fx_rate.read().unwrap()
+    }
+
+    /// 設定期初匯率（ccy2 per ccy1）。
+    pub fn set_fx_rate(&self, fx_rate: f64) {
+        *self.fx_rate.write().unwrap() = fx_rate;
+    }
+
+    fn leg_nominal(&self, market: &Arc<dyn Market>) -> f64 {
+        if market.settlement_currency().code() == self.fx_market.currency_pair().ccy1().code() {
+            self.nominal
+        } else {
+            self.nominal * self.fx_rate()
+        }
+    }
+
+    fn build_swap(
+        &self,
+        position:               Position,
+        receive_leg_characters: Arc<dyn LegCharacters>,
+        pay_leg_characters:     Arc<dyn LegCharacters>,
+    ) -> Result<Arc<CrossCurrencySwap>, String> {
+        let receive_leg = CrossCurrencySwapLeg::new(
+            self.receive_leg.market.clone(),
+            receive_leg_characters,
+            self.leg_nominal(&self.receive_leg.market),
+        );
+        let pay_leg = CrossCurrencySwapLeg::new(
+            self.pay_leg.market.clone(),
+            pay_leg_characters,
+            self.leg_nominal(&self.pay_leg.market),
+        );
+        CrossCurrencySwap::new(
+            position,
+            self.profit_and_loss_market.clone(),
+            self.fx_market.clone(),
+            receive_leg,
+            pay_leg,
+            self.conventions,
+        )
+        .map(Arc::new)
+        .map_err(|e| e.to_string())
+    }
+
+    pub fn generate_swap_with_maturity_date(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_date:  NaiveDate,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<CrossCurrencySwap>, String> {
+        let receive_leg_characters = self.receive_leg.leg_character_genrator
+            .generate_with_maturity_date(trade_date, maturity_date, start_date_opt)?;
+        let pay_leg_characters = self.pay_leg.leg_character_genrator
+            .generate_with_maturity_date(trade_date, maturity_date, start_date_opt)?;
+        self.build_swap(position, receive_leg_characters, pay_leg_characters)
+    }
+
+    pub fn generate_swap_with_maturity_tenor(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_tenor: Period,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<CrossCurrencySwap>, String> {
+        let receive_leg_characters = self.receive_leg.leg_character_genrator
+            .generate_with_maturity_tenor(trade_date, maturity_tenor, start_date_opt)?;
+        let pay_leg_characters = self.pay_leg.leg_character_genrator
+            .generate_with_maturity_tenor(trade_date, maturity_tenor, start_date_opt)?;
+        self.build_swap(position, receive_leg_characters, pay_leg_characters)
+    }
+}
+
+
+// ═════════════════════════════════════════════════════════════════════════════
+// CrossCurrencySwapGeneratorLoader
+// ═════════════════════════════════════════════════════════════════════════════
+//
+// JSON 範例（收 TWD 浮動、付 USD SOFR，TWD leg MTM reset）：
+//   {
+//     "name": "USDTWD_XCCY",
+//     "market": "TWD_MARKET",
+//     "fx_market": "USDTWD",
+//     "receive_leg_market": "TWD_USD_COLLATERAL_MARKET",
+//     "receive_leg": {
+//       "type": "Floating",
+//       "calendar": "TWD",
+//       "schedule_generator": "TWD_3M_SCHED",
+//       "day_counter_generator": "ACT365",
+//       "compounding": "Simple",
+//       "index": "TAIBOR_3M"
+//     },
+//     "pay_leg_market": "USD_MARKET",
+//     "pay_leg": {
+//       "type": "Floating",
+//       "calendar": "NewYorkBank",
+//       "schedule_generator": "USD_3M_SCHED",
+//       "day_counter_generator": "ACT360",
+//       "compounding": "Simple",
+//       "index": "SOFR_TERM_3M"
+//     },
+//     "nominal": 10000000.0,
+//     "fx_rate": 32.0,
+//     "mtm_reset_leg": "ReceiveLeg"
+//   }
+//
+// `nominal` 以 FX market 的 ccy1 表示（省略時 1,000,000）；`fx_rate` 省略時為 1.0；
+// `initial_exchange` / `final_exchange` 省略時為 true；`mtm_reset_leg` 省略時不重設。
+
+/// [`CrossCurrencySwapGeneratorLoader`] 的 supports：利率商品共用依賴 + FX market 查找。
+pub type CrossCurrencySwapSupports<'a> = (
+    &'a InterestRateInstrumentSupports<'a>,
+    &'a FrozenManager<FxMatket>,
+);
+
+fn default_nominal() -> f64 {
+    1_000_000.0
+}
+
+fn default_fx_rate() -> f64 {
+    1.0
+}
+
+fn default_exchange() -> bool {
+    true
+}
+
+#[derive(Deserialize)]
+struct CrossCurrencySwapGeneratorJsonProp {
+    market:             String,
+    fx_market:          String,
+    receive_leg_market: String,
+    receive_leg:        LegJsonProp,
+    pay_leg_market:     String,
+    pay_leg:            LegJsonProp,
+    #[serde(default = "default_nominal")]
+    nominal:            f64,
+    #[serde(default = "default_fx_rate")]
+    fx_rate:            f64,
+    #[serde(default = "default_exchange")]
+    initial_exchange:   bool,
+    #[serde(default = "default_exchange")]
+    final_exchange:     bool,
+    #[serde(default)]
+    mtm_reset_leg:      Option<CrossCurrencySwapLegSide>,
+}
+
+pub struct CrossCurrencySwapGeneratorLoader;
+
+impl<'a> JsonLoader<CrossCurrencySwapGenerator, CrossCurrencySwapSupports<'a>> for CrossCurrencySwapGeneratorLoader {
+    fn insert_obj_from_json(
+        &self,
+        builder:    &mut ManagerBuilder<CrossCurrencySwapGenerator>,
+        json_value: serde_json::Value,
+        supports:   &CrossCurrencySwapSupports<'a>,
+    ) -> Result<(), ManagerError> {
+        let named: Named<CrossCurrencySwapGeneratorJsonProp> = parse_json_value(json_value)?;
+        let p = named.inner;
+        let ir_supports = supports.0;
+
+        let market     = ir_supports.0.get(&p.market)?;
+        let fx_market  = supports.1.get(&p.fx_market)?;
+        let receive_leg = CrossCurrencySwapLegGenerator::new(
+            ir_supports.0.get(&p.receive_leg_market)?,
+            build_leg_characters_generator(p.receive_leg, ir_supports)?,
+        );
+        let pay_leg = CrossCurrencySwapLegGenerator::new(
+            ir_supports.0.get(&p.pay_leg_market)?,
+            build_leg_characters_generator(p.pay_leg, ir_supports)?,
+        );
+
+        let conventions = CrossCurrencySwapConventions {
+            initial_exchange: p.initial_exchange,
+            final_exchange:   p.final_exchange,
+            mtm_reset_leg:    p.mtm_reset_leg,
+        };
+        let generator = CrossCurrencySwapGenerator::new(
+            market, fx_market, receive_leg, pay_leg, conventions, p.nominal, p.fx_rate,
+        );
+        builder.insert(named.name, Arc::new(generator));
+        Ok(())
+    }
+}
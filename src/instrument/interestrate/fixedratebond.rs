@@ -0,0 +1,783 @@
+// ── fixedratebond.rs ──────────────────────────────────────────────────────────
+//
+// 固定利率債券：以 FixedRateLegCharacters（票息）+ 到期還本組成。
+//
+// # 報價與應計利息
+//
+// 價格以每 100 面額表示：dirty = clean + accrued。
+// 應計利息一律使用 Actual/Actual ICMA（以債券 schedule 產生），
+// 令結算日 s 落在第 k 期 [start_k, end_k)：
+//
+//   s <  ex_k：accrued =  100 K τ_ICMA(start_k, s)
+//   s >= ex_k：accrued = −100 K τ_ICMA(s, end_k)      （除息期間，買方拿不到第 k 期票息）
+//
+// ex_k 為第 k 期付息日往前 `ex_coupon_days` 個營業日（payment calendar）；
+// ex_coupon_days = 0 時不適用除息。
+//
+// # 殖利率
+//
+// 剩餘現金流 CF_j（每 100 面額）於票息日 end_j，t_j = τ_ICMA(s, end_j)，f 為年付息次數：
+//
+// | YieldConvention | 折現因子                                         |
+// |-----------------|--------------------------------------------------|
+// | Street          | (1 + y/f)^(−f t_j)；只剩最後一期時 1 / (1 + y t) |
+// | Isma            | (1 + y)^(−t_j)                                   |
+//
+// price → yield 以 math::rootsolver 求解，初值為票面利率。
+// Macaulay duration = Σ t_j CF_j DF_j / P；modified duration = −P′/P；convexity = P″/P。
+//
+// # 評價
+//
+// 商品的 flows（以 P&L market 的 discount curve 折現）：
+//   receive：買方可得的票息（s < ex_k 且付息日在 s 之後）與到期還本
+//   pay    ：結算日支付 N × dirty / 100
+//
+// 校準時 NPV = 0 ⇔ 曲線隱含的 dirty price 等於成交價。
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::instrument::instrument::{
+    CurveFunction,
+    Instrument,
+    InstrumentWithLinearFlows,
+    Position,
+    SimpleInstrument,
+};
+use crate::instrument::interestrate::simpleinterestrateinstrumentgenerator::SimpleInterestRateInstrumentGenerator;
+use crate::instrument::leg::fixedratelegcharacters::{
+    FixedRateLegCharacters,
+    FixedRateLegCharactersGenerator,
+};
+use crate::instrument::leg::legcharacters::{
+    GenericLegCharacters,
+    LegCharacters,
+    LegCharactersGenerator,
+    LegCharactersSetter,
+};
+use crate::instrument::leg::legcharactersgeneratorloader::InterestRateInstrumentSupports;
+use crate::interestrate::compounding::Compounding;
+use crate::manager::manager::{JsonLoader, ManagerBuilder};
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
+use crate::market::market::Market;
+use crate::math::rootsolver::{RootSolver, RootSolverError};
+use crate::math::round::round;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::pricingcondition::PricingCondition;
+use crate::time::daycounter::daycounter::{DayCounter, DayCounterGenerator};
+use crate::time::daycounter::icmaactualdaycountdominator::ICMADayCounterDominatorGenerator;
+use crate::time::daycounter::numerator::actualnumerator::ActualNumeratorGenerator;
+use crate::time::period::{Period, TimeUnit};
+use crate::time::schedule::schedule::Schedule;
+use crate::value::cashflows::CashFlows;
+
+
+/// 價格的報價單位（每 100 面額）。
+const PRICE_BASE: f64 = 100.0;
+
+/// 殖利率求解的第二個初始點位移。
+const YIELD_SOLVER_STEP: f64 = 1e-2;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// BondError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum BondError {
+    #[error("coupon frequency {0} is not a whole number of payments per year")]
+    IrregularFrequency(String),
+
+    #[error("ICMA accrual day counter generation failed: {0}")]
+    AccrualDayCounter(String),
+
+    #[error("settlement date {settlement_date} is outside the accrual schedule {start_date}/{maturity_date}")]
+    SettlementOutsideSchedule {
+        settlement_date: NaiveDate,
+        start_date:      NaiveDate,
+        maturity_date:   NaiveDate,
+    },
+
+    #[error("yield solve failed: {0}")]
+    YieldSolve(#[from] RootSolverError),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Conventions
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum YieldConvention {
+    /// 以付息頻率複利，最後一期單利。
+    #[default]
+    Street,
+    /// 年複利（ISMA / ICMA Rule 803）。
+    Isma,
+}
+
+/// 債券的交割與還本慣例。
+#[derive(Debug, Clone, Copy)]
+pub struct BondConventions {
+    pub nominal:          f64,
+    /// 還本比例（1.0 = 面額還本）。
+    pub redemption:       f64,
+    /// trade date 到結算日的營業日數。
+    pub settlement_lag:   u32,
+    /// 付息日前幾個營業日除息；0 表示不適用。
+    pub ex_coupon_days:   u32,
+    pub yield_convention: YieldConvention,
+}
+
+impl Default for BondConventions {
+    fn default() -> Self {
+        Self {
+            nominal:          DEFAULT_BOND_NOMINAL,
+            redemption:       1.0,
+            settlement_lag:   0,
+            ex_coupon_days:   0,
+            yield_convention: YieldConvention::Street,
+        }
+    }
+}
+
+/// 殖利率下的利率風險指標。
+#[derive(Debug, Clone, Copy)]
+pub struct YieldRisk {
+    pub macaulay_duration: f64,
+    pub modified_duration: f64,
+    pub convexity:         f64,
+}
+
+/// 每 100 面額的剩餘現金流。
+struct YieldFlow {
+    time:   f64,
+    amount: f64,
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FixedRateBond
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FixedRateBond {
+    position:               Position,
+    profit_and_loss_market: Arc<dyn Market>,
+    leg_characters:         FixedRateLegCharacters,
+    conventions:            BondConventions,
+    settlement_date:        NaiveDate,
+    clean_price:            f64,
+    accrual_day_counter:    DayCounter,
+    coupon_frequency:       f64,
+    /// 與 schedule periods 對齊的除息日。
+    ex_coupon_dates:        Vec<NaiveDate>,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl FixedRateBond {
+    /// # Errors
+    ///
+    /// - schedule 頻率不是每年整數次付息
+    /// - ICMA day counter 無法由 schedule 產生
+    pub fn new(
+        position:               Position,
+        profit_and_loss_market: Arc<dyn Market>,
+        leg_characters:         FixedRateLegCharacters,
+        conventions:            BondConventions,
+        settlement_date:        NaiveDate,
+        clean_price:            f64,
+    ) -> Result<Self, BondError> {
+        let schedule = leg_characters.generic_characters().schedule();
+        let coupon_frequency = coupon_frequency(
+            schedule.generator().calculation_period_generator().frequency(),
+        )?;
+
+        let accrual_day_counter = DayCounterGenerator::new(
+            Arc::new(ActualNumeratorGenerator::new()),
+            Arc::new(ICMADayCounterDominatorGenerator::new()),
+            false,
+            true,
+        )
+        .generate(Some(schedule))
+        .map_err(|e| BondError::AccrualDayCounter(e.to_string()))?;
+
+        let payment_calendar = schedule.payment_calendar();
+        let ex_coupon_dates = schedule
+            .schedule_periods()
+            .iter()
+            .map(|p| {
+                payment_calendar.shift_n_business_day(p.payment_date(), -(conventions.ex_coupon_days as i32))
+            })
+            .collect();
+
+        let mut curve_name_map: HashMap<CurveFunction, String> = HashMap::new();
+        curve_name_map.insert(
+            CurveFunction::ProfitAndLossDiscount,
+            profit_and_loss_market.discount_curve_name().to_string(),
+        );
+
+        Ok(Self {
+            position,
+            profit_and_loss_market,
+            leg_characters,
+            conventions,
+            settlement_date,
+            clean_price,
+            accrual_day_counter,
+            coupon_frequency,
+            ex_coupon_dates,
+            curve_name_map,
+        })
+    }
+
+    pub fn leg_characters(&self) -> &FixedRateLegCharacters { &self.leg_characters }
+    pub fn conventions(&self) -> &BondConventions { &self.conventions }
+    pub fn settlement_date(&self) -> NaiveDate { self.settlement_date }
+    pub fn clean_price(&self) -> f64 { self.clean_price }
+    pub fn coupon_rate(&self) -> f64 { self.leg_characters.fixed_rate() }
+    pub fn coupon_frequency(&self) -> f64 { self.coupon_frequency }
+    pub fn ex_coupon_dates(&self) -> &[NaiveDate] { &self.ex_coupon_dates }
+
+    fn schedule(&self) -> &Schedule {
+        self.leg_characters.generic_characters().schedule()
+    }
+
+    fn sign(&self) -> f64 {
+        self.position as i32 as f64
+    }
+
+    /// 結算日為 `settlement_date` 時，第 k 期票息是否歸買方。
+    fn is_entitled(&self, k: usize, settlement_date: NaiveDate) -> bool {
+        let payment_date = self.schedule().schedule_periods()[k].payment_date();
+        payment_date > settlement_date && settlement_date < self.ex_coupon_dates[k]
+    }
+
+    /// 包含 `settlement_date` 的計息期 index。
+    fn accrual_period_index(&self, settlement_date: NaiveDate) -> Result<usize, BondError> {
+        let periods = self.schedule().schedule_periods();
+        let start_date = periods[0].calculation_period().start_date();
+        let maturity_date = periods[periods.len() - 1].calculation_period().end_date();
+        if settlement_date < start_date || settlement_date >= maturity_date {
+            return Err(BondError::SettlementOutsideSchedule { settlement_date, start_date, maturity_date });
+        }
+        Ok(periods.partition_point(|p| p.calculation_period().end_date() <= settlement_date))
+    }
+
+    // ── 價格 / 殖利率 ────────────────────────────────────────────────────────
+
+    /// 每 100 面額的應計利息（除息期間為負）。
+    pub fn accrued_interest(&self, settlement_date: NaiveDate) -> Result<f64, BondError> {
+        let k = self.accrual_period_index(settlement_date)?;
+        let period = self.schedule().schedule_periods()[k].calculation_period();
+        let rate = PRICE_BASE * self.coupon_rate();
+        Ok(if settlement_date < self.ex_coupon_dates[k] {
+            rate * self.accrual_day_counter.year_fraction(period.start_date(), settlement_date)
+        } else {
+            -rate * self.accrual_day_counter.year_fraction(settlement_date, period.end_date())
+        })
+    }
+
+    pub fn dirty_price(&self, clean_price: f64, settlement_date: NaiveDate) -> Result<f64, BondError> {
+        Ok(clean_price + self.accrued_interest(settlement_date)?)
+    }
+
+    /// 結算日之後、買方可得的每 100 面額現金流（票息與還本）。
+    fn yield_flows(&self, settlement_date: NaiveDate) -> Result<Vec<YieldFlow>, BondError> {
+        self.accrual_period_index(settlement_date)?;
+        let periods = self.schedule().schedule_periods();
+        let flow_values = self.leg_characters.flow_values();
+        let last = periods.len() - 1;
+
+        let mut flows: Vec<YieldFlow> = (0..periods.len())
+            .filter(|&k| self.is_entitled(k, settlement_date))
+            .map(|k| YieldFlow {
+                time:   self.accrual_day_counter
+                    .year_fraction(settlement_date, periods[k].calculation_period().end_date()),
+                amount: PRICE_BASE * flow_values[k],
+            })
+            .collect();
+
+        let redemption = PRICE_BASE * self.conventions.redemption;
+        match flows.last_mut() {
+            Some(flow) if self.is_entitled(last, settlement_date) => flow.amount += redemption,
+            _ => flows.push(YieldFlow {
+                time:   self.accrual_day_counter
+                    .year_fraction(settlement_date, periods[last].calculation_period().end_date()),
+                amount: redemption,
+            }),
+        }
+        Ok(flows)
+    }
+
+    /// 單一現金流在殖利率 y 下的折現因子及其對 y 的一、二階導數。
+    fn yield_discount(&self, y: f64, time: f64, is_final_period: bool) -> (f64, f64, f64) {
+        let frequency = match self.conventions.yield_convention {
+            YieldConvention::Street if is_final_period => {
+                let df = 1.0 / (1.0 + y * time);
+                return (df, -time * df * df, 2.0 * time * time * df * df * df);
+            }
+            YieldConvention::Street => self.coupon_frequency,
+            YieldConvention::Isma   => 1.0,
+        };
+        let base = 1.0 + y / frequency;
+        let df = base.powf(-frequency * time);
+        (df, -time * df / base, time * (time + 1.0 / frequency) * df / (base * base))
+    }
+
+    /// Σ CF_j (DF_j, t_j DF_j, DF_j′, DF_j″)。
+    fn yield_sums(&self, y: f64, settlement_date: NaiveDate) -> Result<(f64, f64, f64, f64), BondError> {
+        let flows = self.yield_flows(settlement_date)?;
+        let is_final_period = flows.len() == 1;
+        Ok(flows.iter().fold((0.0, 0.0, 0.0, 0.0), |(p, tp, d1, d2), flow| {
+            let (df, df_1, df_2) = self.yield_discount(y, flow.time, is_final_period);
+            (
+                p + flow.amount * df,
+                tp + flow.time * flow.amount * df,
+                d1 + flow.amount * df_1,
+                d2 + flow.amount * df_2,
+            )
+        }))
+    }
+
+    pub fn dirty_price_from_yield(&self, y: f64, settlement_date: NaiveDate) -> Result<f64, BondError> {
+        Ok(self.yield_sums(y, settlement_date)?.0)
+    }
+
+    pub fn clean_price_from_yield(&self, y: f64, settlement_date: NaiveDate) -> Result<f64, BondError> {
+        Ok(self.dirty_price_from_yield(y, settlement_date)? - self.accrued_interest(settlement_date)?)
+    }
+
+    /// 以 RootSolver 由 clean price 反推殖利率。
+    pub fn yield_from_clean_price(&self, clean_price: f64, settlement_date: NaiveDate) -> Result<f64, BondError> {
+        let dirty_price = self.dirty_price(clean_price, settlement_date)?;
+        // 先檢查日期，objective 內的錯誤才能安全地以 NaN 表示
+        self.yield_flows(settlement_date)?;
+
+        let objective = |y: f64| {
+            self.dirty_price_from_yield(y, settlement_date)
+                .map_or(f64::NAN, |price| price - dirty_price)
+        };
+        let initial_guess = self.coupon_rate();
+        Ok(RootSolver::with_defaults().solve(objective, initial_guess, Some(initial_guess + YIELD_SOLVER_STEP))?)
+    }
+
+    pub fn yield_risk(&self, y: f64, settlement_date: NaiveDate) -> Result<YieldRisk, BondError> {
+        let (price, time_weighted, first, second) = self.yield_sums(y, settlement_date)?;
+        Ok(YieldRisk {
+            macaulay_duration: time_weighted / price,
+            modified_duration: -first / price,
+            convexity:         second / price,
+        })
+    }
+
+    // ── Flows ────────────────────────────────────────────────────────────────
+
+    /// 買方收取的票息與還本（含 position 方向）。
+    fn receive_flows(&self, projected: bool, pricing_condition: &PricingCondition) -> CashFlows {
+        let digits = self.profit_and_loss_market.settlement_currency().digits();
+        let rounding_digits_opt = pricing_condition.fixed_flow_rounding_digits(digits);
+        let rounded = |flow: f64| rounding_digits_opt.map_or(flow, |d| round(flow, d));
+        let notional = self.sign() * self.conventions.nominal;
+
+        let mut cash_flows = CashFlows::new();
+        let periods = self.schedule().schedule_periods();
+        for (k, (period, flow_value)) in periods.iter().zip(self.leg_characters.flow_values()).enumerate() {
+            let payment_date = period.payment_date();
+            if self.is_entitled(k, self.settlement_date)
+                && is_projected(payment_date, pricing_condition) == projected
+            {
+                cash_flows[&payment_date] += rounded(notional * flow_value);
+            }
+        }
+
+        let maturity_date = self.leg_characters.max_date();
+        if is_projected(maturity_date, pricing_condition) == projected {
+            cash_flows[&maturity_date] += rounded(notional * self.conventions.redemption);
+        }
+        cash_flows
+    }
+
+    /// 結算日支付的 dirty amount（正值）；結算日不在計息期間內時為 NaN。
+    fn settlement_amount(&self, pricing_condition: &PricingCondition) -> f64 {
+        let digits = self.profit_and_loss_market.settlement_currency().digits();
+        let amount = self
+            .dirty_price(self.clean_price, self.settlement_date)
+            .map_or(f64::NAN, |dirty| self.sign() * self.conventions.nominal * dirty / PRICE_BASE);
+        match pricing_condition.fixed_flow_rounding_digits(digits) {
+            Some(d) => round(amount, d),
+            None    => amount,
+        }
+    }
+}
+
+
+/// schedule 頻率 → 每年付息次數。
+fn coupon_frequency(frequency: Period) -> Result<f64, BondError> {
+    let per_year = match frequency.unit() {
+        TimeUnit::Months if frequency.number() > 0 && 12 % frequency.number() == 0 => 12 / frequency.number(),
+        TimeUnit::Years if frequency.number() == 1 => 1,
+        _ => return Err(BondError::IrregularFrequency(frequency.to_string())),
+    };
+    Ok(per_year as f64)
+}
+
+/// 現金流是否仍屬 horizon 之後（FixedRateBond 與 FloatingRateNote 共用）。
+pub(crate) fn is_projected(date: NaiveDate, pricing_condition: &PricingCondition) -> bool {
+    let horizon = *pricing_condition.horizon();
+    date > horizon || (date == horizon && *pricing_condition.include_horizon_flow())
+}
+
+/// 到期日往前按付息頻率回推，第一個不晚於結算日的票息日（未調整）。
+/// 頻率非正（例如零息 schedule）無法回推，回傳 None。
+pub(crate) fn previous_coupon_date(
+    frequency:       Period,
+    maturity_date:   NaiveDate,
+    settlement_date: NaiveDate,
+) -> Option<NaiveDate> {
+    if frequency.number() <= 0 {
+        return None;
+    }
+    let mut n = 1;
+    loop {
+        let d = maturity_date - Period::new(n * frequency.number(), frequency.unit());
+        if d <= settlement_date {
+            return Some(d);
+        }
+        n += 1;
+    }
+}
+
+
+impl Instrument for FixedRateBond {
+    fn max_date(&self) -> NaiveDate {
+        self.leg_characters.max_date()
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+
+impl InstrumentWithLinearFlows for FixedRateBond {
+    fn past_pay_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if !is_projected(self.settlement_date, pricing_condition) {
+            cash_flows[&self.settlement_date] += self.settlement_amount(pricing_condition);
+        }
+        cash_flows
+    }
+
+    fn past_receive_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        self.receive_flows(false, pricing_condition)
+    }
+
+    fn projected_pay_flows(
+        &self,
+        _forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if is_projected(self.settlement_date, pricing_condition) {
+            cash_flows[&self.settlement_date] -= self.settlement_amount(pricing_condition);
+        }
+        cash_flows
+    }
+
+    fn projected_receive_flows(
+        &self,
+        _forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        self.receive_flows(true, pricing_condition)
+    }
+}
+
+impl SimpleInstrument for FixedRateBond {}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FixedRateBondGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// 票面利率存在 leg generator 的 setter；成交 clean price 以 RwLock 保存，
+// 由 quote sheet 設定後再產生商品。
+
+pub struct FixedRateBondGenerator {
+    profit_and_loss_market: Arc<dyn Market>,
+    leg_character_genrator: Arc<FixedRateLegCharactersGenerator>,
+    conventions:            BondConventions,
+    clean_price:            RwLock<f64>,
+}
+
+impl FixedRateBondGenerator {
+    pub fn new(
+        profit_and_loss_market: Arc<dyn Market>,
+        leg_character_genrator: Arc<FixedRateLegCharactersGenerator>,
+        conventions:            BondConventions,
+    ) -> Self {
+        Self {
+            profit_and_loss_market,
+            leg_character_genrator,
+            conventions,
+            clean_price: RwLock::new(PRICE_BASE),
+        }
+    }
+
+    /// 使用預設慣例（面額還本、T+0、不除息、Street yield）。
+    pub fn with_defaults(
+        profit_and_loss_market: Arc<dyn Market>,
+        leg_character_genrator: Arc<FixedRateLegCharactersGenerator>,
+    ) -> Self {
+        Self::new(profit_and_loss_market, leg_character_genrator, BondConventions::default())
+    }
+
+    pub fn leg_character_genrator(&self) -> &Arc<FixedRateLegCharactersGenerator> { &self.leg_character_genrator }
+    pub fn conventions(&self) -> &BondConventions { &self.conventions }
+
+    pub fn coupon_rate(&self) -> f64 {
+        self.leg_character_genrator.setter().fixed_rate()
+    }
+
+    pub fn set_coupon_rate(&self, coupon_rate: f64) {
+        self.leg_character_genrator.setter().set_fixed_rate(coupon_rate);
+    }
+
+    pub fn clean_price(&self) -> f64 {
+        *self.clean_price.read().unwrap()
+    }
+
+    /// 設定成交 clean price（quote sheet 的報價）。
+    pub fn set_clean_price(&self, clean_price: f64) {
+        *self.clean_price.write().unwrap() = clean_price;
+    }
+
+    pub fn settlement_date(&self, trade_date: NaiveDate) -> NaiveDate {
+        self.leg_character_genrator
+            .calendar()
+            .shift_n_business_day(trade_date, self.conventions.settlement_lag as i32)
+    }
+
+    fn build_bond(
+        &self,
+        position:        Position,
+        schedule:        Schedule,
+        settlement_date: NaiveDate,
+    ) -> Result<Arc<FixedRateBond>, String> {
+        let leg = &self.leg_character_genrator;
+        let day_counter = leg
+            .day_counter_generator()
+            .generate(Some(&schedule))
+            .map_err(|e| format!("bond coupon day counter generation failed: {e}"))?;
+        let leg_characters = FixedRateLegCharacters::new(
+            GenericLegCharacters::new(*leg.compounding(), day_counter, schedule),
+            leg.setter().fixed_rate(),
+        );
+        FixedRateBond::new(
+            position,
+            self.profit_and_loss_market.clone(),
+            leg_characters,
+            self.conventions,
+            settlement_date,
+            self.clean_price(),
+        )
+        .map(Arc::new)
+        .map_err(|e| e.to_string())
+    }
+
+    /// `start_date_opt`（起息日）省略時，以結算日前最近一個票息日起算，
+    /// 使已流通債券有正確的應計利息。
+    pub fn generate_bond_with_maturity_date(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_date:  NaiveDate,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<FixedRateBond>, String> {
+        let settlement_date = self.settlement_date(trade_date);
+        let leg = &self.leg_character_genrator;
+        let start_date = match start_date_opt {
+            Some(start_date) => start_date,
+            None => {
+                let frequency = leg.schedule_generator().calculation_period_generator().frequency();
+                previous_coupon_date(frequency, maturity_date, settlement_date)
+                    .ok_or_else(|| format!("cannot infer start date from coupon frequency {frequency}"))?
+            }
+        };
+        let schedule = leg
+            .schedule_generator()
+            .generate_with_maturity_date(
+                trade_date,
+                maturity_date,
+                leg.calendar(),
+                leg.fixing_calendar(),
+                leg.payment_calendar(),
+                Some(start_date),
+            )
+            .ok_or_else(|| format!("failed to generate bond schedule {start_date}/{maturity_date}"))?;
+        self.build_bond(position, schedule, settlement_date)
+    }
+
+    /// 新發行債券：`start_date_opt` 省略時自結算日起息。
+    pub fn generate_bond_with_maturity_tenor(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_tenor: Period,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<FixedRateBond>, String> {
+        let settlement_date = self.settlement_date(trade_date);
+        let leg = &self.leg_character_genrator;
+        let schedule = leg
+            .schedule_generator()
+            .generate_from_maturity_tenor(
+                trade_date,
+                maturity_tenor,
+                leg.calendar(),
+                leg.fixing_calendar(),
+                leg.payment_calendar(),
+                Some(start_date_opt.unwrap_or(settlement_date)),
+            )
+            .ok_or_else(|| format!("failed to generate bond schedule for tenor {maturity_tenor}"))?;
+        self.build_bond(position, schedule, settlement_date)
+    }
+}
+
+impl SimpleInterestRateInstrumentGenerator for FixedRateBondGenerator {
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn generate_with_maturity_date(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_date:  NaiveDate,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let bond = self.generate_bond_with_maturity_date(position, trade_date, maturity_date, start_date_opt)?;
+        Ok(bond)
+    }
+
+    fn generate_with_maturity_tenor(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_tenor: Period,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let bond = self.generate_bond_with_maturity_tenor(position, trade_date, maturity_tenor, start_date_opt)?;
+        Ok(bond)
+    }
+}
+
+
+// ═════════════════════════════════════════════════════════════════════════════
+// FixedRateBondGeneratorLoader
+// ═════════════════════════════════════════════════════════════════════════════
+//
+// JSON 範例（半年付息公債，T+1 結算）：
+//   {
+//     "name": "UST",
+//     "market": "USD_MARKET",
+//     "calendar": "NewYorkBank",
+//     "schedule_generator": "6MUnadjusted",
+//     "day_counter_generator": "ActualActualICMA",
+//     "coupon_rate": 0.0425,
+//     "nominal": 1000000.0,
+//     "settlement_lag": 1,
+//     "ex_coupon_days": 0,
+//     "yield_convention": "Street"
+//   }
+//
+// `coupon_rate` 省略時為 0（之後以 `set_coupon_rate` 設定）；
+// `redemption` 省略時為 1.0；`payment_calendar` 省略時同 `calendar`。
+// 票息以 Simple compounding 計算。
+
+const DEFAULT_BOND_NOMINAL: f64 = 1_000_000.0;
+
+fn default_bond_nominal() -> f64 {
+    DEFAULT_BOND_NOMINAL
+}
+
+fn default_redemption() -> f64 {
+    1.0
+}
+
+#[derive(Deserialize)]
+struct FixedRateBondGeneratorJsonProp {
+    market:                String,
+    calendar:              String,
+    #[serde(default)]
+    payment_calendar:      Option<String>,
+    schedule_generator:    String,
+    day_counter_generator: String,
+    #[serde(default)]
+    coupon_rate:           f64,
+    #[serde(default = "default_bond_nominal")]
+    nominal:               f64,
+    #[serde(default = "default_redemption")]
+    redemption:            f64,
+    #[serde(default)]
+    settlement_lag:        u32,
+    #[serde(default)]
+    ex_coupon_days:        u32,
+    #[serde(default)]
+    yield_convention:      YieldConvention,
+}
+
+pub struct FixedRateBondGeneratorLoader;
+
+impl<'a> JsonLoader<FixedRateBondGenerator, InterestRateInstrumentSupports<'a>> for FixedRateBondGeneratorLoader {
+    fn insert_obj_from_json(
+        &self,
+        builder:    &mut ManagerBuilder<FixedRateBondGenerator>,
+        json_value: serde_json::Value,
+        supports:   &InterestRateInstrumentSupports<'a>,
+    ) -> Result<(), ManagerError> {
+        let named: Named<FixedRateBondGeneratorJsonProp> = parse_json_value(json_value)?;
+        let prop = named.inner;
+
+        let market  = supports.0.get(&prop.market)?;
+        let cal     = supports.1.get(&prop.calendar)?;
+        let pay_cal = match &prop.payment_calendar {
+            Some(name) => supports.1.get(name)?,
+            None       => cal.clone(),
+        };
+        let sched   = supports.2.get(&prop.schedule_generator)?;
+        let dcg     = supports.3.get(&prop.day_counter_generator)?;
+
+        let setter = LegCharactersSetter::new();
+        setter.set_fixed_rate(prop.coupon_rate);
+        let leg = FixedRateLegCharactersGenerator::new(
+            cal.clone(), cal, pay_cal, sched, dcg, Compounding::Simple, setter,
+        );
+
+        let conventions = BondConventions {
+            nominal:          prop.nominal,
+            redemption:       prop.redemption,
+            settlement_lag:   prop.settlement_lag,
+            ex_coupon_days:   prop.ex_coupon_days,
+            yield_convention: prop.yield_convention,
+        };
+        builder.insert(named.name, Arc::new(FixedRateBondGenerator::new(market, Arc::new(leg), conventions)));
+        Ok(())
+    }
+}
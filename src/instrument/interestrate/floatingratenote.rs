@@ -0,0 +1,784 @@
+// ── floatingratenote.rs ───────────────────────────────────────────────────────
+//
+// 浮動利率債券（FRN）：以 FloatingRateLegCharacters（票息）+ 到期還本組成。
+//
+// # Index
+//
+// 票息利率 = leverage × L_k + QM（quoted margin，即 leg 的 spread），
+// L_k 由 leg 的 FixingRateCalculator 計算：
+//
+//   - TermRate index（TAIBOR / IBOR）：TermRateCalculator，stub 依 StubRateConvention
+//   - 複利隔夜 index（SOFR / ESTR）：CompoundingRateIndexCalculator
+//     （逐日複利由 index 處理；lookback / lockout 設定在 index 上）
+//
+// Loader 以 `InterestRateIndex::compounding_rate_index` 判斷 index 型別並選擇 calculator。
+//
+// # 應計利息
+//
+// 結算日 s 落在第 k 期 [start_k, end_k)，τ 使用 leg 的 day counter：
+//
+//   TermRate      ：accrued = 100 (leverage L_k + QM) τ(start_k, s)
+//   CompoundingRate：accrued = 100 (leverage R(start_k, s) + QM) τ(start_k, s)
+//
+// R(start_k, s) 為期初至結算日已實現的複利利率。
+//
+// # Discount margin
+//
+// 剩餘各期以「index 預期利率 + DM」逐期折現（τ′_j = τ(max(start_j, s), end_j)）：
+//
+//   D_j = D_{j−1} / (1 + (L_j + DM) τ′_j),   D_{k−1} = 1
+//   dirty = Σ 100 c_j D_j + 100 R D_n
+//
+// c_j 為每單位本金的票息；price → DM 以 math::rootsolver 求解，初值為 QM。
+//
+// # Simple margin（報價殖利率指標）
+//
+// T = τ(s, maturity)：
+//
+//   simple margin          = QM + (100 R − clean) / (100 T)
+//   adjusted simple margin = (100 QM + (100 R − dirty) / T) / dirty
+//
+// 後者以投入的 dirty price 調整 spread，即 spread-adjusted yield。
+//
+// # 評價
+//
+// 商品的 flows（以 P&L market 的 discount curve 折現）：
+//   receive：結算日之後付息的票息與到期還本
+//   pay    ：結算日支付 N × dirty / 100
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::instrument::instrument::{
+    CurveFunction,
+    Instrument,
+    InstrumentWithLinearFlows,
+    Position,
+    SimpleInstrument,
+};
+use crate::instrument::interestrate::fixedratebond::{is_projected, previous_coupon_date};
+use crate::instrument::interestrate::simpleinterestrateinstrumentgenerator::SimpleInterestRateInstrumentGenerator;
+use crate::instrument::leg::fixingratecalculator::compoundingrateindexcalculator::CompoundingRateIndexCalculatorGenerator;
+use crate::instrument::leg::fixingratecalculator::fixingratecalculator::FixingRateCalculatorGenerator;
+use crate::instrument::leg::fixingratecalculator::termratecalculator::{
+    StubRateConvention,
+    TermRateCalculatorGenerator,
+};
+use crate::instrument::leg::floatingratelegcharacters::{
+    FloatingRateLegCharacters,
+    FloatingRateLegCharactersGenerator,
+};
+use crate::instrument::leg::legcharacters::{
+    LegCharacters,
+    LegCharactersGenerator,
+    LegCharactersSetter,
+};
+use crate::instrument::leg::legcharactersgeneratorloader::InterestRateInstrumentSupports;
+use crate::interestrate::compounding::Compounding;
+use crate::interestrate::index::interestrateindex::InterestRateIndexType;
+use crate::manager::manager::{JsonLoader, ManagerBuilder};
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
+use crate::market::market::Market;
+use crate::math::rootsolver::{RootSolver, RootSolverError};
+use crate::math::round::round;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::pricingcondition::PricingCondition;
+use crate::time::period::Period;
+use crate::time::schedule::schedule::Schedule;
+use crate::time::schedule::scheduleperiod::CalculationPeriod;
+use crate::value::cashflows::CashFlows;
+
+
+/// 價格的報價單位（每 100 面額）。
+const PRICE_BASE: f64 = 100.0;
+
+/// DM 求解的第二個初始點位移。
+const MARGIN_SOLVER_STEP: f64 = 1e-3;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FrnError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum FrnError {
+    #[error("settlement date {settlement_date} is outside the accrual schedule {start_date}/{maturity_date}")]
+    SettlementOutsideSchedule {
+        settlement_date: NaiveDate,
+        start_date:      NaiveDate,
+        maturity_date:   NaiveDate,
+    },
+
+    #[error("index rate for {start_date}/{end_date} is unavailable (missing past fixing or forward curve)")]
+    MissingIndexRate {
+        start_date: NaiveDate,
+        end_date:   NaiveDate,
+    },
+
+    #[error("discount margin solve failed: {0}")]
+    DiscountMarginSolve(#[from] RootSolverError),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Conventions
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// FRN 的交割與還本慣例。
+#[derive(Debug, Clone, Copy)]
+pub struct FrnConventions {
+    pub nominal:        f64,
+    /// 還本比例（1.0 = 面額還本）。
+    pub redemption:     f64,
+    /// trade date 到結算日的營業日數。
+    pub settlement_lag: u32,
+}
+
+impl Default for FrnConventions {
+    fn default() -> Self {
+        Self {
+            nominal:        DEFAULT_FRN_NOMINAL,
+            redemption:     1.0,
+            settlement_lag: 0,
+        }
+    }
+}
+
+/// 結算日之後的每期資料（每 100 面額）。
+struct MarginFlow {
+    /// τ′_j：自 max(start_j, s) 至 end_j。
+    accrual:    f64,
+    index_rate: f64,
+    amount:     f64,
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FloatingRateNote
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FloatingRateNote {
+    position:               Position,
+    profit_and_loss_market: Arc<dyn Market>,
+    leg_characters:         FloatingRateLegCharacters,
+    conventions:            FrnConventions,
+    settlement_date:        NaiveDate,
+    clean_price:            f64,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl FloatingRateNote {
+    pub fn new(
+        position:               Position,
+        profit_and_loss_market: Arc<dyn Market>,
+        leg_characters:         FloatingRateLegCharacters,
+        conventions:            FrnConventions,
+        settlement_date:        NaiveDate,
+        clean_price:            f64,
+    ) -> Self {
+        let reference_curve_name = leg_characters.index().reference_curve_name().clone();
+        let mut curve_name_map: HashMap<CurveFunction, String> = HashMap::new();
+        curve_name_map.insert(
+            CurveFunction::ProfitAndLossDiscount,
+            profit_and_loss_market.discount_curve_name().to_string(),
+        );
+        // 應計利息（結算日付款）也可能需要推算 index
+        curve_name_map.insert(CurveFunction::ReceiveForward, reference_curve_name.clone());
+        curve_name_map.insert(CurveFunction::PayForward, reference_curve_name);
+
+        Self {
+            position,
+            profit_and_loss_market,
+            leg_characters,
+            conventions,
+            settlement_date,
+            clean_price,
+            curve_name_map,
+        }
+    }
+
+    pub fn leg_characters(&self) -> &FloatingRateLegCharacters { &self.leg_characters }
+    pub fn conventions(&self) -> &FrnConventions { &self.conventions }
+    pub fn settlement_date(&self) -> NaiveDate { self.settlement_date }
+    pub fn clean_price(&self) -> f64 { self.clean_price }
+    pub fn quoted_margin(&self) -> f64 { self.leg_characters.spread() }
+
+    fn schedule(&self) -> &Schedule {
+        self.leg_characters.generic_characters().schedule()
+    }
+
+    fn sign(&self) -> f64 {
+        self.position as i32 as f64
+    }
+
+    fn year_fraction(&self, start_date: NaiveDate, end_date: NaiveDate) -> f64 {
+        self.leg_characters.generic_characters().day_counter().year_fraction(start_date, end_date)
+    }
+
+    /// 包含 `settlement_date` 的計息期 index。
+    fn accrual_period_index(&self, settlement_date: NaiveDate) -> Result<usize, FrnError> {
+        let periods = self.schedule().schedule_periods();
+        let start_date = periods[0].calculation_period().start_date();
+        let maturity_date = periods[periods.len() - 1].calculation_period().end_date();
+        if settlement_date < start_date || settlement_date >= maturity_date {
+            return Err(FrnError::SettlementOutsideSchedule { settlement_date, start_date, maturity_date });
+        }
+        Ok(periods.partition_point(|p| p.calculation_period().end_date() <= settlement_date))
+    }
+
+    fn index_rounded(&self, rate: f64, pricing_condition: &PricingCondition) -> f64 {
+        let digits = self.profit_and_loss_market.settlement_currency().digits();
+        match pricing_condition.floating_index_rounding_digits(digits) {
+            Some(d) => round(rate, d),
+            None    => rate,
+        }
+    }
+
+    /// 第 k 期的 index 利率 L_k（已做 index rounding）。
+    ///
+    /// 有 forward curve 時交給 leg 的 FixingRateCalculator；
+    /// 沒有時只能使用 past fixings，需要推算則回傳 Err。
+    fn index_rate(
+        &self,
+        k: usize,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> Result<f64, FrnError> {
+        let period = self.schedule().schedule_periods()[k].calculation_period();
+        let rate = match forward_curve_opt {
+            Some(curve) => Some(self.leg_characters.fixing_rate_calculator().fixing(k, curve, pricing_condition)),
+            None => self.leg_characters.index().fixing_rate_for_period(&period, None, pricing_condition),
+        };
+        rate.map(|r| self.index_rounded(r, pricing_condition))
+            .ok_or(FrnError::MissingIndexRate { start_date: period.start_date(), end_date: period.end_date() })
+    }
+
+    /// 第 k 期每單位本金的票息。
+    fn coupon(
+        &self,
+        k: usize,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> Result<f64, FrnError> {
+        let rate = self.leg_characters.leverage() * self.index_rate(k, forward_curve_opt, pricing_condition)?
+            + self.quoted_margin();
+        let compounding = self.leg_characters.generic_characters().compounding();
+        Ok(compounding.future_value(rate, self.leg_characters.taus()[k]) - 1.0)
+    }
+
+    // ── 價格 / margin ────────────────────────────────────────────────────────
+
+    /// 每 100 面額的應計利息。
+    pub fn accrued_interest(
+        &self,
+        settlement_date:   NaiveDate,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> Result<f64, FrnError> {
+        let k = self.accrual_period_index(settlement_date)?;
+        let start_date = self.schedule().schedule_periods()[k].calculation_period().start_date();
+        if settlement_date == start_date {
+            return Ok(0.0);
+        }
+
+        let index = self.leg_characters.index();
+        let index_rate = match index.index_type() {
+            InterestRateIndexType::TermRate => self.index_rate(k, forward_curve_opt, pricing_condition)?,
+            InterestRateIndexType::CompoundingRate => {
+                let accrual_period = CalculationPeriod::regular(start_date, settlement_date);
+                let rate = index
+                    .fixing_rate_for_period(&accrual_period, forward_curve_opt, pricing_condition)
+                    .ok_or(FrnError::MissingIndexRate { start_date, end_date: settlement_date })?;
+                self.index_rounded(rate, pricing_condition)
+            }
+        };
+        let rate = self.leg_characters.leverage() * index_rate + self.quoted_margin();
+        Ok(PRICE_BASE * rate * self.year_fraction(start_date, settlement_date))
+    }
+
+    /// 結算日之後的各期資料；票息與 index 利率以 forward curve 推算。
+    fn margin_flows(
+        &self,
+        settlement_date:   NaiveDate,
+        forward_curve:     &Arc<dyn InterestRateCurve>,
+        pricing_condition: &PricingCondition,
+    ) -> Result<Vec<MarginFlow>, FrnError> {
+        let k = self.accrual_period_index(settlement_date)?;
+        let periods = self.schedule().schedule_periods();
+        (k..periods.len())
+            .map(|j| {
+                let period = periods[j].calculation_period();
+                let accrual_start = period.start_date().max(settlement_date);
+                Ok(MarginFlow {
+                    accrual:    self.year_fraction(accrual_start, period.end_date()),
+                    index_rate: self.index_rate(j, Some(forward_curve), pricing_condition)?,
+                    amount:     PRICE_BASE * self.coupon(j, Some(forward_curve), pricing_condition)?,
+                })
+            })
+            .collect()
+    }
+
+    fn dirty_price_from_margin_flows(&self, discount_margin: f64, flows: &[MarginFlow]) -> f64 {
+        let redemption = PRICE_BASE * self.conventions.redemption;
+        let (price, discount) = flows.iter().fold((0.0, 1.0), |(price, discount), flow| {
+            let discount = discount / (1.0 + (flow.index_rate + discount_margin) * flow.accrual);
+            (price + flow.amount * discount, discount)
+        });
+        price + redemption * discount
+    }
+
+    pub fn dirty_price_from_discount_margin(
+        &self,
+        discount_margin:   f64,
+        settlement_date:   NaiveDate,
+        forward_curve:     &Arc<dyn InterestRateCurve>,
+        pricing_condition: &PricingCondition,
+    ) -> Result<f64, FrnError> {
+        let flows = self.margin_flows(settlement_date, forward_curve, pricing_condition)?;
+        Ok(self.dirty_price_from_margin_flows(discount_margin, &flows))
+    }
+
+    pub fn clean_price_from_discount_margin(
+        &self,
+        discount_margin:   f64,
+        settlement_date:   NaiveDate,
+        forward_curve:     &Arc<dyn InterestRateCurve>,
+        pricing_condition: &PricingCondition,
+    ) -> Result<f64, FrnError> {
+        let dirty_price = self.dirty_price_from_discount_margin(
+            discount_margin, settlement_date, forward_curve, pricing_condition,
+        )?;
+        Ok(dirty_price - self.accrued_interest(settlement_date, Some(forward_curve), pricing_condition)?)
+    }
+
+    /// 以 RootSolver 由 clean price 反推 discount margin。
+    pub fn discount_margin_from_clean_price(
+        &self,
+        clean_price:       f64,
+        settlement_date:   NaiveDate,
+        forward_curve:     &Arc<dyn InterestRateCurve>,
+        pricing_condition: &PricingCondition,
+    ) -> Result<f64, FrnError> {
+        let dirty_price = clean_price + self.accrued_interest(settlement_date, Some(forward_curve), pricing_condition)?;
+        // 預先算好各期資料，objective 只做折現
+        let flows = self.margin_flows(settlement_date, forward_curve, pricing_condition)?;
+
+        let objective = |dm: f64| self.dirty_price_from_margin_flows(dm, &flows) - dirty_price;
+        let initial_guess = self.quoted_margin();
+        Ok(RootSolver::with_defaults().solve(objective, initial_guess, Some(initial_guess + MARGIN_SOLVER_STEP))?)
+    }
+
+    /// QM + (100 R − clean) / (100 T)。
+    pub fn simple_margin(&self, clean_price: f64, settlement_date: NaiveDate) -> Result<f64, FrnError> {
+        self.accrual_period_index(settlement_date)?;
+        let time_to_maturity = self.year_fraction(settlement_date, self.leg_characters.generic_characters().maturity_date());
+        let redemption = PRICE_BASE * self.conventions.redemption;
+        Ok(self.quoted_margin() + (redemption - clean_price) / (PRICE_BASE * time_to_maturity))
+    }
+
+    /// (100 QM + (100 R − dirty) / T) / dirty：以投入的 dirty price 調整後的 margin。
+    pub fn adjusted_simple_margin(
+        &self,
+        clean_price:       f64,
+        settlement_date:   NaiveDate,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> Result<f64, FrnError> {
+        let dirty_price = clean_price + self.accrued_interest(settlement_date, forward_curve_opt, pricing_condition)?;
+        let time_to_maturity = self.year_fraction(settlement_date, self.leg_characters.generic_characters().maturity_date());
+        let redemption = PRICE_BASE * self.conventions.redemption;
+        Ok((PRICE_BASE * self.quoted_margin() + (redemption - dirty_price) / time_to_maturity) / dirty_price)
+    }
+
+    // ── Flows ────────────────────────────────────────────────────────────────
+
+    fn rounded_flow(&self, flow: f64, floating: bool, pricing_condition: &PricingCondition) -> f64 {
+        let digits = self.profit_and_loss_market.settlement_currency().digits();
+        let rounding_digits_opt = if floating {
+            pricing_condition.floating_flow_rounding_digits(digits)
+        } else {
+            pricing_condition.fixed_flow_rounding_digits(digits)
+        };
+        rounding_digits_opt.map_or(flow, |d| round(flow, d))
+    }
+
+    /// 買方收取的票息與還本（含 position 方向）；無法取得 index 利率的票息為 NaN。
+    fn receive_flows(
+        &self,
+        projected:         bool,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        let notional = self.sign() * self.conventions.nominal;
+
+        let periods = self.schedule().schedule_periods();
+        let mut cash_flows = CashFlows::new();
+        for (k, period) in periods.iter().enumerate() {
+            let payment_date = period.payment_date();
+            if payment_date > self.settlement_date
+                && is_projected(payment_date, pricing_condition) == projected
+            {
+                let flow = self
+                    .coupon(k, forward_curve_opt, pricing_condition)
+                    .map_or(f64::NAN, |coupon| notional * coupon);
+                cash_flows[&payment_date] += self.rounded_flow(flow, true, pricing_condition);
+            }
+        }
+
+        // 還本與最後一期票息同日支付
+        let maturity_date_opt = periods
+            .last()
+            .map(|period| period.payment_date())
+            .filter(|d| is_projected(*d, pricing_condition) == projected);
+        if let Some(maturity_date) = maturity_date_opt {
+            cash_flows[&maturity_date] +=
+                self.rounded_flow(notional * self.conventions.redemption, false, pricing_condition);
+        }
+        cash_flows
+    }
+
+    /// 結算日支付的 dirty amount（正值）；應計利息無法計算時為 NaN。
+    fn settlement_amount(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> f64 {
+        let amount = self
+            .accrued_interest(self.settlement_date, forward_curve_opt, pricing_condition)
+            .map_or(f64::NAN, |accrued| {
+                self.sign() * self.conventions.nominal * (self.clean_price + accrued) / PRICE_BASE
+            });
+        self.rounded_flow(amount, true, pricing_condition)
+    }
+}
+
+
+impl Instrument for FloatingRateNote {
+    fn max_date(&self) -> NaiveDate {
+        self.leg_characters.max_date()
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+
+impl InstrumentWithLinearFlows for FloatingRateNote {
+    fn past_pay_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if !is_projected(self.settlement_date, pricing_condition) {
+            cash_flows[&self.settlement_date] += self.settlement_amount(None, pricing_condition);
+        }
+        cash_flows
+    }
+
+    fn past_receive_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        self.receive_flows(false, None, pricing_condition)
+    }
+
+    fn projected_pay_flows(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if is_projected(self.settlement_date, pricing_condition) {
+            cash_flows[&self.settlement_date] -= self.settlement_amount(forward_curve_opt, pricing_condition);
+        }
+        cash_flows
+    }
+
+    fn projected_receive_flows(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        self.receive_flows(true, forward_curve_opt, pricing_condition)
+    }
+}
+
+impl SimpleInstrument for FloatingRateNote {}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FloatingRateNoteGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// Quoted margin 存在 leg generator 的 setter（spread）；成交 clean price 以 RwLock 保存。
+
+pub struct FloatingRateNoteGenerator {
+    profit_and_loss_market: Arc<dyn Market>,
+    leg_character_genrator: Arc<FloatingRateLegCharactersGenerator>,
+    conventions:            FrnConventions,
+    clean_price:            RwLock<f64>,
+}
+
+impl FloatingRateNoteGenerator {
+    pub fn new(
+        profit_and_loss_market: Arc<dyn Market>,
+        leg_character_genrator: Arc<FloatingRateLegCharactersGenerator>,
+        conventions:            FrnConventions,
+    ) -> Self {
+        Self {
+            profit_and_loss_market,
+            leg_character_genrator,
+            conventions,
+            clean_price: RwLock::new(PRICE_BASE),
+        }
+    }
+
+    /// 使用預設慣例（面額還本、T+0）。
+    pub fn with_defaults(
+        profit_and_loss_market: Arc<dyn Market>,
+        leg_character_genrator: Arc<FloatingRateLegCharactersGenerator>,
+    ) -> Self {
+        Self::new(profit_and_loss_market, leg_character_genrator, FrnConventions::default())
+    }
+
+    pub fn leg_character_genrator(&self) -> &Arc<FloatingRateLegCharactersGenerator> { &self.leg_character_genrator }
+    pub fn conventions(&self) -> &FrnConventions { &self.conventions }
+
+    pub fn quoted_margin(&self) -> f64 {
+        self.leg_character_genrator.setter().spread()
+    }
+
+    pub fn set_quoted_margin(&self, quoted_margin: f64) {
+        self.leg_character_genrator.setter().set_spread(quoted_margin);
+    }
+
+    pub fn clean_price(&self) -> f64 {
+        *self.clean_price.read().unwrap()
+    }
+
+    pub fn set_clean_price(&self, clean_price: f64) {
+        *self.clean_price.write().unwrap() = clean_price;
+    }
+
+    pub fn settlement_date(&self, trade_date: NaiveDate) -> NaiveDate {
+        self.leg_character_genrator
+            .calendar()
+            .shift_n_business_day(trade_date, self.conventions.settlement_lag as i32)
+    }
+
+    fn build_note(&self, position: Position, schedule: Schedule, settlement_date: NaiveDate) -> Arc<FloatingRateNote> {
+        Arc::new(FloatingRateNote::new(
+            position,
+            self.profit_and_loss_market.clone(),
+            self.leg_character_genrator.generate_floating_with_schedule(schedule),
+            self.conventions,
+            settlement_date,
+            self.clean_price(),
+        ))
+    }
+
+    /// `start_date_opt`（起息日）省略時，以結算日前最近一個票息日起算。
+    pub fn generate_note_with_maturity_date(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_date:  NaiveDate,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<FloatingRateNote>, String> {
+        let settlement_date = self.settlement_date(trade_date);
+        let leg = &self.leg_character_genrator;
+        let start_date = match start_date_opt {
+            Some(start_date) => start_date,
+            None => {
+                let frequency = leg.schedule_generator().calculation_period_generator().frequency();
+                previous_coupon_date(frequency, maturity_date, settlement_date)
+                    .ok_or_else(|| format!("cannot infer start date from coupon frequency {frequency}"))?
+            }
+        };
+        let schedule = leg
+            .schedule_generator()
+            .generate_with_maturity_date(
+                trade_date,
+                maturity_date,
+                leg.calendar(),
+                leg.fixing_calendar(),
+                leg.payment_calendar(),
+                Some(start_date),
+            )
+            .ok_or_else(|| format!("failed to generate FRN schedule {start_date}/{maturity_date}"))?;
+        Ok(self.build_note(position, schedule, settlement_date))
+    }
+
+    /// 新發行 FRN：`start_date_opt` 省略時自結算日起息。
+    pub fn generate_note_with_maturity_tenor(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_tenor: Period,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<FloatingRateNote>, String> {
+        let settlement_date = self.settlement_date(trade_date);
+        let leg = &self.leg_character_genrator;
+        let schedule = leg
+            .schedule_generator()
+            .generate_from_maturity_tenor(
+                trade_date,
+                maturity_tenor,
+                leg.calendar(),
+                leg.fixing_calendar(),
+                leg.payment_calendar(),
+                Some(start_date_opt.unwrap_or(settlement_date)),
+            )
+            .ok_or_else(|| format!("failed to generate FRN schedule for tenor {maturity_tenor}"))?;
+        Ok(self.build_note(position, schedule, settlement_date))
+    }
+}
+
+impl SimpleInterestRateInstrumentGenerator for FloatingRateNoteGenerator {
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn generate_with_maturity_date(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_date:  NaiveDate,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let note = self.generate_note_with_maturity_date(position, trade_date, maturity_date, start_date_opt)?;
+        Ok(note)
+    }
+
+    fn generate_with_maturity_tenor(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_tenor: Period,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let note = self.generate_note_with_maturity_tenor(position, trade_date, maturity_tenor, start_date_opt)?;
+        Ok(note)
+    }
+}
+
+
+// ═════════════════════════════════════════════════════════════════════════════
+// FloatingRateNoteGeneratorLoader
+// ═════════════════════════════════════════════════════════════════════════════
+//
+// JSON 範例（SOFR 複利 FRN，lookback 設定在 index 上，T+2 結算）：
+//   {
+//     "name": "USD_SOFR_FRN",
+//     "market": "USD_MARKET",
+//     "calendar": "NewYorkBank",
+//     "schedule_generator": "3MModifiedFollowing",
+//     "day_counter_generator": "ACT360",
+//     "index": "SOFR_LOOKBACK5",
+//     "quoted_margin": 0.0045,
+//     "nominal": 1000000.0,
+//     "settlement_lag": 2
+//   }
+//
+// `index` 可為 TermRate 或 CompoundingRate；兩者皆經由 TermRateCalculatorGenerator
+// 委託 index 計算（CompoundingRate 不套用 stub convention）。
+// `fixing_calendar` / `payment_calendar` 省略時同 `calendar`；
+// `redemption` 省略時為 1.0；`leverage` 省略時為 1.0。票息以 Simple compounding 計算。
+
+const DEFAULT_FRN_NOMINAL: f64 = 1_000_000.0;
+
+fn default_frn_nominal() -> f64 {
+    DEFAULT_FRN_NOMINAL
+}
+
+fn default_redemption() -> f64 {
+    1.0
+}
+
+fn default_leverage() -> f64 {
+    1.0
+}
+
+#[derive(Deserialize)]
+struct FloatingRateNoteGeneratorJsonProp {
+    market:                String,
+    calendar:              String,
+    #[serde(default)]
+    fixing_calendar:       Option<String>,
+    #[serde(default)]
+    payment_calendar:      Option<String>,
+    schedule_generator:    String,
+    day_counter_generator: String,
+    index:                 String,
+    #[serde(default)]
+    quoted_margin:         f64,
+    #[serde(default = "default_leverage")]
+    leverage:              f64,
+    #[serde(default)]
+    stub_rate_convention:  StubRateConvention,
+    #[serde(default = "default_frn_nominal")]
+    nominal:               f64,
+    #[serde(default = "default_redemption")]
+    redemption:            f64,
+    #[serde(default)]
+    settlement_lag:        u32,
+}
+
+pub struct FloatingRateNoteGeneratorLoader;
+
+impl<'a> JsonLoader<FloatingRateNoteGenerator, InterestRateInstrumentSupports<'a>> for FloatingRateNoteGeneratorLoader {
+    fn insert_obj_from_json(
+        &self,
+        builder:    &mut ManagerBuilder<FloatingRateNoteGenerator>,
+        json_value: serde_json::Value,
+        supports:   &InterestRateInstrumentSupports<'a>,
+    ) -> Result<(), ManagerError> {
+        let named: Named<FloatingRateNoteGeneratorJsonProp> = parse_json_value(json_value)?;
+        let prop = named.inner;
+
+        let market  = supports.0.get(&prop.market)?;
+        let cal     = supports.1.get(&prop.calendar)?;
+        let fix_cal = match &prop.fixing_calendar {
+            Some(name) => supports.1.get(name)?,
+            None       => cal.clone(),
+        };
+        let pay_cal = match &prop.payment_calendar {
+            Some(name) => supports.1.get(name)?,
+            None       => cal.clone(),
+        };
+        let sched   = supports.2.get(&prop.schedule_generator)?;
+        let dcg     = supports.3.get(&prop.day_counter_generator)?;
+        let index   = supports.4.get(&prop.index)?;
+
+        let setter = LegCharactersSetter::new();
+        setter.set_spread(prop.quoted_margin);
+        setter.set_leverage(prop.leverage);
+        let calc_gen: Arc<dyn FixingRateCalculatorGenerator> = match index.clone().compounding_rate_index() {
+            Some(compounding_index) => Arc::new(CompoundingRateIndexCalculatorGenerator::new(compounding_index)),
+            None => Arc::new(TermRateCalculatorGenerator::new(index.clone(), prop.stub_rate_convention)),
+        };
+        let leg = FloatingRateLegCharactersGenerator::new(
+            cal, fix_cal, pay_cal, sched, dcg, Compounding::Simple, setter, index, calc_gen,
+        );
+
+        let conventions = FrnConventions {
+            nominal:        prop.nominal,
+            redemption:     prop.redemption,
+            settlement_lag: prop.settlement_lag,
+        };
+        builder.insert(named.name, Arc::new(FloatingRateNoteGenerator::new(market, Arc::new(leg), conventions)));
+        Ok(())
+    }
+}
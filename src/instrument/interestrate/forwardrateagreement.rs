@@ -0,0 +1,455 @@
+// ── forwardrateagreement.rs ───────────────────────────────────────────────────
+//
+// Forward Rate Agreement（FRA），以 TermRate index（IBOR / TAIBOR）結算。
+//
+// # 合約
+//
+// "AxB" FRA：spot（trade date + index start_lag 營業日）起 A 個月開始、
+// B 個月結束，日期以 index 的 adjuster / calendar 調整。
+//
+// # 結算（ISDA FRA Discounting）
+//
+// 於起始日以 index fixing L 結算，並以 L 本身把期末差額折回起始日：
+//
+//   settlement = N τ (L − K) / (1 + L τ)
+//
+// τ 使用 index 的 day counter；Buy = 收浮動 / 付固定（long FRA）。
+// 拆成兩個同日 flow 表示（兩者都隨 L 變動）：
+//
+//   receive：N τ L / (1 + L τ)
+//   pay    ：N τ K / (1 + L τ)
+//
+// 校準時 NPV = 0 ⇔ L_model = K。
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::instrument::instrument::{
+    CurveFunction,
+    Instrument,
+    InstrumentWithLinearFlows,
+    Position,
+    SimpleInstrument,
+};
+use crate::instrument::interestrate::simpleinterestrateinstrumentgenerator::SimpleInterestRateInstrumentGenerator;
+use crate::instrument::leg::legcharactersgeneratorloader::InterestRateInstrumentSupports;
+use crate::interestrate::index::interestrateindex::{InterestRateIndex, InterestRateIndexType};
+use crate::manager::manager::{JsonLoader, ManagerBuilder};
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
+use crate::market::market::Market;
+use crate::math::round::round;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::pricingcondition::PricingCondition;
+use crate::time::period::Period;
+use crate::time::schedule::scheduleperiod::CalculationPeriod;
+use crate::value::cashflows::CashFlows;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FraTenor
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// "AxB" 形式的 FRA 期間：spot 起算的開始 / 結束 tenor。
+///
+/// 兩側可為純數字（視為月，如 `"3x6"`）或 tenor 字串（如 `"1Mx4M"`）。
+#[derive(Clone, Copy)]
+pub struct FraTenor {
+    start: Period,
+    end:   Period,
+}
+
+impl FraTenor {
+    pub fn new(start: Period, end: Period) -> Self {
+        Self { start, end }
+    }
+
+    /// 解析 `"3x6"`；不是 "AxB" 形式時回傳 None。
+    pub fn parse(key: &str) -> Option<Result<Self, String>> {
+        let (start, end) = key.split_once(['x', 'X'])?;
+        let parse_side = |text: &str| -> Result<Period, String> {
+            let text = text.trim();
+            match text.parse::<i32>() {
+                Ok(months) => Ok(Period::months(months)),
+                Err(_) if text.is_empty() => Err(format!("empty FRA tenor in \"{key}\"")),
+                Err(_) => Period::parse(text).map_err(|e| e.to_string()),
+            }
+        };
+        Some(parse_side(start).and_then(|s| Ok(Self::new(s, parse_side(end)?))))
+    }
+
+    pub fn start(&self) -> Period { self.start }
+    pub fn end(&self) -> Period { self.end }
+}
+
+impl fmt::Display for FraTenor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.start, self.end)
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ForwardRateAgreement
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct ForwardRateAgreement {
+    position:               Position,
+    profit_and_loss_market: Arc<dyn Market>,
+    index:                  Arc<dyn InterestRateIndex + Send + Sync>,
+    nominal:                f64,
+    fixed_rate:             f64,
+    period:                 CalculationPeriod,
+    accrual_factor:         f64,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl ForwardRateAgreement {
+    pub fn new(
+        position:               Position,
+        profit_and_loss_market: Arc<dyn Market>,
+        index:                  Arc<dyn InterestRateIndex + Send + Sync>,
+        nominal:                f64,
+        fixed_rate:             f64,
+        start_date:             NaiveDate,
+        end_date:               NaiveDate,
+    ) -> Self {
+        let accrual_factor = index.day_counter().year_fraction(start_date, end_date);
+
+        // 兩個 flow 都含 L 的折現因子，pay / receive 皆以 index 的 reference curve 推算
+        let mut curve_name_map: HashMap<CurveFunction, String> = HashMap::new();
+        curve_name_map.insert(
+            CurveFunction::ProfitAndLossDiscount,
+            profit_and_loss_market.discount_curve_name().to_string(),
+        );
+        curve_name_map.insert(CurveFunction::ReceiveForward, index.reference_curve_name().clone());
+        curve_name_map.insert(CurveFunction::PayForward, index.reference_curve_name().clone());
+
+        Self {
+            position,
+            profit_and_loss_market,
+            index,
+            nominal,
+            fixed_rate,
+            period: CalculationPeriod::regular(start_date, end_date),
+            accrual_factor,
+            curve_name_map,
+        }
+    }
+
+    pub fn nominal(&self) -> f64 { self.nominal }
+    pub fn fixed_rate(&self) -> f64 { self.fixed_rate }
+    pub fn start_date(&self) -> NaiveDate { self.period.start_date() }
+    pub fn end_date(&self) -> NaiveDate { self.period.end_date() }
+    pub fn accrual_factor(&self) -> f64 { self.accrual_factor }
+
+    fn sign(&self) -> f64 {
+        self.position as i32 as f64
+    }
+
+    /// 結算日（= 起始日）是否仍在 horizon 之後。
+    fn is_projected(&self, pricing_condition: &PricingCondition) -> bool {
+        let horizon = *pricing_condition.horizon();
+        let settlement_date = self.period.start_date();
+        settlement_date > horizon
+            || (settlement_date == horizon && *pricing_condition.include_horizon_flow())
+    }
+
+    /// 結算利率 L；需要推算而沒有 forward curve（或缺 past fixing）時回傳 None。
+    pub fn settlement_rate(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        let horizon = *pricing_condition.horizon();
+        let start_date = self.period.start_date();
+        let is_past = start_date < horizon
+            || (start_date == horizon && !pricing_condition.estimate_horizon_index());
+        if !is_past && forward_curve_opt.is_none() {
+            return None;
+        }
+
+        let digits = self.profit_and_loss_market.settlement_currency().digits();
+        let rate = self.index.fixing_rate_for_period(&self.period, forward_curve_opt, pricing_condition)?;
+        Some(match pricing_condition.floating_index_rounding_digits(digits) {
+            Some(d) => round(rate, d),
+            None    => rate,
+        })
+    }
+
+    /// 以 (1 + L τ) 折回起始日的期末金額 N τ rate。
+    fn discounted_flow(&self, rate: f64, settlement_rate: f64, pricing_condition: &PricingCondition) -> f64 {
+        let digits = self.profit_and_loss_market.settlement_currency().digits();
+        let tau = self.accrual_factor;
+        let flow = self.sign() * self.nominal * tau * rate / (1.0 + settlement_rate * tau);
+        match pricing_condition.floating_flow_rounding_digits(digits) {
+            Some(d) => round(flow, d),
+            None    => flow,
+        }
+    }
+
+    /// (receive, pay) 兩個 flow 金額（皆為正向表示）。
+    fn settlement_flows(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> (f64, f64) {
+        match self.settlement_rate(forward_curve_opt, pricing_condition) {
+            Some(l) => (
+                self.discounted_flow(l, l, pricing_condition),
+                self.discounted_flow(self.fixed_rate, l, pricing_condition),
+            ),
+            None => (f64::NAN, f64::NAN),
+        }
+    }
+}
+
+
+impl Instrument for ForwardRateAgreement {
+    fn max_date(&self) -> NaiveDate {
+        self.period.end_date()
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+
+impl InstrumentWithLinearFlows for ForwardRateAgreement {
+    fn past_pay_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if !self.is_projected(pricing_condition) {
+            let (_, pay) = self.settlement_flows(None, pricing_condition);
+            cash_flows[&self.period.start_date()] += pay;
+        }
+        cash_flows
+    }
+
+    fn past_receive_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if !self.is_projected(pricing_condition) {
+            let (receive, _) = self.settlement_flows(None, pricing_condition);
+            cash_flows[&self.period.start_date()] += receive;
+        }
+        cash_flows
+    }
+
+    fn projected_pay_flows(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if self.is_projected(pricing_condition) {
+            let (_, pay) = self.settlement_flows(forward_curve_opt, pricing_condition);
+            cash_flows[&self.period.start_date()] -= pay;
+        }
+        cash_flows
+    }
+
+    fn projected_receive_flows(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if self.is_projected(pricing_condition) {
+            let (receive, _) = self.settlement_flows(forward_curve_opt, pricing_condition);
+            cash_flows[&self.period.start_date()] += receive;
+        }
+        cash_flows
+    }
+}
+
+impl SimpleInstrument for ForwardRateAgreement {}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FraGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FraGenerator {
+    profit_and_loss_market: Arc<dyn Market>,
+    index:                  Arc<dyn InterestRateIndex + Send + Sync>,
+    nominal:                f64,
+    fixed_rate:             RwLock<f64>,
+}
+
+impl FraGenerator {
+    pub fn new(
+        profit_and_loss_market: Arc<dyn Market>,
+        index:                  Arc<dyn InterestRateIndex + Send + Sync>,
+        nominal:                f64,
+    ) -> Self {
+        Self {
+            profit_and_loss_market,
+            index,
+            nominal,
+            fixed_rate: RwLock::new(0.0),
+        }
+    }
+
+    pub fn index(&self) -> &Arc<dyn InterestRateIndex + Send + Sync> { &self.index }
+    pub fn nominal(&self) -> f64 { self.nominal }
+
+    pub fn fixed_rate(&self) -> f64 {
+        *self.fixed_rate.read().unwrap()
+    }
+
+    /// 設定 FRA rate（quote sheet 的報價）。
+    pub fn set_fixed_rate(&self, fixed_rate: f64) {
+        *self.fixed_rate.write().unwrap() = fixed_rate;
+    }
+
+    /// trade date + index start_lag 營業日。
+    pub fn spot_date(&self, trade_date: NaiveDate) -> NaiveDate {
+        self.index.calendar().shift_n_business_day(trade_date, self.index.start_lag() as i32)
+    }
+
+    fn date_from_spot(&self, spot_date: NaiveDate, tenor: Period) -> NaiveDate {
+        self.index.adjuster().from_tenor_to_date(spot_date, tenor, self.index.calendar())
+    }
+
+    /// 以 "AxB" 期間產生 FRA。
+    pub fn generate_with_fra_tenor(
+        &self,
+        position:   Position,
+        trade_date: NaiveDate,
+        fra_tenor:  FraTenor,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let spot_date = self.spot_date(trade_date);
+        let start_date = self.date_from_spot(spot_date, fra_tenor.start());
+        let end_date = self.date_from_spot(spot_date, fra_tenor.end());
+        self.generate_for_period(position, start_date, end_date)
+            .map_err(|e| format!("FRA {fra_tenor}: {e}"))
+    }
+
+    fn generate_for_period(
+        &self,
+        position:   Position,
+        start_date: NaiveDate,
+        end_date:   NaiveDate,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        if end_date <= start_date {
+            return Err(format!("FRA period {start_date}/{end_date} is empty"));
+        }
+        Ok(Arc::new(ForwardRateAgreement::new(
+            position,
+            self.profit_and_loss_market.clone(),
+            self.index.clone(),
+            self.nominal,
+            self.fixed_rate(),
+            start_date,
+            end_date,
+        )))
+    }
+}
+
+impl SimpleInterestRateInstrumentGenerator for FraGenerator {
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    /// `start_date_opt` 省略時，起始日為到期日往前一個 index tenor（再調整）。
+    fn generate_with_maturity_date(
+        &self,
+        position:       Position,
+        _trade_date:    NaiveDate,
+        maturity_date:  NaiveDate,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let start_date = start_date_opt.unwrap_or_else(|| {
+            self.index.adjuster().adjust(maturity_date - *self.index.tenor(), self.index.calendar())
+        });
+        self.generate_for_period(position, start_date, maturity_date)
+    }
+
+    /// `maturity_tenor` 視為 spot 起算的結束 tenor；
+    /// `start_date_opt` 省略時，期間長度為 index tenor（如 3M index 的 "6M" → 3x6）。
+    fn generate_with_maturity_tenor(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_tenor: Period,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let spot_date = self.spot_date(trade_date);
+        let end_date = self.date_from_spot(spot_date, maturity_tenor);
+        let start_date = start_date_opt.unwrap_or_else(|| {
+            let unadjusted_end = spot_date + maturity_tenor;
+            self.index.adjuster().adjust(unadjusted_end - *self.index.tenor(), self.index.calendar())
+        });
+        self.generate_for_period(position, start_date, end_date)
+    }
+}
+
+
+// ═════════════════════════════════════════════════════════════════════════════
+// FraGeneratorLoader
+// ═════════════════════════════════════════════════════════════════════════════
+//
+// JSON 範例（TAIBOR 3M FRA）：
+//   {
+//     "name": "TWD_FRA_3M",
+//     "market": "TWD_MARKET",
+//     "index": "TAIBOR_3M",
+//     "nominal": 1000000.0
+//   }
+//
+// `index` 必須為 TermRate 型別；`nominal` 省略時為 1,000,000。
+
+const DEFAULT_FRA_NOMINAL: f64 = 1_000_000.0;
+
+fn default_fra_nominal() -> f64 {
+    DEFAULT_FRA_NOMINAL
+}
+
+#[derive(Deserialize)]
+struct FraGeneratorJsonProp {
+    market:  String,
+    index:   String,
+    #[serde(default = "default_fra_nominal")]
+    nominal: f64,
+}
+
+pub struct FraGeneratorLoader;
+
+impl<'a> JsonLoader<FraGenerator, InterestRateInstrumentSupports<'a>> for FraGeneratorLoader {
+    fn insert_obj_from_json(
+        &self,
+        builder:    &mut ManagerBuilder<FraGenerator>,
+        json_value: serde_json::Value,
+        supports:   &InterestRateInstrumentSupports<'a>,
+    ) -> Result<(), ManagerError> {
+        let named: Named<FraGeneratorJsonProp> = parse_json_value(json_value)?;
+        let prop = named.inner;
+
+        let market = supports.0.get(&prop.market)?;
+        let index = supports.4.get(&prop.index)?;
+        if index.index_type() != InterestRateIndexType::TermRate {
+            return Err(ManagerError::InvalidValue(format!(
+                "FRA index '{}' must be a TermRate index", prop.index
+            )));
+        }
+
+        builder.insert(named.name, Arc::new(FraGenerator::new(market, index, prop.nominal)));
+        Ok(())
+    }
+}
@@ -0,0 +1,448 @@
+// ── fximplieddeposit.rs ───────────────────────────────────────────────────────
+//
+// FX implied deposit：把 FX forward points 報價表示成單一幣別的校準商品，
+// 用於校準以另一幣別為擔保（collateral）的折現曲線（例如 USD collateral 的 TWD 曲線）。
+//
+// # Covered interest parity
+//
+// 令 X 為被校準幣別（P&L market 幣別），C 為 FX market 中的另一幣別（擔保幣別），
+// s 為 spot date，T 為交割日，S / F 為 spot / outright（報價方向同 FX market：ccy2 per ccy1）。
+// 以 X 每單位 C 的匯率 Q（X = ccy2 時 Q = S, F；X = ccy1 時取倒數）表示：
+//
+//   F_Q D_X(T) = S_Q D_X(s) D_C(T) / D_C(s)
+//
+// # Flows（幣別 X，名目本金 N 以 C 計）
+//
+//   pay    ：s   N S_Q D_C(T) / D_C(s)   （C 的 forward 由 PayForward = C 折現曲線推算）
+//   receive：T   N F_Q
+//
+// NPV = 0 ⇔ 上式成立，因此 C 曲線固定時即可拔靴出 X 曲線。
+// outright F = S + points × points_unit（points_unit 為報價單位，例如 JPY 為 0.01）。
+//
+// # 限制
+//
+// 僅供曲線校準：spot date 已過時 pay flow 需要 C 曲線才能表示，past pay flow 為 NaN。
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::instrument::instrument::{
+    CurveFunction,
+    Instrument,
+    InstrumentWithLinearFlows,
+    Position,
+    SimpleInstrument,
+};
+use crate::instrument::interestrate::simpleinterestrateinstrumentgenerator::SimpleInterestRateInstrumentGenerator;
+use crate::manager::manager::{FrozenManager, JsonLoader, ManagerBuilder};
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
+use crate::market::fxmarket::FxMatket;
+use crate::market::market::Market;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::pricingcondition::PricingCondition;
+use crate::time::period::Period;
+use crate::value::cashflows::CashFlows;
+
+
+/// forward premium 年化時使用的天數基準。
+const PREMIUM_DAYS_IN_YEAR: f64 = 365.0;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxImpliedDepositError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum FxImpliedDepositError {
+    #[error("market currency {currency} is not part of the FX pair {pair}")]
+    CurrencyNotInPair {
+        currency: String,
+        pair:     String,
+    },
+
+    #[error("FX implied deposit value date {maturity_date} must differ from spot date {spot_date}")]
+    EmptyPeriod {
+        spot_date:     NaiveDate,
+        maturity_date: NaiveDate,
+    },
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxForwardQuote
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// spot 與 outright 報價（FX market 報價方向：ccy2 per ccy1）。
+#[derive(Debug, Clone, Copy)]
+pub struct FxForwardQuote {
+    pub spot:     f64,
+    pub outright: f64,
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxImpliedDeposit
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FxImpliedDeposit {
+    position:               Position,
+    profit_and_loss_market: Arc<dyn Market>,
+    nominal:                f64,
+    /// 以 X 每單位 C 表示的 spot / outright。
+    spot:                   f64,
+    outright:               f64,
+    spot_date:              NaiveDate,
+    maturity_date:          NaiveDate,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl FxImpliedDeposit {
+    /// P&L market 幣別為 ccy1 時，`quote` 內部轉為倒數。
+    ///
+    /// # Errors
+    ///
+    /// - P&L market 幣別不屬於 FX market 的貨幣對
+    /// - 交割日等於 spot date
+    pub fn new(
+        position:               Position,
+        profit_and_loss_market: Arc<dyn Market>,
+        fx_market:              &FxMatket,
+        nominal:                f64,
+        quote:                  FxForwardQuote,
+        spot_date:              NaiveDate,
+        maturity_date:          NaiveDate,
+    ) -> Result<Self, FxImpliedDepositError> {
+        let pair = fx_market.currency_pair();
+        let currency = profit_and_loss_market.settlement_currency().code();
+        let (collateral_curve_name, spot, outright) = if currency == pair.ccy2().code() {
+            (fx_market.foreign_discount_curve_name(), quote.spot, quote.outright)
+        } else if currency == pair.ccy1().code() {
+            (fx_market.domestic_discount_curve_name(), 1.0 / quote.spot, 1.0 / quote.outright)
+        } else {
+            return Err(FxImpliedDepositError::CurrencyNotInPair { currency, pair: pair.code() });
+        };
+        if maturity_date == spot_date {
+            return Err(FxImpliedDepositError::EmptyPeriod { spot_date, maturity_date });
+        }
+
+        let mut curve_name_map: HashMap<CurveFunction, String> = HashMap::new();
+        curve_name_map.insert(
+            CurveFunction::ProfitAndLossDiscount,
+            profit_and_loss_market.discount_curve_name().to_string(),
+        );
+        curve_name_map.insert(CurveFunction::PayForward, collateral_curve_name.to_string());
+
+        Ok(Self {
+            position,
+            profit_and_loss_market,
+            nominal,
+            spot,
+            outright,
+            spot_date,
+            maturity_date,
+            curve_name_map,
+        })
+    }
+
+    pub fn nominal(&self) -> f64 { self.nominal }
+    pub fn spot_date(&self) -> NaiveDate { self.spot_date }
+    pub fn maturity_date(&self) -> NaiveDate { self.maturity_date }
+
+    /// 年化 forward premium (F_Q / S_Q − 1) × 365 / days（≈ X 與 C 的利差）。
+    pub fn forward_premium_rate(&self) -> f64 {
+        let days = (self.maturity_date - self.spot_date).num_days() as f64;
+        (self.outright / self.spot - 1.0) * PREMIUM_DAYS_IN_YEAR / days
+    }
+
+    fn sign(&self) -> f64 {
+        self.position as i32 as f64
+    }
+
+    fn is_projected(date: NaiveDate, pricing_condition: &PricingCondition) -> bool {
+        let horizon = *pricing_condition.horizon();
+        date > horizon || (date == horizon && *pricing_condition.include_horizon_flow())
+    }
+
+    /// spot date 支付的 X 金額；沒有 C 曲線時為 NaN。
+    fn spot_amount(&self, collateral_curve_opt: Option<&Arc<dyn InterestRateCurve>>) -> f64 {
+        collateral_curve_opt.map_or(f64::NAN, |curve| {
+            let discount_curve = curve.to_discount_curve();
+            let forward_factor = discount_curve.discount(self.maturity_date) / discount_curve.discount(self.spot_date);
+            self.sign() * self.nominal * self.spot * forward_factor
+        })
+    }
+
+    fn maturity_amount(&self) -> f64 {
+        self.sign() * self.nominal * self.outright
+    }
+}
+
+
+impl Instrument for FxImpliedDeposit {
+    fn max_date(&self) -> NaiveDate {
+        self.maturity_date.max(self.spot_date)
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+
+impl InstrumentWithLinearFlows for FxImpliedDeposit {
+    fn past_pay_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if !Self::is_projected(self.spot_date, pricing_condition) {
+            cash_flows[&self.spot_date] += self.spot_amount(None);
+        }
+        cash_flows
+    }
+
+    fn past_receive_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if !Self::is_projected(self.maturity_date, pricing_condition) {
+            cash_flows[&self.maturity_date] += self.maturity_amount();
+        }
+        cash_flows
+    }
+
+    fn projected_pay_flows(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if Self::is_projected(self.spot_date, pricing_condition) {
+            cash_flows[&self.spot_date] -= self.spot_amount(forward_curve_opt);
+        }
+        cash_flows
+    }
+
+    fn projected_receive_flows(
+        &self,
+        _forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if Self::is_projected(self.maturity_date, pricing_condition) {
+            cash_flows[&self.maturity_date] += self.maturity_amount();
+        }
+        cash_flows
+    }
+}
+
+impl SimpleInstrument for FxImpliedDeposit {}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxImpliedDepositGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// spot 與 forward points 以 RwLock 保存，由 quote sheet 在產生商品前設定。
+
+pub struct FxImpliedDepositGenerator {
+    profit_and_loss_market: Arc<dyn Market>,
+    fx_market:              Arc<FxMatket>,
+    nominal:                f64,
+    points_unit:            f64,
+    spot:                   RwLock<f64>,
+    forward_points:         RwLock<f64>,
+}
+
+impl FxImpliedDepositGenerator {
+    pub fn new(
+        profit_and_loss_market: Arc<dyn Market>,
+        fx_market:              Arc<FxMatket>,
+        nominal:                f64,
+        points_unit:            f64,
+    ) -> Self {
+        Self {
+            profit_and_loss_market,
+            fx_market,
+            nominal,
+            points_unit,
+            spot:           RwLock::new(1.0),
+            forward_points: RwLock::new(0.0),
+        }
+    }
+
+    pub fn fx_market(&self) -> &Arc<FxMatket> { &self.fx_market }
+    pub fn nominal(&self) -> f64 { self.nominal }
+    pub fn points_unit(&self) -> f64 { self.points_unit }
+
+    pub fn spot(&self) -> f64 {
+        *self.spot.read().unwrap()
+    }
+
+    pub fn set_spot(&self, spot: f64) {
+        *self.spot.write().unwrap() = spot;
+    }
+
+    pub fn forward_points(&self) -> f64 {
+        *self.forward_points.read().unwrap()
+    }
+
+    /// 設定 forward points（quote sheet 的報價，單位見 `points_unit`）。
+    pub fn set_forward_points(&self, forward_points: f64) {
+        *self.forward_points.write().unwrap() = forward_points;
+    }
+
+    pub fn outright(&self) -> f64 {
+        self.spot() + self.forward_points() * self.points_unit
+    }
+
+    /// 以交割日產生（spot date 由 FX market 決定）。
+    pub fn generate_deposit_with_value_date(
+        &self,
+        position:      Position,
+        trade_date:    NaiveDate,
+        maturity_date: NaiveDate,
+    ) -> Result<Arc<FxImpliedDeposit>, String> {
+        FxImpliedDeposit::new(
+            position,
+            self.profit_and_loss_market.clone(),
+            &self.fx_market,
+            self.nominal,
+            FxForwardQuote { spot: self.spot(), outright: self.outright() },
+            self.fx_market.settlement_date(trade_date),
+            maturity_date,
+        )
+        .map(Arc::new)
+        .map_err(|e| e.to_string())
+    }
+
+    /// 以 spot 起算的 tenor 產生，交割日見 [`FxMatket::value_date`]。
+    pub fn generate_deposit_with_tenor(
+        &self,
+        position:   Position,
+        trade_date: NaiveDate,
+        tenor:      Period,
+    ) -> Result<Arc<FxImpliedDeposit>, String> {
+        let maturity_date = self.fx_market.value_date(trade_date, tenor);
+        self.generate_deposit_with_value_date(position, trade_date, maturity_date)
+    }
+}
+
+impl SimpleInterestRateInstrumentGenerator for FxImpliedDepositGenerator {
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    /// 報價一律相對 spot date，`start_date_opt` 不使用。
+    fn generate_with_maturity_date(
+        &self,
+        position:        Position,
+        trade_date:      NaiveDate,
+        maturity_date:   NaiveDate,
+        _start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let deposit = self.generate_deposit_with_value_date(position, trade_date, maturity_date)?;
+        Ok(deposit)
+    }
+
+    /// `start_date_opt` 不使用。
+    fn generate_with_maturity_tenor(
+        &self,
+        position:        Position,
+        trade_date:      NaiveDate,
+        maturity_tenor:  Period,
+        _start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let deposit = self.generate_deposit_with_tenor(position, trade_date, maturity_tenor)?;
+        Ok(deposit)
+    }
+}
+
+
+// ═════════════════════════════════════════════════════════════════════════════
+// FxImpliedDepositGeneratorLoader
+// ═════════════════════════════════════════════════════════════════════════════
+//
+// JSON 範例（USD collateral 的 TWD / JPY 曲線）：
+//   {
+//     "name": "TWD_FX_IMPLIED",
+//     "market": "TWD_USD_COLLATERAL_MARKET",
+//     "fx_market": "USDTWD",
+//     "points_unit": 0.001
+//   }
+//   {
+//     "name": "JPY_FX_IMPLIED",
+//     "market": "JPY_USD_COLLATERAL_MARKET",
+//     "fx_market": "USDJPY",
+//     "points_unit": 0.01
+//   }
+//
+// `market` 的幣別須為 FX market 的其中一個幣別，其 discount curve 即被校準的曲線；
+// `points_unit` 省略時為 1.0；`nominal`（以擔保幣別計）省略時為 1,000,000。
+
+const DEFAULT_FX_IMPLIED_NOMINAL: f64 = 1_000_000.0;
+
+fn default_fx_implied_nominal() -> f64 {
+    DEFAULT_FX_IMPLIED_NOMINAL
+}
+
+fn default_points_unit() -> f64 {
+    1.0
+}
+
+#[derive(Deserialize)]
+struct FxImpliedDepositGeneratorJsonProp {
+    market:      String,
+    fx_market:   String,
+    #[serde(default = "default_fx_implied_nominal")]
+    nominal:     f64,
+    #[serde(default = "default_points_unit")]
+    points_unit: f64,
+}
+
+/// [`FxImpliedDepositGeneratorLoader`] 的 supports：(market 查找, FX market 查找)。
+pub type FxImpliedDepositSupports<'a> = (
+    &'a FrozenManager<dyn Market>,
+    &'a FrozenManager<FxMatket>,
+);
+
+pub struct FxImpliedDepositGeneratorLoader;
+
+impl<'a> JsonLoader<FxImpliedDepositGenerator, FxImpliedDepositSupports<'a>> for FxImpliedDepositGeneratorLoader {
+    fn insert_obj_from_json(
+        &self,
+        builder:    &mut ManagerBuilder<FxImpliedDepositGenerator>,
+        json_value: serde_json::Value,
+        supports:   &FxImpliedDepositSupports<'a>,
+    ) -> Result<(), ManagerError> {
+        let named: Named<FxImpliedDepositGeneratorJsonProp> = parse_json_value(json_value)?;
+        let prop = named.inner;
+
+        let market = supports.0.get(&prop.market)?;
+        let fx_market = supports.1.get(&prop.fx_market)?;
+        let pair = fx_market.currency_pair();
+        let currency = market.settlement_currency().code();
+        if currency != pair.ccy1().code() && currency != pair.ccy2().code() {
+            return Err(ManagerError::InvalidValue(format!(
+                "FX implied deposit '{}': market currency {} is not part of {}",
+                named.name, currency, pair.code(),
+            )));
+        }
+
+        let generator = FxImpliedDepositGenerator::new(market, fx_market, prop.nominal, prop.points_unit);
+        builder.insert(named.name, Arc::new(generator));
+        Ok(())
+    }
+}
@@ -93,6 +93,14 @@ impl InterestRateSwap {
         &self.receive_leg_characters
     }
 
+    pub fn pay_leg_flow_observer_list(&self) -> &[FlowObserver] {
+        &self.pay_leg_flow_observer_list
+    }
+
+    pub fn receive_leg_flow_observer_list(&self) -> &[FlowObserver] {
+        &self.receive_leg_flow_observer_list
+    }
+
     fn build_flow_observer_list(
         leg_characters: &Arc<dyn LegCharacters>,
         nominals: Vec<f64>,
@@ -524,21 +532,16 @@ impl InterestRateSwapGenerator {
     pub fn receive_leg_nominal_generator(&self) -> &Arc<dyn NominalGenerator> {
         &self.receive_leg_nominal_generator
     }
-}
 
-
-impl SimpleInterestRateInstrumentGenerator for InterestRateSwapGenerator {
-    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
-        &self.profit_and_loss_market
-    }
-
-    fn generate_with_maturity_date(
-        &self, 
-        position: Position, 
+    /// 與 [`SimpleInterestRateInstrumentGenerator::generate_with_maturity_date`] 相同，
+    /// 但回傳具體型別，供 Swaption 等需要存取兩條腿的商品使用。
+    pub fn generate_swap_with_maturity_date(
+        &self,
+        position: Position,
         trade_date: NaiveDate,
         maturity_date: NaiveDate,
         start_date_opt: Option<NaiveDate>
-    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+    ) -> Result<InterestRateSwap, String> {
         let pay_leg_characters = self.pay_leg_character_genrator.generate_with_maturity_date(
             trade_date,
             maturity_date,
@@ -551,31 +554,18 @@ impl SimpleInterestRateInstrumentGenerator for InterestRateSwapGenerator {
             start_date_opt,
         )?;
 
-        let pay_leg_nominals = self.pay_leg_nominal_generator.generate_nominal(
-            pay_leg_characters.generic_characters().schedule()
-        );
-
-        let receive_leg_nominals = self.receive_leg_nominal_generator.generate_nominal(
-            receive_leg_characters.generic_characters().schedule()
-        );
-
-        Ok(Arc::new(InterestRateSwap::new(
-            position,
-            self.profit_and_loss_market.clone(),
-            pay_leg_characters,
-            pay_leg_nominals,
-            receive_leg_characters,
-            receive_leg_nominals,
-        )))
+        Ok(self.build_swap(position, pay_leg_characters, receive_leg_characters))
     }
 
-    fn generate_with_maturity_tenor(
-            &self, 
-            position: Position, 
-            trade_date: NaiveDate,
-            maturity_tenor: crate::time::period::Period,
-            start_date_opt: Option<NaiveDate>
-        ) -> Result<Arc<dyn SimpleInstrument>, String> {
+    /// 與 [`SimpleInterestRateInstrumentGenerator::generate_with_maturity_tenor`] 相同，
+    /// 但回傳具體型別。
+    pub fn generate_swap_with_maturity_tenor(
+        &self,
+        position: Position,
+        trade_date: NaiveDate,
+        maturity_tenor: crate::time::period::Period,
+        start_date_opt: Option<NaiveDate>
+    ) -> Result<InterestRateSwap, String> {
         let pay_leg_characters = self.pay_leg_character_genrator.generate_with_maturity_tenor(
             trade_date,
             maturity_tenor,
@@ -588,6 +578,15 @@ impl SimpleInterestRateInstrumentGenerator for InterestRateSwapGenerator {
             start_date_opt,
         )?;
 
+        Ok(self.build_swap(position, pay_leg_characters, receive_leg_characters))
+    }
+
+    fn build_swap(
+        &self,
+        position: Position,
+        pay_leg_characters: Arc<dyn LegCharacters>,
+        receive_leg_characters: Arc<dyn LegCharacters>,
+    ) -> InterestRateSwap {
         let pay_leg_nominals = self.pay_leg_nominal_generator.generate_nominal(
             pay_leg_characters.generic_characters().schedule()
         );
@@ -596,14 +595,43 @@ impl SimpleInterestRateInstrumentGenerator for InterestRateSwapGenerator {
             receive_leg_characters.generic_characters().schedule()
         );
 
-        Ok(Arc::new(InterestRateSwap::new(
+        InterestRateSwap::new(
             position,
             self.profit_and_loss_market.clone(),
             pay_leg_characters,
             pay_leg_nominals,
             receive_leg_characters,
             receive_leg_nominals,
-        )))
+        )
+    }
+}
+
+
+impl SimpleInterestRateInstrumentGenerator for InterestRateSwapGenerator {
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn generate_with_maturity_date(
+        &self, 
+        position: Position, 
+        trade_date: NaiveDate,
+        maturity_date: NaiveDate,
+        start_date_opt: Option<NaiveDate>
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let swap = self.generate_swap_with_maturity_date(position, trade_date, maturity_date, start_date_opt)?;
+        Ok(Arc::new(swap))
+    }
+
+    fn generate_with_maturity_tenor(
+            &self, 
+            position: Position, 
+            trade_date: NaiveDate,
+            maturity_tenor: crate::time::period::Period,
+            start_date_opt: Option<NaiveDate>
+        ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let swap = self.generate_swap_with_maturity_tenor(position, trade_date, maturity_tenor, start_date_opt)?;
+        Ok(Arc::new(swap))
     }
 }
 
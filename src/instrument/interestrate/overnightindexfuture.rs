@@ -0,0 +1,657 @@
+// ── overnightindexfuture.rs ───────────────────────────────────────────────────
+//
+// SOFR / ESTR 等隔夜利率期貨（1M serial 與 3M IMM 合約）。
+//
+// # 合約期間
+//
+// | ContractType | 參考期間                                   | 預設 averaging |
+// |--------------|--------------------------------------------|----------------|
+// | OneMonth     | 合約月份第一天 → 次月第一天                | Arithmetic     |
+// | ThreeMonth   | 合約月份第三個星期三 → 三個月後第三個星期三 | Compounded     |
+//
+// quote sheet 的日期 key（如 `"2025-06-18"`）只取年月決定合約月份；
+// `"start/end"` 形式則直接指定參考期間。
+//
+// # 期貨利率
+//
+// 參考期間依 index calendar 切成子區間 [n_i, n_{i+1})，
+// 每段利率 r_i：已過 horizon 取 index 的 past fixing（非營業日沿用前一營業日），
+// 其餘以 D(n_i) / D(n_{i+1}) 推算。
+//
+//   Arithmetic：R = Σ r_i δ_i / τ
+//   Compounded：R = (∏(1 + r_i δ_i) − 1) / τ   （未來部分以 D(n_k)/D(end) telescoping）
+//
+// δ_i、τ 使用 index 的 day counter。
+//
+// # 評價
+//
+// 報價 P → futures rate = (100 − P) / 100；forward rate = futures rate − CA。
+// 以 FRA 形式表示在參考期間結束日的 flow（Buy = long）：
+//
+//   receive：N τ (R_trade − CA)
+//   pay    ：N τ R_model
+//
+// 校準時 NPV = 0 ⇔ R_model = R_trade − CA。
+//
+// # Convexity adjustment
+//
+// - `ConstantTable`：依合約起始日查表，日期間線性插值、兩端平坦外插
+// - `HullWhite`：Hull-White 一因子模型隱含的期貨 / 遠期差（Kirikos–Novak），
+//   t、T 為 trade date 到參考期間起迄的年化時間（index day counter）
+//
+// CA 於產生商品時以 trade date 計算後固定。
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use serde::Deserialize;
+
+use crate::instrument::instrument::{
+    CurveFunction,
+    Instrument,
+    InstrumentWithLinearFlows,
+    Position,
+    SimpleInstrument,
+};
+use crate::instrument::interestrate::simpleinterestrateinstrumentgenerator::SimpleInterestRateInstrumentGenerator;
+use crate::instrument::leg::legcharactersgeneratorloader::InterestRateInstrumentSupports;
+use crate::interestrate::index::interestrateindex::{InterestRateIndex, InterestRateIndexType};
+use crate::manager::manager::{JsonLoader, ManagerBuilder};
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
+use crate::market::market::Market;
+use crate::math::round::round;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::pricingcondition::PricingCondition;
+use crate::time::period::Period;
+use crate::value::cashflows::CashFlows;
+
+
+/// Hull-White mean reversion 低於此值時改用 a → 0 的極限式。
+const MEAN_REVERSION_EPSILON: f64 = 1e-8;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Contract conventions
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum FutureContractType {
+    OneMonth,
+    ThreeMonth,
+}
+
+impl FutureContractType {
+    /// 合約月份（取 `contract_date` 的年月）對應的參考期間。
+    pub fn reference_period(&self, contract_date: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        let first = contract_date.with_day(1)?;
+        match self {
+            FutureContractType::OneMonth => Some((first, first.checked_add_months(Months::new(1))?)),
+            FutureContractType::ThreeMonth => {
+                let end_month = first.checked_add_months(Months::new(3))?;
+                Some((imm_date(first)?, imm_date(end_month)?))
+            }
+        }
+    }
+
+    pub fn default_averaging(&self) -> FutureAveraging {
+        match self {
+            FutureContractType::OneMonth   => FutureAveraging::Arithmetic,
+            FutureContractType::ThreeMonth => FutureAveraging::Compounded,
+        }
+    }
+}
+
+/// 該月第三個星期三。
+pub fn imm_date(month: NaiveDate) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(month.year(), month.month(), Weekday::Wed, 3)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum FutureAveraging {
+    Arithmetic,
+    Compounded,
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ConvexityAdjustment
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 期貨利率 − 遠期利率（正值代表期貨利率較高）。
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(tag = "type")]
+pub enum ConvexityAdjustment {
+    #[default]
+    None,
+    /// 合約起始日 → adjustment（小數，1bp = 1e-4）。
+    ConstantTable {
+        table: BTreeMap<NaiveDate, f64>,
+    },
+    HullWhite {
+        mean_reversion: f64,
+        volatility:     f64,
+    },
+}
+
+impl ConvexityAdjustment {
+    /// - `start`：參考期間起始日（查表用）
+    /// - `t`, `big_t`：trade date 到參考期間起迄的年化時間
+    /// - `futures_rate`：報價隱含的期貨利率
+    pub fn adjustment(&self, start: NaiveDate, t: f64, big_t: f64, futures_rate: f64) -> f64 {
+        match self {
+            ConvexityAdjustment::None => 0.0,
+            ConvexityAdjustment::ConstantTable { table } => Self::lookup(table, start),
+            ConvexityAdjustment::HullWhite { mean_reversion, volatility } => {
+                Self::hull_white(*mean_reversion, *volatility, t.max(0.0), big_t, futures_rate)
+            }
+        }
+    }
+
+    fn lookup(table: &BTreeMap<NaiveDate, f64>, start: NaiveDate) -> f64 {
+        let before = table.range(..=start).next_back();
+        let after = table.range(start..).next();
+        match (before, after) {
+            (Some((d0, v0)), Some((d1, v1))) if d0 != d1 => {
+                let w = (start - *d0).num_days() as f64 / (*d1 - *d0).num_days() as f64;
+                v0 + w * (v1 - v0)
+            }
+            (Some((_, v)), _) | (None, Some((_, v))) => *v,
+            (None, None) => 0.0,
+        }
+    }
+
+    /// Kirikos–Novak：CA = (1 − e^{−z}) (R_fut + 1/(T − t))，
+    /// z = σ²/2 · [B(T−t)² (1 − e^{−2at}) / a + B(T−t) B(t)²]，B(x) = (1 − e^{−ax}) / a；
+    /// a → 0 時 z = σ² δ t (T − t/2)，δ = T − t（Ho-Lee）。
+    fn hull_white(a: f64, sigma: f64, t: f64, big_t: f64, futures_rate: f64) -> f64 {
+        let delta = big_t - t;
+        if delta <= 0.0 {
+            return 0.0;
+        }
+        let b = |x: f64| if a.abs() < MEAN_REVERSION_EPSILON { x } else { (1.0 - (-a * x).exp()) / a };
+        let variance_t = if a.abs() < MEAN_REVERSION_EPSILON {
+            2.0 * t
+        } else {
+            (1.0 - (-2.0 * a * t).exp()) / a
+        };
+        let half_sigma_square = 0.5 * sigma * sigma;
+        let b_delta = b(delta);
+        let b_t = b(t);
+        let z = half_sigma_square * (b_delta * b_delta * variance_t + b_delta * b_t * b_t);
+        (1.0 - (-z).exp()) * (futures_rate + 1.0 / delta)
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// OvernightIndexFuture
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 合約條款：參考期間、averaging 與每口名目本金。
+#[derive(Debug, Clone, Copy)]
+pub struct FutureTerms {
+    pub averaging:         FutureAveraging,
+    pub start_date:        NaiveDate,
+    pub end_date:          NaiveDate,
+    pub contract_notional: f64,
+}
+
+pub struct OvernightIndexFuture {
+    position:               Position,
+    profit_and_loss_market: Arc<dyn Market>,
+    index:                  Arc<dyn InterestRateIndex + Send + Sync>,
+    terms:                  FutureTerms,
+    price:                  f64,
+    convexity_adjustment:   f64,
+    /// 參考期間的子區間節點：start、期間內營業日、end。
+    accrual_nodes:          Vec<NaiveDate>,
+    accrual_factor:         f64,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl OvernightIndexFuture {
+    pub fn new(
+        position:               Position,
+        profit_and_loss_market: Arc<dyn Market>,
+        index:                  Arc<dyn InterestRateIndex + Send + Sync>,
+        terms:                  FutureTerms,
+        price:                  f64,
+        convexity_adjustment:   f64,
+    ) -> Self {
+        let FutureTerms { start_date, end_date, .. } = terms;
+        let calendar = index.calendar().clone();
+        let mut accrual_nodes = vec![start_date];
+        let mut d = start_date + Days::new(1);
+        while d < end_date {
+            if calendar.is_business_day(d) {
+                accrual_nodes.push(d);
+            }
+            d = d + Days::new(1);
+        }
+        accrual_nodes.push(end_date);
+
+        let accrual_factor = index.day_counter().year_fraction(start_date, end_date);
+
+        // 期貨沒有期初交割，兩個 flow 都以 P&L market 的 discount curve 折現；
+        // 浮動端以 index 的 reference curve 推算
+        let mut curve_name_map: HashMap<CurveFunction, String> = HashMap::new();
+        curve_name_map.insert(
+            CurveFunction::ProfitAndLossDiscount,
+            profit_and_loss_market.discount_curve_name().to_string(),
+        );
+        curve_name_map.insert(CurveFunction::PayForward, index.reference_curve_name().clone());
+
+        Self {
+            position,
+            profit_and_loss_market,
+            index,
+            terms,
+            price,
+            convexity_adjustment,
+            accrual_nodes,
+            accrual_factor,
+            curve_name_map,
+        }
+    }
+
+    pub fn terms(&self) -> &FutureTerms { &self.terms }
+    pub fn price(&self) -> f64 { self.price }
+    pub fn convexity_adjustment(&self) -> f64 { self.convexity_adjustment }
+
+    /// 報價隱含的期貨利率 (100 − P) / 100。
+    pub fn futures_rate(&self) -> f64 {
+        0.01 * (100.0 - self.price)
+    }
+
+    /// 經 convexity adjustment 後的遠期利率。
+    pub fn adjusted_forward_rate(&self) -> f64 {
+        self.futures_rate() - self.convexity_adjustment
+    }
+
+    fn sign(&self) -> f64 {
+        self.position as i32 as f64
+    }
+
+    fn is_projected(&self, pricing_condition: &PricingCondition) -> bool {
+        let horizon = *pricing_condition.horizon();
+        self.terms.end_date > horizon
+            || (self.terms.end_date == horizon && *pricing_condition.include_horizon_flow())
+    }
+
+    /// 某一天（含）之前最近的 past fixing。
+    fn past_fixing(&self, d: NaiveDate) -> Option<f64> {
+        let calendar = self.index.calendar();
+        let mut fixing_date = d;
+        while !calendar.is_business_day(fixing_date) {
+            fixing_date = fixing_date.pred_opt()?;
+        }
+        self.index.past_fixings().get(&fixing_date).copied()
+    }
+
+    /// 參考期間的平均利率；需要推算而沒有 forward curve 時回傳 None。
+    pub fn average_rate(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        let horizon = *pricing_condition.horizon();
+        let estimate_horizon = *pricing_condition.estimate_horizon_index();
+        let day_counter = self.index.day_counter();
+        let discount_curve = forward_curve_opt.map(|c| c.to_discount_curve());
+
+        let mut sum = 0.0;
+        let mut factor = 1.0;
+        for window in self.accrual_nodes.windows(2) {
+            let (d, next_d) = (window[0], window[1]);
+            let is_past = d < horizon || (d == horizon && !estimate_horizon);
+            if !is_past && self.terms.averaging == FutureAveraging::Compounded {
+                let discount = discount_curve.as_ref()?;
+                factor *= discount.discount(d) / discount.discount(self.terms.end_date);
+                break;
+            }
+
+            let delta = day_counter.year_fraction(d, next_d);
+            let rate = if is_past {
+                self.past_fixing(d)?
+            } else {
+                let discount = discount_curve.as_ref()?;
+                (discount.discount(d) / discount.discount(next_d) - 1.0) / delta
+            };
+            sum += rate * delta;
+            factor *= 1.0 + rate * delta;
+        }
+
+        let tau = self.accrual_factor;
+        Some(match self.terms.averaging {
+            FutureAveraging::Arithmetic => sum / tau,
+            FutureAveraging::Compounded => (factor - 1.0) / tau,
+        })
+    }
+
+    fn fixed_flow(&self, pricing_condition: &PricingCondition) -> f64 {
+        let digits = self.profit_and_loss_market.settlement_currency().digits();
+        let flow = self.sign() * self.terms.contract_notional * self.accrual_factor * self.adjusted_forward_rate();
+        match pricing_condition.fixed_flow_rounding_digits(digits) {
+            Some(d) => round(flow, d),
+            None    => flow,
+        }
+    }
+
+    fn floating_flow(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        let digits = self.profit_and_loss_market.settlement_currency().digits();
+        let mut rate = self.average_rate(forward_curve_opt, pricing_condition)?;
+        if let Some(d) = pricing_condition.floating_index_rounding_digits(digits) {
+            rate = round(rate, d);
+        }
+        let flow = self.sign() * self.terms.contract_notional * self.accrual_factor * rate;
+        Some(match pricing_condition.floating_flow_rounding_digits(digits) {
+            Some(d) => round(flow, d),
+            None    => flow,
+        })
+    }
+}
+
+
+impl Instrument for OvernightIndexFuture {
+    fn max_date(&self) -> NaiveDate {
+        self.terms.end_date
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+
+impl InstrumentWithLinearFlows for OvernightIndexFuture {
+    fn past_pay_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if !self.is_projected(pricing_condition) {
+            // 參考期間已結束，所有 fixing 皆為 past
+            if let Some(flow) = self.floating_flow(None, pricing_condition) {
+                cash_flows[&self.terms.end_date] += flow;
+            }
+        }
+        cash_flows
+    }
+
+    fn past_receive_flows(&self, pricing_condition: &PricingCondition) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if !self.is_projected(pricing_condition) {
+            cash_flows[&self.terms.end_date] += self.fixed_flow(pricing_condition);
+        }
+        cash_flows
+    }
+
+    fn projected_pay_flows(
+        &self,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if self.is_projected(pricing_condition) {
+            let flow = self
+                .floating_flow(forward_curve_opt, pricing_condition)
+                .unwrap_or(f64::NAN);
+            cash_flows[&self.terms.end_date] -= flow;
+        }
+        cash_flows
+    }
+
+    fn projected_receive_flows(
+        &self,
+        _forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
+        pricing_condition: &PricingCondition,
+    ) -> CashFlows {
+        let mut cash_flows = CashFlows::new();
+        if self.is_projected(pricing_condition) {
+            cash_flows[&self.terms.end_date] += self.fixed_flow(pricing_condition);
+        }
+        cash_flows
+    }
+}
+
+impl SimpleInstrument for OvernightIndexFuture {}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// OvernightIndexFutureGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct OvernightIndexFutureGenerator {
+    profit_and_loss_market: Arc<dyn Market>,
+    index:                  Arc<dyn InterestRateIndex + Send + Sync>,
+    contract_type:          FutureContractType,
+    averaging:              FutureAveraging,
+    contract_notional:      f64,
+    convexity_adjustment:   ConvexityAdjustment,
+    price:                  RwLock<f64>,
+}
+
+impl OvernightIndexFutureGenerator {
+    pub fn new(
+        profit_and_loss_market: Arc<dyn Market>,
+        index:                  Arc<dyn InterestRateIndex + Send + Sync>,
+        contract_type:          FutureContractType,
+        averaging:              FutureAveraging,
+        contract_notional:      f64,
+        convexity_adjustment:   ConvexityAdjustment,
+    ) -> Self {
+        Self {
+            profit_and_loss_market,
+            index,
+            contract_type,
+            averaging,
+            contract_notional,
+            convexity_adjustment,
+            price: RwLock::new(100.0),
+        }
+    }
+
+    /// 使用合約型別預設 averaging、不做 convexity adjustment。
+    pub fn with_defaults(
+        profit_and_loss_market: Arc<dyn Market>,
+        index:                  Arc<dyn InterestRateIndex + Send + Sync>,
+        contract_type:          FutureContractType,
+    ) -> Self {
+        Self::new(
+            profit_and_loss_market,
+            index,
+            contract_type,
+            contract_type.default_averaging(),
+            DEFAULT_CONTRACT_NOTIONAL,
+            ConvexityAdjustment::None,
+        )
+    }
+
+    pub fn index(&self) -> &Arc<dyn InterestRateIndex + Send + Sync> { &self.index }
+    pub fn contract_type(&self) -> FutureContractType { self.contract_type }
+    pub fn averaging(&self) -> FutureAveraging { self.averaging }
+    pub fn contract_notional(&self) -> f64 { self.contract_notional }
+    pub fn convexity_adjustment(&self) -> &ConvexityAdjustment { &self.convexity_adjustment }
+
+    pub fn price(&self) -> f64 {
+        *self.price.read().unwrap()
+    }
+
+    /// 設定成交價（quote sheet 的報價）。
+    pub fn set_price(&self, price: f64) {
+        *self.price.write().unwrap() = price;
+    }
+
+    fn generate_for_period(
+        &self,
+        position:   Position,
+        trade_date: NaiveDate,
+        start_date: NaiveDate,
+        end_date:   NaiveDate,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        if end_date <= start_date {
+            return Err(format!("future reference period {start_date}/{end_date} is empty"));
+        }
+        let price = self.price();
+        let day_counter = self.index.day_counter();
+        let convexity_adjustment = self.convexity_adjustment.adjustment(
+            start_date,
+            day_counter.year_fraction(trade_date, start_date),
+            day_counter.year_fraction(trade_date, end_date),
+            self.market_rate(price),
+        );
+
+        let terms = FutureTerms {
+            averaging:         self.averaging,
+            start_date,
+            end_date,
+            contract_notional: self.contract_notional,
+        };
+
+        Ok(Arc::new(OvernightIndexFuture::new(
+            position,
+            self.profit_and_loss_market.clone(),
+            self.index.clone(),
+            terms,
+            price,
+            convexity_adjustment,
+        )))
+    }
+}
+
+impl SimpleInterestRateInstrumentGenerator for OvernightIndexFutureGenerator {
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    /// `start_date_opt` 有值時直接以 [start, maturity) 為參考期間，
+    /// 否則以 `maturity_date` 的年月決定合約月份。
+    fn generate_with_maturity_date(
+        &self,
+        position:       Position,
+        trade_date:     NaiveDate,
+        maturity_date:  NaiveDate,
+        start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        let (start_date, end_date) = match start_date_opt {
+            Some(start_date) => (start_date, maturity_date),
+            None => self
+                .contract_type
+                .reference_period(maturity_date)
+                .ok_or_else(|| format!("invalid future contract month: {maturity_date}"))?,
+        };
+        self.generate_for_period(position, trade_date, start_date, end_date)
+    }
+
+    fn generate_with_maturity_tenor(
+        &self,
+        _position:       Position,
+        _trade_date:     NaiveDate,
+        maturity_tenor:  Period,
+        _start_date_opt: Option<NaiveDate>,
+    ) -> Result<Arc<dyn SimpleInstrument>, String> {
+        Err(format!(
+            "overnight index futures are keyed by contract date, got tenor {maturity_tenor}"
+        ))
+    }
+
+    fn market_rate(&self, market_quote: f64) -> f64 {
+        0.01 * (100.0 - market_quote)
+    }
+}
+
+
+// ═════════════════════════════════════════════════════════════════════════════
+// OvernightIndexFutureGeneratorLoader
+// ═════════════════════════════════════════════════════════════════════════════
+//
+// JSON 範例（3M SOFR，Hull-White convexity）：
+//   {
+//     "name": "SOFR_3M_FUTURE",
+//     "market": "USD_MARKET",
+//     "index": "SOFR",
+//     "contract_type": "ThreeMonth",
+//     "contract_notional": 1000000.0,
+//     "convexity_adjustment": { "type": "HullWhite", "mean_reversion": 0.03, "volatility": 0.008 }
+//   }
+//
+// JSON 範例（1M SOFR，常數表）：
+//   {
+//     "name": "SOFR_1M_FUTURE",
+//     "market": "USD_MARKET",
+//     "index": "SOFR",
+//     "contract_type": "OneMonth",
+//     "convexity_adjustment": { "type": "ConstantTable", "table": { "2025-06-01": 0.00002 } }
+//   }
+//
+// `averaging` 省略時依 contract_type（OneMonth → Arithmetic、ThreeMonth → Compounded）；
+// `index` 必須為 CompoundingRate 型別。
+
+const DEFAULT_CONTRACT_NOTIONAL: f64 = 1_000_000.0;
+
+fn default_contract_notional() -> f64 {
+    DEFAULT_CONTRACT_NOTIONAL
+}
+
+#[derive(Deserialize)]
+struct OvernightIndexFutureGeneratorJsonProp {
+    market:               String,
+    index:                String,
+    contract_type:        FutureContractType,
+    #[serde(default)]
+    averaging:            Option<FutureAveraging>,
+    #[serde(default = "default_contract_notional")]
+    contract_notional:    f64,
+    #[serde(default)]
+    convexity_adjustment: ConvexityAdjustment,
+}
+
+pub struct OvernightIndexFutureGeneratorLoader;
+
+impl<'a> JsonLoader<OvernightIndexFutureGenerator, InterestRateInstrumentSupports<'a>>
+    for OvernightIndexFutureGeneratorLoader
+{
+    fn insert_obj_from_json(
+        &self,
+        builder:    &mut ManagerBuilder<OvernightIndexFutureGenerator>,
+        json_value: serde_json::Value,
+        supports:   &InterestRateInstrumentSupports<'a>,
+    ) -> Result<(), ManagerError> {
+        let named: Named<OvernightIndexFutureGeneratorJsonProp> = parse_json_value(json_value)?;
+        let prop = named.inner;
+
+        let market = supports.0.get(&prop.market)?;
+        let index = supports.4.get(&prop.index)?;
+        if index.index_type() != InterestRateIndexType::CompoundingRate {
+            return Err(ManagerError::InvalidValue(format!(
+                "future index '{}' must be a CompoundingRate index", prop.index
+            )));
+        }
+
+        let generator = OvernightIndexFutureGenerator::new(
+            market,
+            index,
+            prop.contract_type,
+            prop.averaging.unwrap_or(prop.contract_type.default_averaging()),
+            prop.contract_notional,
+            prop.convexity_adjustment,
+        );
+        builder.insert(named.name, Arc::new(generator));
+        Ok(())
+    }
+}
@@ -0,0 +1,321 @@
+// ── swaption.rs ───────────────────────────────────────────────────────────────
+//
+// European swaption：於到期日 T_e 取得進入 underlying InterestRateSwap 的權利。
+//
+// underlying 須為一條 fixed leg 與一條 floating leg：
+//   - fixed leg 為 pay leg  → Payer swaption（swap rate 的 call）
+//   - fixed leg 為 receive leg → Receiver swaption（swap rate 的 put）
+// 兩條腿的名目本金只取絕對值，正負號由 SwaptionType 與 position 決定。
+//
+// # 交割（SwaptionSettlement）
+//
+//   Physical     ：履約後持有 underlying swap
+//   CashParYield ：於 swap 起息日支付 N · A_cash(S) · ω (S − K)⁺，
+//                  A_cash(S) = Σ τ_i Π_{k≤i} (1 + τ_k S)⁻¹ 為以 swap rate S 計算的 par-yield annuity，
+//                  τ_i 為 fixed leg 各期的 year fraction
+//
+// 到期後的履約判斷依到期日觀察到的 swap rate（with_expiry_swap_rate）；評價見 SwaptionPricer。
+//
+// # 日期（SwaptionGenerator）
+//
+// expiry 由 OptionDateGenerator 依 option tenor 產生，underlying 以 expiry 為 trade date、
+// 依 swap generator 的 schedule（含 start lag）產生，strike 設定為 fixed leg 的利率。
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::instrument::instrument::{CurveFunction, Instrument, OptionType, Position};
+use crate::instrument::interestrate::flowobserver::FlowObserver;
+use crate::instrument::interestrate::interestrateswap::{InterestRateSwap, InterestRateSwapGenerator};
+use crate::instrument::interestrate::simpleinterestrateinstrumentgenerator::SimpleInterestRateInstrumentGenerator;
+use crate::instrument::leg::legcharacters::LegCharacters;
+use crate::market::market::Market;
+use crate::time::businessdayadjuster::{BusinessDayAdjuster, BusinessDayConvention};
+use crate::time::optiondategenerator::{ExpiryRule, OptionDateGenerator};
+use crate::time::period::{Period, TimeUnit};
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Conventions
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwaptionType {
+    /// 履約後支付固定利率。
+    Payer,
+    /// 履約後收取固定利率。
+    Receiver,
+}
+
+impl SwaptionType {
+    /// Payer = swap rate 的 call，Receiver = put。
+    pub fn option_type(&self) -> OptionType {
+        match self {
+            SwaptionType::Payer    => OptionType::Call,
+            SwaptionType::Receiver => OptionType::Put,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+pub enum SwaptionSettlement {
+    Physical,
+    CashParYield,
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SwaptionError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum SwaptionError {
+    #[error("underlying swap must have exactly one fixed leg and one floating leg")]
+    NotFixedFloat,
+
+    #[error("expiry date {expiry_date} is after the underlying start date {start_date}")]
+    ExpiryAfterStart {
+        expiry_date: NaiveDate,
+        start_date:  NaiveDate,
+    },
+
+    #[error("underlying swap generation failed: {0}")]
+    SwapGeneration(String),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Swaption
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct Swaption {
+    position:               Position,
+    underlying:             Arc<InterestRateSwap>,
+    swaption_type:          SwaptionType,
+    settlement:             SwaptionSettlement,
+    expiry_date:            NaiveDate,
+    /// 到期日觀察到的 swap rate，到期後用於履約判斷與現金交割金額。
+    expiry_swap_rate:       Option<f64>,
+    profit_and_loss_market: Arc<dyn Market>,
+    curve_name_map:         HashMap<CurveFunction, String>,
+}
+
+impl Swaption {
+    /// # Errors
+    ///
+    /// - underlying 不是 fixed vs. floating
+    /// - 到期日晚於 underlying 的起息日
+    pub fn new(
+        position:    Position,
+        underlying:  Arc<InterestRateSwap>,
+        expiry_date: NaiveDate,
+        settlement:  SwaptionSettlement,
+    ) -> Result<Self, SwaptionError> {
+        let pay_is_fixed = underlying.pay_leg_characters().reference_curve_name().is_none();
+        let receive_is_fixed = underlying.receive_leg_characters().reference_curve_name().is_none();
+        let swaption_type = match (pay_is_fixed, receive_is_fixed) {
+            (true, false) => SwaptionType::Payer,
+            (false, true) => SwaptionType::Receiver,
+            _ => return Err(SwaptionError::NotFixedFloat),
+        };
+
+        let profit_and_loss_market = underlying.profit_and_loss_market().clone();
+        let curve_name_map = underlying.curve_name_map().clone();
+        let swaption = Self {
+            position,
+            underlying,
+            swaption_type,
+            settlement,
+            expiry_date,
+            expiry_swap_rate: None,
+            profit_and_loss_market,
+            curve_name_map,
+        };
+
+        let start_date = swaption.start_date();
+        if expiry_date > start_date {
+            return Err(SwaptionError::ExpiryAfterStart { expiry_date, start_date });
+        }
+        Ok(swaption)
+    }
+
+    /// 設定到期日觀察到的 swap rate。
+    pub fn with_expiry_swap_rate(mut self, expiry_swap_rate: f64) -> Self {
+        self.expiry_swap_rate = Some(expiry_swap_rate);
+        self
+    }
+
+    pub fn underlying(&self) -> &Arc<InterestRateSwap> { &self.underlying }
+    pub fn swaption_type(&self) -> SwaptionType { self.swaption_type }
+    pub fn settlement(&self) -> SwaptionSettlement { self.settlement }
+    pub fn expiry_date(&self) -> NaiveDate { self.expiry_date }
+    pub fn expiry_swap_rate(&self) -> Option<f64> { self.expiry_swap_rate }
+
+    pub fn sign(&self) -> f64 {
+        self.position as i32 as f64
+    }
+
+    pub fn fixed_leg_characters(&self) -> &Arc<dyn LegCharacters> {
+        match self.swaption_type {
+            SwaptionType::Payer    => self.underlying.pay_leg_characters(),
+            SwaptionType::Receiver => self.underlying.receive_leg_characters(),
+        }
+    }
+
+    pub fn floating_leg_characters(&self) -> &Arc<dyn LegCharacters> {
+        match self.swaption_type {
+            SwaptionType::Payer    => self.underlying.receive_leg_characters(),
+            SwaptionType::Receiver => self.underlying.pay_leg_characters(),
+        }
+    }
+
+    pub fn fixed_leg_flow_observer_list(&self) -> &[FlowObserver] {
+        match self.swaption_type {
+            SwaptionType::Payer    => self.underlying.pay_leg_flow_observer_list(),
+            SwaptionType::Receiver => self.underlying.receive_leg_flow_observer_list(),
+        }
+    }
+
+    pub fn floating_leg_flow_observer_list(&self) -> &[FlowObserver] {
+        match self.swaption_type {
+            SwaptionType::Payer    => self.underlying.receive_leg_flow_observer_list(),
+            SwaptionType::Receiver => self.underlying.pay_leg_flow_observer_list(),
+        }
+    }
+
+    /// underlying 的起息日（fixed leg 第一期的起始日），亦為現金交割日。
+    pub fn start_date(&self) -> NaiveDate {
+        self.fixed_leg_characters()
+            .generic_characters()
+            .schedule()
+            .schedule_periods()[0]
+            .calculation_period()
+            .start_date()
+    }
+
+    /// underlying 年限（年），以 fixed leg 起迄日換算後取整到月，供 vol cube 查詢。
+    pub fn swap_tenor(&self) -> f64 {
+        let schedule_periods = self.fixed_leg_characters().generic_characters().schedule().schedule_periods();
+        let end_date = schedule_periods[schedule_periods.len() - 1].calculation_period().end_date();
+        let days = (end_date - self.start_date()).num_days() as f64;
+        (days * 12.0 / 365.25).round() / 12.0
+    }
+
+    /// 每單位名目本金的 par-yield annuity A_cash(S)。
+    pub fn cash_annuity(&self, swap_rate: f64) -> f64 {
+        let generic_characters = self.fixed_leg_characters().generic_characters();
+        let day_counter = generic_characters.day_counter();
+        let mut discount = 1.0;
+        let mut annuity = 0.0;
+        for period in generic_characters.schedule().schedule_periods() {
+            let calculation_period = period.calculation_period();
+            let tau = day_counter.year_fraction(calculation_period.start_date(), calculation_period.end_date());
+            discount /= 1.0 + tau * swap_rate;
+            annuity += tau * discount;
+        }
+        annuity
+    }
+}
+
+
+impl Instrument for Swaption {
+    fn max_date(&self) -> NaiveDate {
+        match self.settlement {
+            SwaptionSettlement::Physical     => self.underlying.max_date(),
+            SwaptionSettlement::CashParYield => self.start_date(),
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn profit_and_loss_market(&self) -> &Arc<dyn Market> {
+        &self.profit_and_loss_market
+    }
+
+    fn curve_name_map(&self) -> &HashMap<CurveFunction, String> {
+        &self.curve_name_map
+    }
+
+    fn is_linear(&self) -> bool {
+        false
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SwaptionGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct SwaptionGenerator {
+    swap_generator: Arc<InterestRateSwapGenerator>,
+    date_generator: OptionDateGenerator,
+    settlement:     SwaptionSettlement,
+}
+
+impl SwaptionGenerator {
+    pub fn new(
+        swap_generator: Arc<InterestRateSwapGenerator>,
+        date_generator: OptionDateGenerator,
+        settlement:     SwaptionSettlement,
+    ) -> Self {
+        Self { swap_generator, date_generator, settlement }
+    }
+
+    /// 利率市場慣例：expiry = horizon + tenor，Days / Weeks 以 Following、
+    /// Months / Years 以 Modified Following 調整。
+    pub fn with_defaults(swap_generator: Arc<InterestRateSwapGenerator>, settlement: SwaptionSettlement) -> Self {
+        let market = swap_generator.profit_and_loss_market().clone();
+        let date_generator = OptionDateGenerator::new(
+            market,
+            ExpiryRule::ExpiryToDelivery,
+            BusinessDayAdjuster::new(BusinessDayConvention::Following, false),
+            ExpiryRule::ExpiryToDelivery,
+            BusinessDayAdjuster::new(BusinessDayConvention::ModifiedFollowing, false),
+            HashSet::from([TimeUnit::Days, TimeUnit::Weeks]),
+        );
+        Self::new(swap_generator, date_generator, settlement)
+    }
+
+    pub fn swap_generator(&self) -> &Arc<InterestRateSwapGenerator> { &self.swap_generator }
+    pub fn date_generator(&self) -> &OptionDateGenerator { &self.date_generator }
+    pub fn settlement(&self) -> SwaptionSettlement { self.settlement }
+
+    pub fn expiry_date(&self, horizon: NaiveDate, expiry_tenor: Period) -> NaiveDate {
+        self.date_generator.generate_expiry(horizon, expiry_tenor)
+    }
+
+    /// 產生 expiry_tenor × swap_tenor 的 swaption，fixed leg 利率設為 strike。
+    ///
+    /// Payer / Receiver 由 swap generator 中 fixed leg 的位置決定。
+    pub fn generate_with_tenor(
+        &self,
+        position:     Position,
+        strike:       f64,
+        horizon:      NaiveDate,
+        expiry_tenor: Period,
+        swap_tenor:   Period,
+    ) -> Result<Swaption, SwaptionError> {
+        let expiry_date = self.expiry_date(horizon, expiry_tenor);
+        let generate = || {
+            self.swap_generator
+                .generate_swap_with_maturity_tenor(position, expiry_date, swap_tenor, None)
+                .map_err(SwaptionError::SwapGeneration)
+        };
+
+        // 先產生一次以判斷 fixed leg 位置，設定 strike 後再產生
+        let probe = generate()?;
+        let fixed_leg_generator = if probe.pay_leg_characters().reference_curve_name().is_none() {
+            self.swap_generator.pay_leg_character_genrator()
+        } else {
+            self.swap_generator.receive_leg_character_genrator()
+        };
+        fixed_leg_generator.setter().set_fixed_rate(strike);
+
+        Swaption::new(position, Arc::new(generate()?), expiry_date, self.settlement)
+    }
+}
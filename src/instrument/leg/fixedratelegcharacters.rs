@@ -55,6 +55,11 @@ impl FixedRateLegCharacters {
     pub fn fixed_rate(&self) -> f64 {
         self.fixed_rate
     }
+
+    /// 每單位本金的各期利息（與 schedule periods 對齊）。
+    pub fn flow_values(&self) -> &[f64] {
+        &self.flow_values
+    }
 }
 
 impl LegCharacters for FixedRateLegCharacters {
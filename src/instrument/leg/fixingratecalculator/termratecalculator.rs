@@ -35,7 +35,7 @@ use chrono::NaiveDate;
 use serde::Deserialize;
 
 use crate::instrument::leg::fixingratecalculator::fixingratecalculator::{FixingRateCalculator, FixingRateCalculatorGenerator};
-use crate::interestrate::index::interestrateindex::InterestRateIndex;
+use crate::interestrate::index::interestrateindex::{InterestRateIndex, InterestRateIndexType};
 use crate::model::interestrate::interestratecurve::InterestRateCurve;
 use crate::pricingcondition::PricingCondition;
 use crate::time::period::Period;
@@ -225,7 +225,10 @@ impl FixingRateCalculator for TermRateCalculator {
             || (period.start_date() == *pricing_condition.horizon()
                 && !pricing_condition.estimate_horizon_index());
 
-        if is_past && period.is_stub() {
+        // CompoundingRate index 逐日計算，stub 長度自然被處理，不套用 stub convention
+        let is_term_rate = self.index.index_type() == InterestRateIndexType::TermRate;
+
+        if is_past && period.is_stub() && is_term_rate {
             // Stub past：依 convention 計算
             self.stub_past_fixing(period, pricing_condition).unwrap_or(0.0)
         } else {
@@ -69,6 +69,11 @@ impl FloatingRateLegCharacters {
 
     pub fn leverage(&self) -> f64 { self.leverage }
     pub fn spread(&self) -> f64 { self.spread }
+    pub fn taus(&self) -> &[f64] { &self.taus }
+
+    pub fn index(&self) -> &Arc<dyn InterestRateIndex + Send + Sync> {
+        &self.index
+    }
 
     pub fn fixing_rate_calculator(&self) -> &Arc<dyn FixingRateCalculator> {
         &self.fixing_rate_calculator
@@ -157,14 +162,14 @@ impl FloatingRateLegCharactersGenerator {
             fixing_rate_calculator_generator,
         }
     }
-}
 
-impl LegCharactersGenerator for FloatingRateLegCharactersGenerator {
-    fn generic_characters_generator(&self) -> &GenericLegCharactersGenerator {
-        &self.generic_characters_generator
+    pub fn index(&self) -> &Arc<dyn InterestRateIndex + Send + Sync> {
+        &self.index
     }
 
-    fn generate_with_schedule(&self, schedule: Schedule) -> Arc<dyn LegCharacters> {
+    /// 已有 Schedule 時建構具體型別的 FloatingRateLegCharacters
+    /// （需要 fixing rate calculator 等浮動 leg 特有介面的商品使用）。
+    pub fn generate_floating_with_schedule(&self, schedule: Schedule) -> FloatingRateLegCharacters {
         let day_counter = self
             .day_counter_generator()
             .generate(Some(&schedule))
@@ -180,12 +185,22 @@ impl LegCharactersGenerator for FloatingRateLegCharactersGenerator {
             schedule,
         );
 
-        Arc::new(FloatingRateLegCharacters::new(
+        FloatingRateLegCharacters::new(
             generic_characters,
             self.setter().leverage(),
             self.setter().spread(),
             self.index.clone(),
             fixing_rate_calculator,
-        ))
+        )
+    }
+}
+
+impl LegCharactersGenerator for FloatingRateLegCharactersGenerator {
+    fn generic_characters_generator(&self) -> &GenericLegCharactersGenerator {
+        &self.generic_characters_generator
+    }
+
+    fn generate_with_schedule(&self, schedule: Schedule) -> Arc<dyn LegCharacters> {
+        Arc::new(self.generate_floating_with_schedule(schedule))
     }
 }
\ No newline at end of file
@@ -6,6 +6,7 @@ use std::sync::Arc;
 use chrono::NaiveDate;
 
 use crate::interestrate::index::cachebackend::{CacheBackend, RefCellBackend, RwLockBackend};
+use crate::interestrate::index::compoundingrateindex::CompoundingRateIndex;
 use crate::interestrate::index::interestrateindex::{InterestRateIndex, InterestRateIndexType};
 use crate::model::interestrate::interestratecurve::InterestRateCurve;
 use crate::pricingcondition::PricingCondition;
@@ -59,6 +60,11 @@ impl<C: CacheBackend> InterestRateIndex for CachedInterestRateIndex<C> {
     fn reference_curve_name(&self) -> &String          { self.index.reference_curve_name() }
     fn past_fixings(&self) -> &HashMap<NaiveDate, f64> { self.index.past_fixings() }
 
+    /// 回傳未經 cache 的底層 index（calculator 逐日計算，不經過 period cache）。
+    fn compounding_rate_index(self: Arc<Self>) -> Option<Arc<CompoundingRateIndex>> {
+        self.index.clone().compounding_rate_index()
+    }
+
     // ── projected_rate_for_period：唯一快取的計算路徑 ────────────────────────
     //
     // Cache key = (curve_ptr, period.start_date())
@@ -230,14 +230,17 @@ impl CompoundingRateIndex {
     }
 
     /// 混合 past/future 的逐日計算（固定用 Standard Forward 計算 future 部分）。
+    ///
+    /// 整段皆為 past 時不需要 forward curve（例如應計利息只用已實現 fixings）；
+    /// 有 projected 日期但未給 curve 時回傳 None。
     fn compute_compound_factor_mixed(
         &self,
         business_days: &[NaiveDate],
         end_date: NaiveDate,
-        forward_curve: &Arc<dyn InterestRateCurve>,
+        forward_curve_opt: Option<&Arc<dyn InterestRateCurve>>,
         pricing_condition: &PricingCondition,
-    ) -> f64 {
-        business_days.iter().enumerate().fold(1.0, |acc, (i, &d)| {
+    ) -> Option<f64> {
+        business_days.iter().enumerate().try_fold(1.0, |acc, (i, &d)| {
             let next_d      = business_days.get(i + 1).copied().unwrap_or(end_date);
             let tau         = self.day_counter.year_fraction(d, next_d);
             let fixing_date = self.accrual_to_fixing(business_days, i, end_date);
@@ -255,11 +258,11 @@ impl CompoundingRateIndex {
                 } else {
                     end_date
                 };
-                let discount_curve = forward_curve.to_discount_curve();
+                let discount_curve = forward_curve_opt?.to_discount_curve();
                 (discount_curve.discount(fixing_date) / discount_curve.discount(next_fixing) - 1.0) / tau
             };
 
-            acc * (1.0 + rate * tau)
+            Some(acc * (1.0 + rate * tau))
         })
     }
 }
@@ -276,6 +279,8 @@ impl InterestRateIndex for CompoundingRateIndex {
     fn reference_curve_name(&self) -> &String { &self.reference_curve_name }
     fn past_fixings(&self) -> &HashMap<NaiveDate, f64> { &self.daily_past_fixings }
 
+    fn compounding_rate_index(self: Arc<Self>) -> Option<Arc<CompoundingRateIndex>> { Some(self) }
+
     fn start_date(&self, fixing_date: NaiveDate) -> NaiveDate {
         self.calendar.shift_n_business_day(fixing_date, -(self.start_lag as i32))
     }
@@ -321,9 +326,9 @@ impl InterestRateIndex for CompoundingRateIndex {
         let factor = self.compute_compound_factor_mixed(
             &bdays,
             period.end_date(),
-            forward_curve_opt?,
+            forward_curve_opt,
             pricing_condition,
-        );
+        )?;
         Some(self.result_compounding.implied_rate(factor, tau))
     }
 }
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use chrono::NaiveDate;
 use serde::Deserialize;
 
+use crate::interestrate::index::compoundingrateindex::CompoundingRateIndex;
 use crate::time::businessdayadjuster::BusinessDayAdjuster;
 use crate::model::interestrate::interestratecurve::InterestRateCurve;
 use crate::pricingcondition::PricingCondition;
@@ -43,6 +44,12 @@ pub trait InterestRateIndex {
 
     fn past_fixings(&self) -> &HashMap<NaiveDate, f64>;
 
+    /// 具體型別為 `CompoundingRateIndex` 時回傳 typed Arc，
+    /// 供 `CompoundingRateIndexCalculatorGenerator` 使用；其餘 index 回傳 None。
+    fn compounding_rate_index(self: Arc<Self>) -> Option<Arc<CompoundingRateIndex>> {
+        None
+    }
+
     // ── 由 fixing_date 推算日期 ───────────────────────────────────────────
 
     fn start_date(&self, fixing_date: NaiveDate) -> NaiveDate;
@@ -4,11 +4,27 @@ pub mod instrument {
     pub mod instrument;
     pub mod nominalgenerator;
 
+    pub mod fx {
+        pub mod fxforward;
+        pub mod fxswap;
+        pub mod nondeliverableforward;
+        pub mod fxvanillaoption;
+    }
+
     pub mod interestrate {
         pub mod flowobserver;
         pub mod simpleinterestrateinstrumentgenerator;
         pub mod deposit;
         pub mod interestrateswap;
+        pub mod overnightindexfuture;
+        pub mod forwardrateagreement;
+        pub mod fixedratebond;
+        pub mod floatingratenote;
+        pub mod crosscurrencyswap;
+        pub mod crosscurrencybasisswap;
+        pub mod fximplieddeposit;
+        pub mod swaption;
+        pub mod capfloor;
     }
 
     pub mod leg {
@@ -54,6 +70,10 @@ pub mod market {
 pub mod marketdata {
     pub mod interestrate {
         pub mod interestratequotesheet;
+        pub mod swaptionvolatilityquotesheet;
+    }
+    pub mod fx {
+        pub mod fxvolatilityquotesheet;
     }
     pub mod marketdataset;
 }
@@ -66,9 +86,15 @@ pub mod math {
             pub mod piecewisepolynomial;
             pub mod lagrangepolynomial;
         }
+        pub mod parametriccurve {
+            pub mod parametriccurve;
+            pub mod nelsonsiegel;
+            pub mod svensson;
+        }
     }
     pub mod round;
     pub mod rootsolver;
+    pub mod normaldistribution;
 }
 
 pub mod model {
@@ -77,11 +103,41 @@ pub mod model {
         pub mod curvegenerationerror;
         pub mod precomputeddiscountcurve;
         pub mod piecewisepolyinterestratecurve;
+        pub mod parametricinterestratecurve;
+        pub mod smithwilsoncurve;
+        pub mod spreadinterestratecurve;
+        pub mod bumpedinterestratecurve;
+        pub mod rolledinterestratecurve;
+        pub mod stepforwardcurve;
         pub mod interestratecurvecalibrator;
+        pub mod calibrationreport;
         pub mod iterativebootstrapper;
+        pub mod leastsquarecalibrator;
+        pub mod multicurvecalibrator;
+        pub mod crosscurrencybasiscurvecalibrator;
+        pub mod quotejacobian;
+        pub mod parametriccurvecalibrator;
+        pub mod spreadcurvebootstrapper;
         pub mod flatforwardcurve;
         pub mod bootstrappingtrait;
     }
+    pub mod fx {
+        pub mod fxforwardcurve;
+        pub mod fxvolatility;
+        pub mod garmankohlhagen;
+        pub mod fxvolatilitysurface;
+    }
+    pub mod volatility {
+        pub mod sabr;
+        pub mod blackformula;
+        pub mod interestratevolatility;
+        pub mod swaptionvolatilitycube;
+        pub mod capletvolatilitystripper;
+    }
+    pub mod shortrate {
+        pub mod hullwhite;
+        pub mod hullwhiteconditionalcurve;
+    }
 }
 
 pub mod objectwithuuid;
@@ -89,6 +145,11 @@ pub mod objectwithuuid;
 pub mod pricer {
     pub mod pricer;
     pub mod simpleinstrumentpricer;
+    pub mod crosscurrencyswappricer;
+    pub mod fxforwardpricer;
+    pub mod fxvanillaoptionpricer;
+    pub mod swaptionpricer;
+    pub mod capfloorpricer;
 }
 
 pub mod pricingcondition;
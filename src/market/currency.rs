@@ -37,5 +37,10 @@ impl CurrencyPair {
     pub fn ccy2(&self) -> &Currency {
         &self.ccy2
     }
+
+    /// 例如 USD/TWD → "USDTWD"。
+    pub fn code(&self) -> String {
+        format!("{}{}", self.ccy1.code, self.ccy2.code)
+    }
 }
 
@@ -6,18 +6,26 @@ use serde::{
     Serialize
 };
 
+use crate::manager::manager::{FrozenManager, JsonLoader, ManagerBuilder};
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
 use crate::market::currency::{Currency, CurrencyPair};
 use crate::market::market::Market;
+use crate::time::businessdayadjuster::{BusinessDayAdjuster, BusinessDayConvention};
 use crate::time::calendar::holidaycalendar::HolidayCalendar;
-use crate::time::period::Period;
+use crate::time::period::{Period, TimeUnit};
 
-#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ATMConvention {
     AtTheMoneyForward,
     DeltaNeutral
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// FX option delta 慣例。
+///
+/// 依 loader 旗標對應：`premium_in_delta = true` → `Pips*`（premium-adjusted delta），
+/// `false` → `Percentage*`（不含 premium）；`*Spot` / `*Forward` 為 spot / forward delta。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DeltaConvention {
     PipsSpot,
     PipsForward,
@@ -126,6 +134,25 @@ impl FxMatket {
     pub fn max_short_term_tenor(&self) -> Period {
         self.max_short_term_tenor
     }
+
+    /// 到期日為 `expiry_date` 的 option 適用的 delta 慣例：
+    /// 到期日不晚於 horizon + max_short_term_tenor 為短天期，否則為長天期。
+    pub fn delta_convention(&self, horizon: NaiveDate, expiry_date: NaiveDate) -> DeltaConvention {
+        if expiry_date <= horizon + self.max_short_term_tenor {
+            self.short_term_delta_convention
+        } else {
+            self.long_term_delta_convention
+        }
+    }
+
+    /// FX forward / swap 的交割日：spot date + tenor，以 settlement calendar 做
+    /// Modified Following 調整；月 / 年 tenor 另套用 end-end rule
+    /// （spot 為月底營業日時交割日亦為月底營業日）。
+    pub fn value_date(&self, horizon: NaiveDate, tenor: Period) -> NaiveDate {
+        let end_end = matches!(tenor.unit(), TimeUnit::Months | TimeUnit::Years);
+        BusinessDayAdjuster::new(BusinessDayConvention::ModifiedFollowing, end_end)
+            .from_tenor_to_date(self.settlement_date(horizon), tenor, &self.settlement_calendar)
+    }
 }
 
 
@@ -166,4 +193,104 @@ impl Market for FxMatket {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxMarketLoader
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// Supports：&FrozenManager<dyn HolidayCalendar + Send + Sync>
+//
+// JSON 範例（ccy1 = foreign / base，ccy2 = domestic / quote）：
+//   {
+//     "name":                         "USDTWD",
+//     "ccy1":                         { "code": "USD", "digits": 2 },
+//     "ccy2":                         { "code": "TWD", "digits": 0 },
+//     "domestic_discount_curve_name": "TWD_USD_COLLATERAL",
+//     "foreign_discount_curve_name":  "USD_SOFR",
+//     "settlement_days":              2,
+//     "expiry_calendar":              "TWD",
+//     "settlement_calendar":          "TWD_NY",
+//     "max_short_term_tenor":         "1Y"
+//   }
+//
+// `expiry_calendar` 省略時同 `settlement_calendar`；
+// `atm_convention` 省略時為 DeltaNeutral；delta 慣例旗標省略時皆為 false
+// （premium 不含在 delta 內、forward delta）；`max_short_term_tenor` 省略時為 1Y。
+
+#[derive(Deserialize)]
+struct FxMarketJsonProp {
+    ccy1:                         Currency,
+    ccy2:                         Currency,
+    domestic_discount_curve_name: String,
+    foreign_discount_curve_name:  String,
+    settlement_days:              u32,
+    #[serde(default)]
+    expiry_calendar:              Option<String>,
+    settlement_calendar:          String,
+    #[serde(default = "default_atm_convention")]
+    atm_convention:               ATMConvention,
+    #[serde(default)]
+    premium_in_delta:             bool,
+    #[serde(default)]
+    spot_delta_for_short_term:    bool,
+    #[serde(default)]
+    spot_delta_for_long_term:     bool,
+    #[serde(default = "default_max_short_term_tenor")]
+    max_short_term_tenor:         String,
+}
+
+fn default_atm_convention() -> ATMConvention {
+    ATMConvention::DeltaNeutral
+}
+
+fn default_max_short_term_tenor() -> String {
+    "1Y".to_string()
+}
+
+pub struct FxMarketLoader;
+
+impl<'a> JsonLoader<
+    FxMatket,
+    &'a FrozenManager<dyn HolidayCalendar + Send + Sync>,
+> for FxMarketLoader {
+    fn insert_obj_from_json(
+        &self,
+        builder: &mut ManagerBuilder<FxMatket>,
+        json_value: serde_json::Value,
+        supports: &&'a FrozenManager<dyn HolidayCalendar + Send + Sync>,
+    ) -> Result<(), ManagerError> {
+        let named: Named<FxMarketJsonProp> = parse_json_value(json_value)?;
+        let p = named.inner;
+
+        if p.settlement_days > 2 {
+            return Err(ManagerError::InvalidValue(format!(
+                "FX market '{}' settlement_days must be at most 2", named.name
+            )));
+        }
+
+        let settlement_calendar = supports.get(&p.settlement_calendar)?;
+        let expiry_calendar = match &p.expiry_calendar {
+            Some(name) => supports.get(name)?,
+            None       => settlement_calendar.clone(),
+        };
+        let settlement_currency = p.ccy2.clone();
+        let market = FxMatket::new(
+            CurrencyPair::new(p.ccy1, p.ccy2),
+            p.domestic_discount_curve_name,
+            p.foreign_discount_curve_name,
+            settlement_currency,
+            p.settlement_days,
+            expiry_calendar,
+            settlement_calendar,
+            p.atm_convention,
+            p.premium_in_delta,
+            p.spot_delta_for_short_term,
+            p.spot_delta_for_long_term,
+            Period::parse(&p.max_short_term_tenor)?,
+        );
+        builder.insert(named.name, Arc::new(market));
+        Ok(())
+    }
+}
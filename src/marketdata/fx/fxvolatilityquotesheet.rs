@@ -0,0 +1,109 @@
+// ── fxvolatilityquotesheet.rs ─────────────────────────────────────────────────
+//
+// FX option 市場的 vol 報價（每個 tenor 一組）：
+//
+//   ATM         — 依 FX market ATM 慣例的 ATM vol
+//   RR(Δ)       — risk reversal：σ_call(Δ) − σ_put(Δ)
+//   BF(Δ)       — butterfly，依 ButterflyType 解讀：
+//                   Smile ：smile strangle，σ_call(Δ) + σ_put(Δ) = 2 (σ_ATM + BF)
+//                   Broker：market strangle，以單一 vol σ_ATM + BF 計算的 Δ call + Δ put 價格
+//
+// Δ 為 25D（必要）與 10D（選填），皆依 FX market 的 delta 慣例解讀。
+// 報價由 FxVolatilitySurfaceCalibrator 轉成 smile，本身不含任何轉換邏輯。
+
+use std::collections::HashMap;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ButterflyType
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButterflyType {
+    /// broker / market strangle（vega-weighted 的單一 vol strangle 報價）。
+    Broker,
+    /// smile strangle：直接為 smile 上兩個 Δ 點 vol 的平均減 ATM。
+    Smile,
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxVolatilityQuote
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FxVolatilityQuote {
+    pub atm:              f64,
+    pub risk_reversal_25: f64,
+    pub butterfly_25:     f64,
+    pub risk_reversal_10: Option<f64>,
+    pub butterfly_10:     Option<f64>,
+}
+
+impl FxVolatilityQuote {
+    pub fn new(atm: f64, risk_reversal_25: f64, butterfly_25: f64) -> Self {
+        Self {
+            atm,
+            risk_reversal_25,
+            butterfly_25,
+            risk_reversal_10: None,
+            butterfly_10:     None,
+        }
+    }
+
+    /// 加上 10D risk reversal 與 butterfly。
+    pub fn with_ten_delta(mut self, risk_reversal_10: f64, butterfly_10: f64) -> Self {
+        self.risk_reversal_10 = Some(risk_reversal_10);
+        self.butterfly_10 = Some(butterfly_10);
+        self
+    }
+
+    /// 10D 報價（RR 與 BF 需同時存在）。
+    pub fn ten_delta(&self) -> Option<(f64, f64)> {
+        Some((self.risk_reversal_10?, self.butterfly_10?))
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxVolatilityQuoteSheet
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FxVolatilityQuoteSheet {
+    butterfly_type: ButterflyType,
+    /// key 為 option tenor 字串（"1W"、"1M"、"1Y"），日期依 FxVanillaOptionGenerator 產生。
+    sheet:          HashMap<String, FxVolatilityQuote>,
+}
+
+impl FxVolatilityQuoteSheet {
+    pub fn new(butterfly_type: ButterflyType) -> Self {
+        Self {
+            butterfly_type,
+            sheet: HashMap::new(),
+        }
+    }
+
+    pub fn add_quote(&mut self, tenor: impl Into<String>, quote: FxVolatilityQuote) {
+        self.sheet.insert(tenor.into(), quote);
+    }
+
+    pub fn get_quote(&self, tenor: &str) -> Option<&FxVolatilityQuote> {
+        self.sheet.get(tenor)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.sheet.keys()
+    }
+
+    pub fn quotes(&self) -> impl Iterator<Item = (&String, &FxVolatilityQuote)> {
+        self.sheet.iter()
+    }
+
+    pub fn butterfly_type(&self) -> ButterflyType {
+        self.butterfly_type
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sheet.is_empty()
+    }
+}
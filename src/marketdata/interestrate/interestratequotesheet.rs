@@ -6,12 +6,19 @@ use chrono::NaiveDate;
 
 use crate::configuration::InterestRateInstrumentGeneratorCollection;
 use crate::instrument::instrument::{Position, SimpleInstrument};
+use crate::instrument::interestrate::crosscurrencybasisswap::CrossCurrencyBasisSwap;
+use crate::instrument::interestrate::crosscurrencyswap::CrossCurrencySwapLegSide;
+use crate::instrument::interestrate::forwardrateagreement::FraTenor;
 use crate::instrument::interestrate::simpleinterestrateinstrumentgenerator::SimpleInterestRateInstrumentGenerator;
 use crate::manager::managererror::ManagerError;
 use crate::model::interestrate::interestratecurvecalibrator::InterestRateCurveCalibrationHelper;
 use crate::time::period::Period;
 
 
+/// 日期型 key 的格式。
+const KEY_DATE_FORMAT: &str = "%Y-%m-%d";
+
+
 // ─────────────────────────────────────────────────────────────────────────────
 // InterestRateQuoteSheetError
 // ─────────────────────────────────────────────────────────────────────────────
@@ -52,14 +59,35 @@ pub enum InterestRateSwapQuoteTarget {
 /// 決定如何將 quote 值 apply 到 generator，以及用哪種 key 格式產生 instrument。
 ///
 /// # Key 格式慣例
-/// - `Deposit` / `InterestRateSwap`：key 為 tenor 字串，如 `"3M"`、`"1Y"`
-/// - `Future`（未來擴充）：key 為日期字串，如 `"2024-06-15"`
+/// - `Deposit` / `InterestRateSwap`：key 為 tenor 字串，如 `"3M"`、`"1Y"`；
+///   meeting-dated OIS 可用到期日 `"2025-03-20"`，或起迄日 `"2025-01-30/2025-03-20"`
+///   （forward-starting，會議日到會議日）
+/// - `Future`：key 為合約月份內的日期字串，如 `"2024-06-15"`（只取年月），
+///   或參考期間 `"2024-06-19/2024-09-18"`；quote 為價格（100 − rate）
+/// - `Fra`：key 為 `"3x6"`（spot 起 3 個月開始、6 個月結束），
+///   也接受上述 tenor / 日期格式；quote 為 FRA rate
+/// - `Bond`：key 為到期日 `"2030-05-15"`、起息日 / 到期日，或新發行的 tenor；
+///   quote 為 clean price（每 100 面額），market rate 為對應的殖利率
+/// - `FxForwardPoints`：key 為 spot 起算的 tenor（`"1M"`）或交割日；
+///   quote 為 forward points，market rate 為年化 forward premium
+/// - `CrossCurrencySwap`：key 同 `InterestRateSwap`；quote 為指定 leg 的 basis spread，
+///   `spot` 決定兩條 leg 的名目本金比例
 pub enum InterestRateGeneratorType {
     Deposit,
     InterestRateSwap {
         leg:    InterestRateSwapQuoteLeg,
         target: InterestRateSwapQuoteTarget,
     },
+    Future,
+    Fra,
+    Bond,
+    FxForwardPoints {
+        spot: f64,
+    },
+    CrossCurrencySwap {
+        leg:  InterestRateSwapQuoteLeg,
+        spot: f64,
+    },
 }
 
 
@@ -123,57 +151,8 @@ impl InterestRateQuoteSheet {
         trade_date:           NaiveDate,
         generator_collection: &InterestRateInstrumentGeneratorCollection,
     ) -> Result<Arc<dyn SimpleInstrument>, InterestRateQuoteSheetError> {
-        let quote = self
-            .sheet
-            .get(key)
-            .ok_or_else(|| InterestRateQuoteSheetError::MaturityNotFound(key.to_string()))?;
-
-        match &self.generator_type {
-            InterestRateGeneratorType::Deposit => {
-                let generator = generator_collection
-                    .deposit_generator_manager
-                    .get(&self.generator_name)?;
-
-                generator
-                    .leg_character_genrator()
-                    .setter()
-                    .set_fixed_rate(*quote);
-
-                let tenor = Period::parse(key).map_err(|e| {
-                    InterestRateQuoteSheetError::TenorParse(key.to_string(), e.to_string())
-                })?;
-                generator
-                    .generate_with_maturity_tenor(position, trade_date, tenor, None)
-                    .map_err(InterestRateQuoteSheetError::InstrumentGeneration)
-            }
-
-            InterestRateGeneratorType::InterestRateSwap { leg, target } => {
-                let generator = generator_collection
-                    .swap_generator_manager
-                    .get(&self.generator_name)?;
-
-                let setter = match leg {
-                    InterestRateSwapQuoteLeg::PayLeg =>
-                        generator.pay_leg_character_genrator().setter(),
-                    InterestRateSwapQuoteLeg::ReceiveLeg =>
-                        generator.receive_leg_character_genrator().setter(),
-                };
-
-                match target {
-                    InterestRateSwapQuoteTarget::ParRate =>
-                        setter.set_fixed_rate(*quote),
-                    InterestRateSwapQuoteTarget::Spread =>
-                        setter.set_spread(*quote),
-                }
-
-                let tenor = Period::parse(key).map_err(|e| {
-                    InterestRateQuoteSheetError::TenorParse(key.to_string(), e.to_string())
-                })?;
-                generator
-                    .generate_with_maturity_tenor(position, trade_date, tenor, None)
-                    .map_err(InterestRateQuoteSheetError::InstrumentGeneration)
-            }
-        }
+        self.generate_calibration_helper(key, position, trade_date, generator_collection)
+            .map(InterestRateCurveCalibrationHelper::into_instrument)
     }
 
     /// 取得 quote 值、apply 到 generator、產生 instrument，
@@ -194,6 +173,23 @@ impl InterestRateQuoteSheet {
             .get(key)
             .ok_or_else(|| InterestRateQuoteSheetError::MaturityNotFound(key.to_string()))?;
 
+        self.generate_calibration_helper_with_quote(
+            key, quote, position, trade_date, generator_collection,
+        )
+    }
+
+    /// 與 `generate_calibration_helper` 相同，但以呼叫端給定的 `quote`
+    /// 取代 sheet 中的報價（sheet 本身不變）。
+    ///
+    /// 供 quote sensitivity（d pillar / d quote）等需要擾動報價的流程使用。
+    pub fn generate_calibration_helper_with_quote(
+        &self,
+        key:                  &str,
+        quote:                f64,
+        position:             Position,
+        trade_date:           NaiveDate,
+        generator_collection: &InterestRateInstrumentGeneratorCollection,
+    ) -> Result<InterestRateCurveCalibrationHelper, InterestRateQuoteSheetError> {
         match &self.generator_type {
             InterestRateGeneratorType::Deposit => {
                 let generator = generator_collection
@@ -207,12 +203,9 @@ impl InterestRateQuoteSheet {
 
                 let market_rate = generator.market_rate(quote);
 
-                let tenor = Period::parse(key).map_err(|e| {
-                    InterestRateQuoteSheetError::TenorParse(key.to_string(), e.to_string())
-                })?;
-                let instrument = generator
-                    .generate_with_maturity_tenor(position, trade_date, tenor, None)
-                    .map_err(InterestRateQuoteSheetError::InstrumentGeneration)?;
+                let instrument = Self::generate_by_key(
+                    generator.as_ref(), key, position, trade_date,
+                )?;
 
                 Ok(InterestRateCurveCalibrationHelper::new(instrument, market_rate))
             }
@@ -238,15 +231,168 @@ impl InterestRateQuoteSheet {
 
                 let market_rate = generator.market_rate(quote);
 
-                let tenor = Period::parse(key).map_err(|e| {
-                    InterestRateQuoteSheetError::TenorParse(key.to_string(), e.to_string())
-                })?;
-                let instrument = generator
-                    .generate_with_maturity_tenor(position, trade_date, tenor, None)
-                    .map_err(InterestRateQuoteSheetError::InstrumentGeneration)?;
+                let instrument = Self::generate_by_key(
+                    generator.as_ref(), key, position, trade_date,
+                )?;
+
+                Ok(InterestRateCurveCalibrationHelper::new(instrument, market_rate))
+            }
+
+            InterestRateGeneratorType::Future => {
+                let generator = generator_collection
+                    .future_generator_manager
+                    .get(&self.generator_name)?;
+
+                generator.set_price(quote);
+
+                let market_rate = generator.market_rate(quote);
+
+                let instrument = Self::generate_by_key(
+                    generator.as_ref(), key, position, trade_date,
+                )?;
+
+                Ok(InterestRateCurveCalibrationHelper::new(instrument, market_rate))
+            }
+
+            InterestRateGeneratorType::Fra => {
+                let generator = generator_collection
+                    .fra_generator_manager
+                    .get(&self.generator_name)?;
+
+                generator.set_fixed_rate(quote);
+
+                let market_rate = generator.market_rate(quote);
+
+                let instrument = match FraTenor::parse(key) {
+                    Some(fra_tenor) => {
+                        let fra_tenor = fra_tenor.map_err(|e| {
+                            InterestRateQuoteSheetError::TenorParse(key.to_string(), e)
+                        })?;
+                        generator
+                            .generate_with_fra_tenor(position, trade_date, fra_tenor)
+                            .map_err(InterestRateQuoteSheetError::InstrumentGeneration)?
+                    }
+                    None => Self::generate_by_key(generator.as_ref(), key, position, trade_date)?,
+                };
 
                 Ok(InterestRateCurveCalibrationHelper::new(instrument, market_rate))
             }
+
+            InterestRateGeneratorType::Bond => {
+                let generator = generator_collection
+                    .bond_generator_manager
+                    .get(&self.generator_name)?;
+
+                generator.set_clean_price(quote);
+
+                let bond = Self::dispatch_by_key(
+                    key,
+                    |maturity, start_date_opt| {
+                        generator.generate_bond_with_maturity_date(position, trade_date, maturity, start_date_opt)
+                    },
+                    |tenor| generator.generate_bond_with_maturity_tenor(position, trade_date, tenor, None),
+                )?;
+
+                // 殖利率依個別債券的現金流而定，無法只由報價經 generator 的 market_rate() 轉換
+                let market_rate = bond
+                    .yield_from_clean_price(quote, bond.settlement_date())
+                    .map_err(|e| InterestRateQuoteSheetError::InstrumentGeneration(e.to_string()))?;
+
+                Ok(InterestRateCurveCalibrationHelper::new(bond, market_rate))
+            }
+
+            InterestRateGeneratorType::FxForwardPoints { spot } => {
+                let generator = generator_collection
+                    .fx_implied_deposit_generator_manager
+                    .get(&self.generator_name)?;
+
+                generator.set_spot(*spot);
+                generator.set_forward_points(quote);
+
+                let deposit = Self::dispatch_by_key(
+                    key,
+                    |maturity, _| generator.generate_deposit_with_value_date(position, trade_date, maturity),
+                    |tenor| generator.generate_deposit_with_tenor(position, trade_date, tenor),
+                )?;
+
+                // 年化 premium 依個別交割日而定
+                let market_rate = deposit.forward_premium_rate();
+
+                Ok(InterestRateCurveCalibrationHelper::new(deposit, market_rate))
+            }
+
+            InterestRateGeneratorType::CrossCurrencySwap { leg, spot } => {
+                let generator = generator_collection
+                    .xccy_swap_generator_manager
+                    .get(&self.generator_name)?;
+
+                let side = match leg {
+                    InterestRateSwapQuoteLeg::PayLeg     => CrossCurrencySwapLegSide::PayLeg,
+                    InterestRateSwapQuoteLeg::ReceiveLeg => CrossCurrencySwapLegSide::ReceiveLeg,
+                };
+                generator.set_fx_rate(*spot);
+                generator.leg(side).leg_character_genrator().setter().set_spread(quote);
+
+                let swap = Self::dispatch_by_key(
+                    key,
+                    |maturity, start_date_opt| {
+                        generator.generate_swap_with_maturity_date(position, trade_date, maturity, start_date_opt)
+                    },
+                    |tenor| generator.generate_swap_with_maturity_tenor(position, trade_date, tenor, None),
+                )?;
+                let basis_swap = CrossCurrencyBasisSwap::new(swap)
+                    .map_err(|e| InterestRateQuoteSheetError::InstrumentGeneration(e.to_string()))?;
+
+                Ok(InterestRateCurveCalibrationHelper::new(Arc::new(basis_swap), quote))
+            }
         }
     }
-}
\ No newline at end of file
+
+    /// 依 key 格式產生 instrument：
+    ///   - `"3M"`：tenor
+    ///   - `"2025-03-20"`：到期日
+    ///   - `"2025-01-30/2025-03-20"`：起始日 / 到期日
+    fn generate_by_key<G: SimpleInterestRateInstrumentGenerator + ?Sized>(
+        generator:  &G,
+        key:        &str,
+        position:   Position,
+        trade_date: NaiveDate,
+    ) -> Result<Arc<dyn SimpleInstrument>, InterestRateQuoteSheetError> {
+        Self::dispatch_by_key(
+            key,
+            |maturity, start_date_opt| {
+                generator.generate_with_maturity_date(position, trade_date, maturity, start_date_opt)
+            },
+            |tenor| generator.generate_with_maturity_tenor(position, trade_date, tenor, None),
+        )
+    }
+
+    /// 解析 key 格式後呼叫對應的產生方式（到期日 / tenor）。
+    fn dispatch_by_key<T>(
+        key:      &str,
+        by_date:  impl Fn(NaiveDate, Option<NaiveDate>) -> Result<T, String>,
+        by_tenor: impl Fn(Period) -> Result<T, String>,
+    ) -> Result<T, InterestRateQuoteSheetError> {
+        let parse_date = |text: &str| {
+            NaiveDate::parse_from_str(text.trim(), KEY_DATE_FORMAT).map_err(|e| {
+                InterestRateQuoteSheetError::DateParse(key.to_string(), e.to_string())
+            })
+        };
+
+        let generated = if let Some((start, maturity)) = key.split_once('/') {
+            by_date(parse_date(maturity)?, Some(parse_date(start)?))
+        } else {
+            match Period::parse(key) {
+                Ok(tenor) => by_tenor(tenor),
+                Err(tenor_error) => {
+                    let maturity = NaiveDate::parse_from_str(key, KEY_DATE_FORMAT).map_err(|_| {
+                        InterestRateQuoteSheetError::TenorParse(key.to_string(), tenor_error.to_string())
+                    })?;
+                    by_date(maturity, None)
+                }
+            }
+        };
+
+        generated.map_err(InterestRateQuoteSheetError::InstrumentGeneration)
+    }
+}
@@ -0,0 +1,178 @@
+// ── swaptionvolatilityquotesheet.rs ───────────────────────────────────────────
+//
+// Swaption vol 報價，結構比照 InterestRateQuoteSheet（generator 名稱 + key → quote）：
+//
+//   atm   — key 為 "{expiry}x{tenor}"（例如 "1Yx5Y"），值為 ATM vol
+//   smile — 同樣的 key，值為各 strike offset（K − F）相對 ATM vol 的 vol spread，
+//           長度與 strike_offsets 相同；smile 格點可比 ATM 矩陣稀疏，
+//           但兩者各自須構成完整的 expiry × tenor 格點
+//
+// vol 的意義（normal / shifted lognormal）由 volatility_type 決定；
+// 日期依 generator 名稱對應的 swap generator 產生（見 SwaptionVolatilityCube）。
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+
+use serde::Deserialize;
+
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
+use crate::model::volatility::blackformula::VolatilityType;
+use crate::time::period::Period;
+
+
+/// 解析 "{expiry}x{tenor}" 格式的 key，例如 "1Yx5Y"、"3Mx10Y"。
+pub fn parse_expiry_tenor_key(key: &str) -> Result<(Period, Period), String> {
+    let (expiry, tenor) = key
+        .split_once(['x', 'X'])
+        .ok_or_else(|| "expected \"{expiry}x{tenor}\"".to_string())?;
+    let expiry = Period::parse(expiry.trim()).map_err(|e| e.to_string())?;
+    let tenor = Period::parse(tenor.trim()).map_err(|e| e.to_string())?;
+    Ok((expiry, tenor))
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SwaptionVolatilityQuoteSheet
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct SwaptionVolatilityQuoteSheet {
+    generator_name:  String,
+    volatility_type: VolatilityType,
+    atm:             HashMap<String, f64>,
+    /// smile 的 strike offset（K − F），遞增排列。
+    strike_offsets:  Vec<f64>,
+    smile:           HashMap<String, Vec<f64>>,
+}
+
+impl SwaptionVolatilityQuoteSheet {
+    pub fn new(generator_name: String, volatility_type: VolatilityType) -> Self {
+        Self {
+            generator_name,
+            volatility_type,
+            atm:            HashMap::new(),
+            strike_offsets: Vec::new(),
+            smile:          HashMap::new(),
+        }
+    }
+
+    /// 設定 smile 的 strike offsets；既有的 smile 報價會被清除。
+    pub fn set_strike_offsets(&mut self, strike_offsets: Vec<f64>) {
+        self.strike_offsets = strike_offsets;
+        self.smile.clear();
+    }
+
+    pub fn add_atm_quote(&mut self, key: impl Into<String>, volatility: f64) {
+        self.atm.insert(key.into(), volatility);
+    }
+
+    /// 新增 smile 報價；長度須與 strike offsets 相同。
+    pub fn add_smile_quote(&mut self, key: impl Into<String>, spreads: Vec<f64>) -> Result<(), ManagerError> {
+        let key = key.into();
+        if spreads.len() != self.strike_offsets.len() {
+            return Err(ManagerError::InvalidValue(format!(
+                "smile {} has {} spreads but {} strike offsets",
+                key,
+                spreads.len(),
+                self.strike_offsets.len(),
+            )));
+        }
+        self.smile.insert(key, spreads);
+        Ok(())
+    }
+
+    pub fn get_atm_quote(&self, key: &str) -> Option<&f64> {
+        self.atm.get(key)
+    }
+
+    pub fn get_smile_quote(&self, key: &str) -> Option<&Vec<f64>> {
+        self.smile.get(key)
+    }
+
+    pub fn atm_keys(&self) -> impl Iterator<Item = &String> {
+        self.atm.keys()
+    }
+
+    pub fn smile_keys(&self) -> impl Iterator<Item = &String> {
+        self.smile.keys()
+    }
+
+    pub fn atm_quotes(&self) -> impl Iterator<Item = (&String, &f64)> {
+        self.atm.iter()
+    }
+
+    pub fn smile_quotes(&self) -> impl Iterator<Item = (&String, &Vec<f64>)> {
+        self.smile.iter()
+    }
+
+    pub fn strike_offsets(&self) -> &[f64] {
+        &self.strike_offsets
+    }
+
+    pub fn generator_name(&self) -> &str {
+        &self.generator_name
+    }
+
+    pub fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+
+    /// 從 JSON 檔案載入（支援 array 或單一 object），回傳 (名稱, quote sheet)。
+    pub fn load_from_reader(file_path: &str) -> Result<Vec<(String, Self)>, ManagerError> {
+        let file = File::open(file_path)?;
+        let json_value: serde_json::Value = serde_json::from_reader(BufReader::new(file))?;
+        match json_value {
+            serde_json::Value::Array(values) => values.into_iter().map(Self::from_json_value).collect(),
+            value => Ok(vec![Self::from_json_value(value)?]),
+        }
+    }
+
+    /// 從單一 JSON 物件解析，回傳 (名稱, quote sheet)。
+    pub fn from_json_value(json_value: serde_json::Value) -> Result<(String, Self), ManagerError> {
+        let named: Named<SwaptionVolatilityQuoteSheetJsonProp> = parse_json_value(json_value)?;
+        let p = named.inner;
+
+        let mut sheet = Self::new(p.swap_generator, p.volatility_type);
+        for (key, volatility) in p.atm {
+            sheet.add_atm_quote(key, volatility);
+        }
+        sheet.set_strike_offsets(p.strike_offsets);
+        for (key, spreads) in p.smile {
+            sheet.add_smile_quote(key, spreads)?;
+        }
+        Ok((named.name, sheet))
+    }
+}
+
+
+// ═════════════════════════════════════════════════════════════════════════════
+// JSON
+// ═════════════════════════════════════════════════════════════════════════════
+//
+// JSON 範例（normal vol，smile 以 strike offset 表示）：
+//   {
+//     "name": "USD_SWAPTION_VOL",
+//     "swap_generator": "USD_IRS",
+//     "volatility_type": { "type": "Normal" },
+//     "atm": { "1Yx5Y": 0.0095, "1Yx10Y": 0.0090, "5Yx5Y": 0.0088, "5Yx10Y": 0.0084 },
+//     "strike_offsets": [-0.01, -0.005, 0.0, 0.005, 0.01],
+//     "smile": {
+//       "1Yx5Y": [0.0012, 0.0004, 0.0, 0.0003, 0.0010],
+//       "5Yx5Y": [0.0008, 0.0003, 0.0, 0.0002, 0.0007]
+//     }
+//   }
+//
+// shifted lognormal：
+//   "volatility_type": { "type": "ShiftedLognormal", "shift": 0.01 }
+
+#[derive(Deserialize)]
+struct SwaptionVolatilityQuoteSheetJsonProp {
+    swap_generator:  String,
+    volatility_type: VolatilityType,
+    atm:             HashMap<String, f64>,
+    #[serde(default)]
+    strike_offsets:  Vec<f64>,
+    #[serde(default)]
+    smile:           HashMap<String, Vec<f64>>,
+}
@@ -1,8 +1,17 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use chrono::NaiveDate;
+
+use crate::market::fxmarket::FxMatket;
+use crate::market::market::Market;
+use crate::marketdata::fx::fxvolatilityquotesheet::FxVolatilityQuoteSheet;
 use crate::marketdata::interestrate::interestratequotesheet::InterestRateQuoteSheet;
+use crate::marketdata::interestrate::swaptionvolatilityquotesheet::SwaptionVolatilityQuoteSheet;
+use crate::model::fx::fxforwardcurve::FxForwardCurve;
+use crate::model::fx::fxvolatility::FxVolatility;
 use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::model::volatility::interestratevolatility::InterestRateVolatility;
 
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -100,26 +109,159 @@ impl Default for InterestRateMarketData {
 }
 
 
+// ─────────────────────────────────────────────────────────────────────────────
+// FxMarketData
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// FX 市場資料，key 為貨幣對代碼（CurrencyPair::code，例如 "USDTWD"）：
+//   spots    — 即期匯率（ccy2 per ccy1）
+//   fixings  — 歷史 fixing（MTM reset 等已過去的觀察日使用）；key 亦可為
+//              fixing source 名稱（例如 NDF 的 "TAIFX1"）
+
+pub struct FxMarketData {
+    spots:   HashMap<String, f64>,
+    fixings: HashMap<String, HashMap<NaiveDate, f64>>,
+}
+
+impl FxMarketData {
+    pub fn new() -> Self {
+        Self {
+            spots:   HashMap::new(),
+            fixings: HashMap::new(),
+        }
+    }
+
+    pub fn insert_spot(&mut self, pair_code: impl Into<String>, spot: f64) {
+        self.spots.insert(pair_code.into(), spot);
+    }
+
+    pub fn spot(&self, pair_code: &str) -> Option<f64> {
+        self.spots.get(pair_code).copied()
+    }
+
+    pub fn insert_fixing(&mut self, pair_code: impl Into<String>, fixing_date: NaiveDate, rate: f64) {
+        self.fixings
+            .entry(pair_code.into())
+            .or_default()
+            .insert(fixing_date, rate);
+    }
+
+    pub fn fixing(&self, pair_code: &str, fixing_date: NaiveDate) -> Option<f64> {
+        self.fixings.get(pair_code)?.get(&fixing_date).copied()
+    }
+}
+
+impl Default for FxMarketData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// VolatilityMarketData
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// option 相關的 vol 市場資料：
+//   fx_quote_sheets             — ATM / RR / BF 報價（FxVolatilitySurfaceCalibrator 的輸入），
+//                                 key 為貨幣對代碼
+//   fx_volatilities             — 已校準的 vol surface（或 flat vol），FxVanillaOptionPricer 由此取 vol
+//   swaption_quote_sheets       — swaption ATM 矩陣與 smile 報價（SwaptionVolatilityCube 的輸入）
+//   interest_rate_volatilities  — swaption / cap / floor 共用的 vol（cube 或 flat vol），
+//                                 key 由 pricer 指定
+
+pub struct VolatilityMarketData {
+    fx_quote_sheets:            HashMap<String, FxVolatilityQuoteSheet>,
+    fx_volatilities:            HashMap<String, Arc<dyn FxVolatility>>,
+    swaption_quote_sheets:      HashMap<String, SwaptionVolatilityQuoteSheet>,
+    interest_rate_volatilities: HashMap<String, Arc<dyn InterestRateVolatility>>,
+}
+
+impl VolatilityMarketData {
+    pub fn new() -> Self {
+        Self {
+            fx_quote_sheets:            HashMap::new(),
+            fx_volatilities:            HashMap::new(),
+            swaption_quote_sheets:      HashMap::new(),
+            interest_rate_volatilities: HashMap::new(),
+        }
+    }
+
+    // ── FX ────────────────────────────────────────────────────────────────────
+
+    pub fn add_fx_quote_sheet(&mut self, pair_code: impl Into<String>, sheet: FxVolatilityQuoteSheet) {
+        self.fx_quote_sheets.insert(pair_code.into(), sheet);
+    }
+
+    pub fn get_fx_quote_sheet(&self, pair_code: &str) -> Option<&FxVolatilityQuoteSheet> {
+        self.fx_quote_sheets.get(pair_code)
+    }
+
+    pub fn insert_fx_volatility(&mut self, pair_code: impl Into<String>, volatility: Arc<dyn FxVolatility>) {
+        self.fx_volatilities.insert(pair_code.into(), volatility);
+    }
+
+    pub fn get_fx_volatility(&self, pair_code: &str) -> Option<&Arc<dyn FxVolatility>> {
+        self.fx_volatilities.get(pair_code)
+    }
+
+    // ── Interest rate ─────────────────────────────────────────────────────────
+
+    pub fn add_swaption_quote_sheet(&mut self, name: impl Into<String>, sheet: SwaptionVolatilityQuoteSheet) {
+        self.swaption_quote_sheets.insert(name.into(), sheet);
+    }
+
+    pub fn get_swaption_quote_sheet(&self, name: &str) -> Option<&SwaptionVolatilityQuoteSheet> {
+        self.swaption_quote_sheets.get(name)
+    }
+
+    pub fn swaption_quote_sheets(&self) -> &HashMap<String, SwaptionVolatilityQuoteSheet> {
+        &self.swaption_quote_sheets
+    }
+
+    pub fn insert_interest_rate_volatility(
+        &mut self,
+        name:       impl Into<String>,
+        volatility: Arc<dyn InterestRateVolatility>,
+    ) {
+        self.interest_rate_volatilities.insert(name.into(), volatility);
+    }
+
+    pub fn get_interest_rate_volatility(&self, name: &str) -> Option<&Arc<dyn InterestRateVolatility>> {
+        self.interest_rate_volatilities.get(name)
+    }
+}
+
+impl Default for VolatilityMarketData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
 // ─────────────────────────────────────────────────────────────────────────────
 // MarketDataSet
 // ─────────────────────────────────────────────────────────────────────────────
 //
 // 系統中所有市場資料的頂層容器。
 // 對應 Configuration 的靜態設定（generators、calendars 等），
-// MarketDataSet 持有動態的市場資料（quotes、calibrated curves）。
+// MarketDataSet 持有動態的市場資料（quotes、calibrated curves、FX、volatility）。
 //
 // 未來可擴充加入：
-//   fx:     FxMarketData
 //   credit: CreditMarketData
 
 pub struct MarketDataSet {
     interest_rate: InterestRateMarketData,
+    fx:            FxMarketData,
+    volatility:    VolatilityMarketData,
 }
 
 impl MarketDataSet {
     pub fn new() -> Self {
         Self {
             interest_rate: InterestRateMarketData::new(),
+            fx:            FxMarketData::new(),
+            volatility:    VolatilityMarketData::new(),
         }
     }
 
@@ -133,6 +275,26 @@ impl MarketDataSet {
         &mut self.interest_rate
     }
 
+    // ── FX ────────────────────────────────────────────────────────────────────
+
+    pub fn fx(&self) -> &FxMarketData {
+        &self.fx
+    }
+
+    pub fn fx_mut(&mut self) -> &mut FxMarketData {
+        &mut self.fx
+    }
+
+    // ── Volatility ────────────────────────────────────────────────────────────
+
+    pub fn volatility(&self) -> &VolatilityMarketData {
+        &self.volatility
+    }
+
+    pub fn volatility_mut(&mut self) -> &mut VolatilityMarketData {
+        &mut self.volatility
+    }
+
     // ── 常用的便利方法，避免呼叫端一直往下鑽 ─────────────────────────────────
 
     /// 取得 quote sheet。
@@ -167,6 +329,16 @@ impl MarketDataSet {
     pub fn quote_book(&self) -> &HashMap<String, InterestRateQuoteSheet> {
         self.interest_rate.curve_market_data().quote_book()
     }
+
+    /// 以 FX market 的 spot 與兩幣別 discount curve 組出 FX forward curve。
+    ///
+    /// spot 報價或任一條 curve 不存在時回傳 None。
+    pub fn fx_forward_curve(&self, fx_market: &FxMatket, horizon: NaiveDate) -> Option<FxForwardCurve> {
+        let spot = self.fx.spot(&fx_market.currency_pair().code())?;
+        let foreign_curve = self.get_curve(fx_market.foreign_discount_curve_name())?;
+        let domestic_curve = self.get_curve(fx_market.domestic_discount_curve_name())?;
+        Some(FxForwardCurve::new(spot, fx_market.settlement_date(horizon), foreign_curve, domestic_curve))
+    }
 }
 
 impl Default for MarketDataSet {
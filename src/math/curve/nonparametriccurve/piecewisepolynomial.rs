@@ -310,6 +310,124 @@ fn generate_pchip_coef_list(points: &[Point2D]) -> Vec<Vec<f64>> {
 }
 
 
+// ─────────────────────────────────────────────
+// Monotone Convex（Hagan-West）
+// ─────────────────────────────────────────────
+//
+// 輸入慣例與其他型別不同：
+//   y_0       — 節點值 f(x_0)（左端點邊界條件）
+//   y_i, i≥1 — f 在 [x_{i-1}, x_i] 的區間平均（discrete forward）
+//
+// 節點值：
+//   f_i = (h_{i+1} y_i + h_i y_{i+1}) / (h_i + h_{i+1})     內部節點
+//   f_n = y_n − (f_{n-1} − y_n) / 2                        右端點
+//
+// 區間 i 上 f(x) = y_i + g(u)，u = (x − x_{i-1}) / h_i，g0 = f_{i-1} − y_i，g1 = f_i − y_i。
+// g 依 (g0, g1) 所在區域取 Hagan-West 的四種形式之一（二次或分段二次），
+// 每種形式都滿足 ∫₀¹ g = 0，因此區間積分 = y_i h_i 與相鄰點無關。
+// 分段處多產生一個 Subpolynomial，不影響 find_segment / 累積積分。
+// 兩端各加一個零長度的常數區段（f_0、f_n），使區間外的值與積分為平坦延伸
+// （Hagan-West 慣例），而非二次式外插。
+//
+// 不做 positivity collar（允許負利率）。
+
+/// |g0| 或 |g1| 小於此值時視為 0，直接取二次式，避免 η 的分母趨近 0。
+const MONOTONE_CONVEX_EPSILON: f64 = 1e-14;
+
+/// 節點 x 相差小於此值時視為同一點（零長度端點區段的 lhs_x 可能有浮點誤差）。
+const KNOT_X_EPSILON: f64 = 1e-12;
+
+/// 以 w = (u − u_c) / len 表示的一段 y + a + b w²，轉為以 x_s 為左端點的 Horner 係數。
+fn monotone_convex_piece(
+    level:   f64,
+    shift:   f64,
+    scale:   f64,
+    start_u: f64,
+    center:  f64,
+    len:     f64,
+    h:       f64,
+) -> Vec<f64> {
+    let p = (start_u - center) / len;
+    let q = 1.0 / (h * len);
+    vec![scale * q * q, 2.0 * scale * p * q, level + shift + scale * p * p]
+}
+
+/// 回傳 (左端點 u, Horner 係數) 的列表。
+fn monotone_convex_interval(level: f64, g0: f64, g1: f64, h: f64) -> Vec<(f64, Vec<f64>)> {
+    let quadratic = || vec![
+        3.0 * (g0 + g1) / (h * h),
+        -(4.0 * g0 + 2.0 * g1) / h,
+        level + g0,
+    ];
+
+    if g0.abs() < MONOTONE_CONVEX_EPSILON || g1.abs() < MONOTONE_CONVEX_EPSILON {
+        // 包含 g0 = g1 = 0（常數）；單側為 0 時二次式仍連續且積分為 0
+        return vec![(0.0, quadratic())];
+    }
+
+    let sector_one = (g0 < 0.0 && -0.5 * g0 <= g1 && g1 <= -2.0 * g0)
+        || (g0 > 0.0 && -0.5 * g0 >= g1 && g1 >= -2.0 * g0);
+    let sector_two = (g0 < 0.0 && g1 > -2.0 * g0) || (g0 > 0.0 && g1 < -2.0 * g0);
+    let sector_three = (g0 > 0.0 && 0.0 > g1 && g1 > -0.5 * g0)
+        || (g0 < 0.0 && 0.0 < g1 && g1 < -0.5 * g0);
+
+    if sector_one {
+        vec![(0.0, quadratic())]
+    } else if sector_two {
+        // g = g0（u ≤ η），之後二次上升至 g1
+        let eta = (g1 + 2.0 * g0) / (g1 - g0);
+        vec![
+            (0.0, vec![level + g0]),
+            (eta, monotone_convex_piece(level, g0, g1 - g0, eta, eta, 1.0 - eta, h)),
+        ]
+    } else if sector_three {
+        // 二次下降至 g1（u < η），之後 g = g1
+        let eta = 3.0 * g1 / (g1 - g0);
+        vec![
+            (0.0, monotone_convex_piece(level, g1, g0 - g1, 0.0, eta, eta, h)),
+            (eta, vec![level + g1]),
+        ]
+    } else {
+        // g0、g1 同號：兩段二次式在 η 處取極值 A
+        let eta = g1 / (g0 + g1);
+        let a = -g0 * g1 / (g0 + g1);
+        vec![
+            (0.0, monotone_convex_piece(level, a, g0 - a, 0.0, eta, eta, h)),
+            (eta, monotone_convex_piece(level, a, g1 - a, eta, eta, 1.0 - eta, h)),
+        ]
+    }
+}
+
+/// 回傳 (左端點 x, Horner 係數) 的列表，長度 ≥ 區間數。
+fn generate_monotone_convex_segments(points: &[Point2D]) -> Vec<(f64, Vec<f64>)> {
+    let n = points.len() - 1;
+    let h: Vec<f64> = (0..n).map(|i| points[i + 1].x() - points[i].x()).collect();
+    let y: Vec<f64> = points.iter().map(|pt| pt.y()).collect();
+
+    let mut f = vec![0.0_f64; n + 1];
+    f[0] = y[0];
+    for i in 1..n {
+        f[i] = (h[i] * y[i] + h[i - 1] * y[i + 1]) / (h[i - 1] + h[i]);
+    }
+    f[n] = y[n] - 0.5 * (f[n - 1] - y[n]);
+
+    let left_flat  = std::iter::once((points[0].x(), vec![f[0]]));
+    let right_flat = std::iter::once((points[n].x(), vec![f[n]]));
+
+    let interior = (0..n)
+        .flat_map(|i| {
+            let x_left = points[i].x();
+            let width  = h[i];
+            monotone_convex_interval(y[i + 1], f[i] - y[i + 1], f[i + 1] - y[i + 1], width)
+                .into_iter()
+                .filter(|(u, _)| *u < 1.0)
+                .map(move |(u, coefs)| (x_left + u * width, coefs))
+        });
+
+    left_flat.chain(interior).chain(right_flat).collect()
+}
+
+
 // ─────────────────────────────────────────────
 // PolynomialType
 // ─────────────────────────────────────────────
@@ -328,6 +446,9 @@ pub enum PolynomialType {
     AkimaCubic,
     ModifiedAkimaCubic,
     PiecewiseCubicHermite,
+    /// Hagan-West monotone convex，搭配 InstantaneousForwardRate 使用：
+    /// 第一個點為節點值，其後各點為區間平均遠期（見上方說明）
+    MonotoneConvex,
 }
 
 fn get_necessary_points(polynomial_type: PolynomialType) -> usize {
@@ -342,6 +463,7 @@ fn get_necessary_points(polynomial_type: PolynomialType) -> usize {
         PolynomialType::AkimaCubic            => 3,
         PolynomialType::ModifiedAkimaCubic    => 3,
         PolynomialType::PiecewiseCubicHermite => 3,
+        PolynomialType::MonotoneConvex        => 2,
     }
 }
 
@@ -375,24 +497,29 @@ impl PiecewisePolynomial {
             return None;
         }
 
-        let coef_list = match polynomial_type {
-            PolynomialType::ForwardFlat           => generate_forward_flat_coef_list(&points),
-            PolynomialType::BackwardFlat          => generate_backward_flat_coef_list(&points),
-            PolynomialType::Linear                => generate_linear_coef_list(&points),
-            PolynomialType::NaturalCubic          => generate_natural_cubic_coef_list(&points),
-            PolynomialType::FinancialCubic        => generate_financial_cubic_coef_list(&points),
-            PolynomialType::ClampedCubic          => generate_clamped_cubic_coef_list(&points, 0.0, 0.0),
-            PolynomialType::NotAKnotCubic         => generate_not_a_knot_cubic_coef_list(&points),
-            PolynomialType::AkimaCubic            => generate_akima_coef_list(&points),
-            PolynomialType::ModifiedAkimaCubic    => generate_modified_akima_coef_list(&points),
-            PolynomialType::PiecewiseCubicHermite => generate_pchip_coef_list(&points),
+        let max_x = points.last().unwrap().x();
+        let at_knots = |coef_list: Vec<Vec<f64>>| -> Vec<(f64, Vec<f64>)> {
+            points.iter().map(|pt| pt.x()).zip(coef_list).collect()
         };
 
-        let n_seg = points.len() - 1;
-        let max_x = points.last().unwrap().x();
+        let segments = match polynomial_type {
+            PolynomialType::ForwardFlat           => at_knots(generate_forward_flat_coef_list(&points)),
+            PolynomialType::BackwardFlat          => at_knots(generate_backward_flat_coef_list(&points)),
+            PolynomialType::Linear                => at_knots(generate_linear_coef_list(&points)),
+            PolynomialType::NaturalCubic          => at_knots(generate_natural_cubic_coef_list(&points)),
+            PolynomialType::FinancialCubic        => at_knots(generate_financial_cubic_coef_list(&points)),
+            PolynomialType::ClampedCubic          => at_knots(generate_clamped_cubic_coef_list(&points, 0.0, 0.0)),
+            PolynomialType::NotAKnotCubic         => at_knots(generate_not_a_knot_cubic_coef_list(&points)),
+            PolynomialType::AkimaCubic            => at_knots(generate_akima_coef_list(&points)),
+            PolynomialType::ModifiedAkimaCubic    => at_knots(generate_modified_akima_coef_list(&points)),
+            PolynomialType::PiecewiseCubicHermite => at_knots(generate_pchip_coef_list(&points)),
+            // 分段點可能多於 knot 數
+            PolynomialType::MonotoneConvex        => generate_monotone_convex_segments(&points),
+        };
 
-        let subpolynomial_list: Vec<Subpolynomial> = (0..n_seg)
-            .map(|i| Subpolynomial::new(coef_list[i].clone(), points[i].x()))
+        let subpolynomial_list: Vec<Subpolynomial> = segments
+            .into_iter()
+            .map(|(lhs_x, coefs)| Subpolynomial::new(coefs, lhs_x))
             .collect();
 
         Some(Self {
@@ -489,11 +616,16 @@ impl DerivativeCurve for PiecewisePolynomialDerivativeCurve {
     }
 }
 
+/// 積分上下限截斷在 [min_x, max_x]；MonotoneConvex 例外，
+/// 以兩端的零長度常數區段平坦延伸（Hagan-West 慣例）。
 impl CurveIntegral for PiecewisePolynomialIntegralCurve {
     fn integral(&self, a: f64, b: f64) -> f64 {
-        let min_x = self.0.subpolynomial_list[0].lhs_x;
-        let a = a.clamp(min_x, self.0.max_x);
-        let b = b.clamp(min_x, self.0.max_x);
+        let (a, b) = if self.0.polynomial_type == PolynomialType::MonotoneConvex {
+            (a, b)
+        } else {
+            let min_x = self.0.subpolynomial_list[0].lhs_x;
+            (a.clamp(min_x, self.0.max_x), b.clamp(min_x, self.0.max_x))
+        };
         if a == b { return 0.0; }
         if a > b { -self.0.integral_ordered(b, a) }
         else     {  self.0.integral_ordered(a, b) }
@@ -516,6 +648,8 @@ impl NonparametricCurve for PiecewisePolynomial {
             self.max_x,
             self.subpolynomial_list.last().unwrap().value(self.max_x),
         ));
+        // MonotoneConvex 的零長度端點區段會產生重複的 x
+        pts.dedup_by(|b, a| (a.x() - b.x()).abs() < KNOT_X_EPSILON);
         pts
     }
 
@@ -0,0 +1,115 @@
+// ── nelsonsiegel.rs ───────────────────────────────────────────────────────────
+//
+// Nelson-Siegel (1987) zero rate 函數。
+//
+// # 數學定義（x = t / τ）
+//
+//   R(t)  = β₀ + β₁ g₁(x) + β₂ g₂(x)
+//   R'(t) = (β₁ g₁'(x) + β₂ g₂'(x)) / τ
+//   ∫₀ᵗ R = β₀ t + (β₁ + β₂) τ Ein(x) − β₂ τ (1 − e^{−x})
+//
+// g₁、g₂、Ein 見 `parametriccurve.rs`。
+//
+// # 參數意義
+//
+//   β₀：長端水準（t → ∞ 時 R → β₀）
+//   β₁：短端與長端的差（R(0) = β₀ + β₁）
+//   β₂：中段駝峰
+//   τ ：衰減尺度（年），必須 > 0
+//
+// 參數向量順序：[β₀, β₁, β₂, τ]。
+
+use std::sync::Arc;
+
+use crate::math::curve::curve::{Curve, CurveIntegral, DerivativeCurve, ValueCurve};
+use crate::math::curve::parametriccurve::parametriccurve::{
+    ParametricCurve,
+    curvature_loading,
+    curvature_loading_derivative,
+    curvature_loading_integral,
+    slope_loading,
+    slope_loading_derivative,
+    slope_loading_integral,
+};
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NelsonSiegel {
+    beta0: f64,
+    beta1: f64,
+    beta2: f64,
+    tau:   f64,
+}
+
+impl NelsonSiegel {
+    pub const PARAMETER_COUNT: usize = 4;
+
+    /// 建立 Nelson-Siegel 曲線。τ 不為正或任一參數非有限值時回傳 None。
+    pub fn new(beta0: f64, beta1: f64, beta2: f64, tau: f64) -> Option<Self> {
+        let finite = [beta0, beta1, beta2, tau].iter().all(|p| p.is_finite());
+        if !finite || tau <= 0.0 {
+            return None;
+        }
+        Some(Self { beta0, beta1, beta2, tau })
+    }
+
+    /// 從 `[β₀, β₁, β₂, τ]` 建立；長度不符時回傳 None。
+    pub fn from_parameters(parameters: &[f64]) -> Option<Self> {
+        match parameters {
+            [beta0, beta1, beta2, tau] => Self::new(*beta0, *beta1, *beta2, *tau),
+            _ => None,
+        }
+    }
+
+    pub fn beta0(&self) -> f64 { self.beta0 }
+    pub fn beta1(&self) -> f64 { self.beta1 }
+    pub fn beta2(&self) -> f64 { self.beta2 }
+    pub fn tau(&self)   -> f64 { self.tau }
+
+    /// ∫₀ᵗ R(s) ds
+    fn antiderivative(&self, t: f64) -> f64 {
+        self.beta0 * t
+            + self.beta1 * slope_loading_integral(t, self.tau)
+            + self.beta2 * curvature_loading_integral(t, self.tau)
+    }
+}
+
+
+// ─────────────────────────────────────────────
+// Trait 實作
+// ─────────────────────────────────────────────
+
+impl ValueCurve for NelsonSiegel {
+    fn value(&self, x: f64) -> f64 {
+        let u = x / self.tau;
+        self.beta0 + self.beta1 * slope_loading(u) + self.beta2 * curvature_loading(u)
+    }
+}
+
+impl DerivativeCurve for NelsonSiegel {
+    fn derivative(&self, x: f64) -> f64 {
+        let u = x / self.tau;
+        (self.beta1 * slope_loading_derivative(u) + self.beta2 * curvature_loading_derivative(u))
+            / self.tau
+    }
+}
+
+impl CurveIntegral for NelsonSiegel {
+    /// 定義於整個實數軸，不做 clamp。
+    fn integral(&self, a: f64, b: f64) -> f64 {
+        if a == b { return 0.0; }
+        self.antiderivative(b) - self.antiderivative(a)
+    }
+}
+
+impl Curve for NelsonSiegel {
+    fn to_value_curve(&self)      -> Arc<dyn ValueCurve>      { Arc::new(*self) }
+    fn to_derivative_curve(&self) -> Arc<dyn DerivativeCurve> { Arc::new(*self) }
+    fn to_integral_curve(&self)   -> Arc<dyn CurveIntegral>   { Arc::new(*self) }
+}
+
+impl ParametricCurve for NelsonSiegel {
+    fn parameters(&self) -> Vec<f64> {
+        vec![self.beta0, self.beta1, self.beta2, self.tau]
+    }
+}
@@ -0,0 +1,133 @@
+// ── parametriccurve.rs ────────────────────────────────────────────────────────
+//
+// 參數型曲線（parametric curve）的共用 trait 與 Nelson-Siegel 族的因子函數。
+//
+// # 與 NonparametricCurve 的差異
+//
+// NonparametricCurve 由節點（Point2D）決定形狀，定義域為 [min_x, max_x]；
+// ParametricCurve 由少數參數以封閉公式定義於整個實數軸（實務上 x ≥ 0），
+// 沒有節點，也不需要外插。
+//
+// # Nelson-Siegel 因子（x = t / τ）
+//
+//   g₁(x) = (1 − e^{−x}) / x           slope loading，g₁(0) = 1
+//   g₂(x) = g₁(x) − e^{−x}             curvature loading，g₂(0) = 0
+//
+//   g₁'(x) = (x e^{−x} − 1 + e^{−x}) / x²
+//   g₂'(x) = g₁'(x) + e^{−x}
+//
+//   ∫₀ᵗ g₁(s/τ) ds = τ · Ein(t/τ)
+//   ∫₀ᵗ e^{−s/τ} ds = τ · (1 − e^{−t/τ})
+//
+// 其中 Ein(x) = ∫₀ˣ (1 − e^{−s}) / s ds 為 entire exponential integral。
+// x 很小時 g₁、g₁' 的封閉公式會有嚴重的消去誤差，改用 Taylor 展開。
+
+use crate::math::curve::curve::Curve;
+
+
+/// 所有 parametric curve 都是 `Curve`，額外提供參數向量。
+///
+/// `parameters()` 的順序與各實作的 `from_parameters()` 一致，
+/// 供校準器以參數向量作為求解變數。
+pub trait ParametricCurve: Curve + Send + Sync {
+    fn parameters(&self) -> Vec<f64>;
+}
+
+
+// ─────────────────────────────────────────────
+// Nelson-Siegel 因子函數
+// ─────────────────────────────────────────────
+
+/// |x| 小於此值時改用 Taylor 展開。
+const SMALL_X: f64 = 1e-4;
+
+/// Euler–Mascheroni 常數 γ。
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+/// g₁(x) = (1 − e^{−x}) / x
+pub(crate) fn slope_loading(x: f64) -> f64 {
+    if x.abs() < SMALL_X {
+        1.0 - x / 2.0 + x * x / 6.0
+    } else {
+        -(-x).exp_m1() / x
+    }
+}
+
+/// g₁'(x)
+pub(crate) fn slope_loading_derivative(x: f64) -> f64 {
+    if x.abs() < SMALL_X {
+        -0.5 + x / 3.0 - x * x / 8.0
+    } else {
+        let e = (-x).exp();
+        (x * e + (-x).exp_m1()) / (x * x)
+    }
+}
+
+/// g₂(x) = g₁(x) − e^{−x}
+pub(crate) fn curvature_loading(x: f64) -> f64 {
+    slope_loading(x) - (-x).exp()
+}
+
+/// g₂'(x) = g₁'(x) + e^{−x}
+pub(crate) fn curvature_loading_derivative(x: f64) -> f64 {
+    slope_loading_derivative(x) + (-x).exp()
+}
+
+/// Ein(x) = ∫₀ˣ (1 − e^{−s}) / s ds = Σ_{k≥1} (−1)^{k+1} xᵏ / (k · k!)
+///
+/// x < 1 時直接用級數；x ≥ 1 時用 Ein(x) = E₁(x) + ln x + γ，
+/// E₁ 以連分數（modified Lentz）計算。
+pub(crate) fn entire_exponential_integral(x: f64) -> f64 {
+    if x < 1.0 {
+        let mut term = x;
+        let mut sum  = x;
+        for k in 2..60 {
+            let k = k as f64;
+            term *= -x / k;
+            let contribution = term / k;
+            sum += contribution;
+            if contribution.abs() < 1e-17 * sum.abs().max(1e-300) {
+                break;
+            }
+        }
+        sum
+    } else {
+        exponential_integral_e1(x) + x.ln() + EULER_GAMMA
+    }
+}
+
+/// E₁(x)，x ≥ 1，連分數展開。
+fn exponential_integral_e1(x: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut b = x + 1.0;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..200 {
+        let a = -((i * i) as f64);
+        b += 2.0;
+        d = 1.0 / (a * d + b);
+        c = b + a / c;
+        let delta = c * d;
+        h *= delta;
+        if (delta - 1.0).abs() < 1e-16 {
+            break;
+        }
+    }
+    h * (-x).exp()
+}
+
+/// ∫₀ᵗ g₁(s/τ) ds
+pub(crate) fn slope_loading_integral(t: f64, tau: f64) -> f64 {
+    tau * entire_exponential_integral(t / tau)
+}
+
+/// ∫₀ᵗ e^{−s/τ} ds
+pub(crate) fn decay_integral(t: f64, tau: f64) -> f64 {
+    -tau * (-t / tau).exp_m1()
+}
+
+/// ∫₀ᵗ g₂(s/τ) ds
+pub(crate) fn curvature_loading_integral(t: f64, tau: f64) -> f64 {
+    slope_loading_integral(t, tau) - decay_integral(t, tau)
+}
@@ -0,0 +1,116 @@
+// ── svensson.rs ───────────────────────────────────────────────────────────────
+//
+// Svensson (1994) zero rate 函數：在 Nelson-Siegel 之上加第二個駝峰。
+//
+// # 數學定義（x₁ = t / τ₁，x₂ = t / τ₂）
+//
+//   R(t)  = β₀ + β₁ g₁(x₁) + β₂ g₂(x₁) + β₃ g₂(x₂)
+//   R'(t) = NS'(t) + β₃ g₂'(x₂) / τ₂
+//   ∫₀ᵗ R = ∫₀ᵗ NS + β₃ [τ₂ Ein(x₂) − τ₂ (1 − e^{−x₂})]
+//
+// 前四項直接委派給內部的 NelsonSiegel。
+//
+// 參數向量順序：[β₀, β₁, β₂, β₃, τ₁, τ₂]（與 ECB / Fed 公布格式一致）。
+// τ₁ = τ₂ 時 β₂、β₃ 不可識別，校準時應避免兩者初始值相同。
+
+use std::sync::Arc;
+
+use crate::math::curve::curve::{Curve, CurveIntegral, DerivativeCurve, ValueCurve};
+use crate::math::curve::parametriccurve::nelsonsiegel::NelsonSiegel;
+use crate::math::curve::parametriccurve::parametriccurve::{
+    ParametricCurve,
+    curvature_loading,
+    curvature_loading_derivative,
+    curvature_loading_integral,
+};
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Svensson {
+    nelson_siegel: NelsonSiegel,
+    beta3:         f64,
+    tau2:          f64,
+}
+
+impl Svensson {
+    pub const PARAMETER_COUNT: usize = 6;
+
+    /// 建立 Svensson 曲線。τ₁、τ₂ 不為正或任一參數非有限值時回傳 None。
+    pub fn new(
+        beta0: f64,
+        beta1: f64,
+        beta2: f64,
+        beta3: f64,
+        tau1:  f64,
+        tau2:  f64,
+    ) -> Option<Self> {
+        if !beta3.is_finite() || !tau2.is_finite() || tau2 <= 0.0 {
+            return None;
+        }
+        let nelson_siegel = NelsonSiegel::new(beta0, beta1, beta2, tau1)?;
+        Some(Self { nelson_siegel, beta3, tau2 })
+    }
+
+    /// 從 `[β₀, β₁, β₂, β₃, τ₁, τ₂]` 建立；長度不符時回傳 None。
+    pub fn from_parameters(parameters: &[f64]) -> Option<Self> {
+        match parameters {
+            [beta0, beta1, beta2, beta3, tau1, tau2] =>
+                Self::new(*beta0, *beta1, *beta2, *beta3, *tau1, *tau2),
+            _ => None,
+        }
+    }
+
+    pub fn beta0(&self) -> f64 { self.nelson_siegel.beta0() }
+    pub fn beta1(&self) -> f64 { self.nelson_siegel.beta1() }
+    pub fn beta2(&self) -> f64 { self.nelson_siegel.beta2() }
+    pub fn beta3(&self) -> f64 { self.beta3 }
+    pub fn tau1(&self)  -> f64 { self.nelson_siegel.tau() }
+    pub fn tau2(&self)  -> f64 { self.tau2 }
+}
+
+
+// ─────────────────────────────────────────────
+// Trait 實作
+// ─────────────────────────────────────────────
+
+impl ValueCurve for Svensson {
+    fn value(&self, x: f64) -> f64 {
+        self.nelson_siegel.value(x) + self.beta3 * curvature_loading(x / self.tau2)
+    }
+}
+
+impl DerivativeCurve for Svensson {
+    fn derivative(&self, x: f64) -> f64 {
+        self.nelson_siegel.derivative(x)
+            + self.beta3 * curvature_loading_derivative(x / self.tau2) / self.tau2
+    }
+}
+
+impl CurveIntegral for Svensson {
+    /// 定義於整個實數軸，不做 clamp。
+    fn integral(&self, a: f64, b: f64) -> f64 {
+        if a == b { return 0.0; }
+        self.nelson_siegel.integral(a, b)
+            + self.beta3 * (curvature_loading_integral(b, self.tau2)
+                          - curvature_loading_integral(a, self.tau2))
+    }
+}
+
+impl Curve for Svensson {
+    fn to_value_curve(&self)      -> Arc<dyn ValueCurve>      { Arc::new(*self) }
+    fn to_derivative_curve(&self) -> Arc<dyn DerivativeCurve> { Arc::new(*self) }
+    fn to_integral_curve(&self)   -> Arc<dyn CurveIntegral>   { Arc::new(*self) }
+}
+
+impl ParametricCurve for Svensson {
+    fn parameters(&self) -> Vec<f64> {
+        vec![
+            self.beta0(),
+            self.beta1(),
+            self.beta2(),
+            self.beta3,
+            self.tau1(),
+            self.tau2,
+        ]
+    }
+}
@@ -0,0 +1,112 @@
+// ── normaldistribution.rs ─────────────────────────────────────────────────────
+//
+// 標準常態分配的 pdf / cdf / inverse cdf，供 option pricing 使用。
+//
+// - cdf：Hart (1968) 有理函數近似（West, "Better approximations to cumulative
+//   normal functions", 2005），全域雙精度
+// - inverse cdf：Acklam 有理函數近似（相對誤差 ~1e-9），再以一次 Halley 步修正到雙精度
+
+const SQRT_2PI: f64 = 2.506_628_274_631_000_5;
+
+/// φ(x)
+pub fn normal_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / SQRT_2PI
+}
+
+/// Φ(x)
+pub fn normal_cdf(x: f64) -> f64 {
+    let xabs = x.abs();
+    let tail = if xabs > 37.0 {
+        0.0
+    } else {
+        let e = (-0.5 * xabs * xabs).exp();
+        if xabs < 7.071_067_811_865_47 {
+            let mut b = 3.526_249_659_989_11e-2 * xabs + 0.700_383_064_443_688;
+            b = b * xabs + 6.373_962_203_531_65;
+            b = b * xabs + 33.912_866_078_383;
+            b = b * xabs + 112.079_291_497_871;
+            b = b * xabs + 221.213_596_169_931;
+            b = b * xabs + 220.206_867_912_376;
+            let mut d = 8.838_834_764_831_84e-2 * xabs + 1.755_667_163_182_64;
+            d = d * xabs + 16.064_177_579_207;
+            d = d * xabs + 86.780_732_202_946_1;
+            d = d * xabs + 296.564_248_779_674;
+            d = d * xabs + 637.333_633_378_831;
+            d = d * xabs + 793.826_512_519_948;
+            d = d * xabs + 440.413_735_824_752;
+            e * b / d
+        } else {
+            let mut b = xabs + 0.65;
+            b = xabs + 4.0 / b;
+            b = xabs + 3.0 / b;
+            b = xabs + 2.0 / b;
+            b = xabs + 1.0 / b;
+            e / b / SQRT_2PI
+        }
+    };
+    if x > 0.0 { 1.0 - tail } else { tail }
+}
+
+/// Φ⁻¹(p)；p ∉ (0, 1) 時回傳 ±∞ 或 NaN。
+pub fn inverse_normal_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+         2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+         1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+         2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+         1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+         6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+         4.374_664_141_464_968,
+         2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.024_25;
+
+    if p.is_nan() || !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    let x = if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    };
+
+    // Halley 修正
+    let e = normal_cdf(x) - p;
+    let u = e * SQRT_2PI * (0.5 * x * x).exp();
+    x - u / (1.0 + 0.5 * x * u)
+}
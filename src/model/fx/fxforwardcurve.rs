@@ -0,0 +1,62 @@
+// ── fxforwardcurve.rs ─────────────────────────────────────────────────────────
+//
+// 由 FX spot 與兩幣別的（collateral-adjusted）discount curve 推出 FX forward。
+//
+// 報價為 ccy1 / ccy2（每 1 單位 ccy1 兌換多少 ccy2），foreign = ccy1、domestic = ccy2。
+// 令 s 為 spot 交割日，D_f / D_d 為自 horizon 起算的折現因子：
+//
+//   F(t) = S · D_f(t) / D_f(s) · D_d(s) / D_d(t)
+//
+// horizon 當下的匯率（把 horizon 現值由 ccy1 換成 ccy2 用）：
+//
+//   X₀ = S · D_d(s) / D_f(s)
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::model::interestrate::interestratecurve::{DiscountCurve, InterestRateCurve};
+
+
+pub struct FxForwardCurve {
+    spot:           f64,
+    spot_date:      NaiveDate,
+    foreign_curve:  Arc<dyn DiscountCurve>,
+    domestic_curve: Arc<dyn DiscountCurve>,
+}
+
+impl FxForwardCurve {
+    pub fn new(
+        spot:           f64,
+        spot_date:      NaiveDate,
+        foreign_curve:  &Arc<dyn InterestRateCurve>,
+        domestic_curve: &Arc<dyn InterestRateCurve>,
+    ) -> Self {
+        Self {
+            spot,
+            spot_date,
+            foreign_curve:  foreign_curve.to_discount_curve(),
+            domestic_curve: domestic_curve.to_discount_curve(),
+        }
+    }
+
+    pub fn spot(&self) -> f64 { self.spot }
+    pub fn spot_date(&self) -> NaiveDate { self.spot_date }
+
+    /// 交割日為 `value_date` 的 outright forward。
+    pub fn forward(&self, value_date: NaiveDate) -> f64 {
+        self.spot
+            * self.foreign_curve.discount(value_date) / self.foreign_curve.discount(self.spot_date)
+            * self.domestic_curve.discount(self.spot_date) / self.domestic_curve.discount(value_date)
+    }
+
+    /// forward − spot（未乘上報價的 point 單位）。
+    pub fn forward_points(&self, value_date: NaiveDate) -> f64 {
+        self.forward(value_date) - self.spot
+    }
+
+    /// X₀：horizon 當下的匯率。
+    pub fn horizon_rate(&self) -> f64 {
+        self.spot * self.domestic_curve.discount(self.spot_date) / self.foreign_curve.discount(self.spot_date)
+    }
+}
@@ -0,0 +1,43 @@
+// ── fxvolatility.rs ───────────────────────────────────────────────────────────
+//
+// FX option 的 Black volatility 來源：依到期日與 strike（ccy2 per ccy1）查詢。
+//
+// 實作：FlatFxVolatility（單一 vol）、FxVolatilitySurface（ATM / RR / BF 報價校準的 smile）。
+// 實際使用的 vol 存放於 MarketDataSet 的 volatility 區塊，key 為貨幣對代碼。
+
+use chrono::NaiveDate;
+
+
+/// option 到期時間（vol 用）的年化天數。
+const VOLATILITY_DAYS_IN_YEAR: f64 = 365.0;
+
+/// vol 使用的到期時間 τ = (到期日 − 參考日) / 365。
+pub fn volatility_time(reference_date: NaiveDate, expiry_date: NaiveDate) -> f64 {
+    (expiry_date - reference_date).num_days() as f64 / VOLATILITY_DAYS_IN_YEAR
+}
+
+
+pub trait FxVolatility: Send + Sync {
+    fn volatility(&self, expiry_date: NaiveDate, strike: f64) -> f64;
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FlatFxVolatility
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FlatFxVolatility {
+    volatility: f64,
+}
+
+impl FlatFxVolatility {
+    pub fn new(volatility: f64) -> Self {
+        Self { volatility }
+    }
+}
+
+impl FxVolatility for FlatFxVolatility {
+    fn volatility(&self, _expiry_date: NaiveDate, _strike: f64) -> f64 {
+        self.volatility
+    }
+}
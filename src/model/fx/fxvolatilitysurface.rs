@@ -0,0 +1,470 @@
+// ── fxvolatilitysurface.rs ────────────────────────────────────────────────────
+//
+// 由 ATM / 25D、10D risk reversal / butterfly 報價校準的 FX vol surface（Clark Ch.3–4）。
+//
+// # 每個 tenor 的 smile
+//
+//   1. 到期日 / 交割日依 FxVanillaOptionGenerator（FX 市場日期慣例），
+//      F、D_d、D_f 由 FxForwardCurve 與兩幣別 discount curve 取得
+//   2. delta / ATM 慣例依 FxMatket（短 / 長天期、premium-adjusted、spot / forward）
+//   3. smile vols：σ_call(Δ) = σ_ATM + SF(Δ) + RR(Δ) / 2，σ_put(Δ) = σ_ATM + SF(Δ) − RR(Δ) / 2，
+//      以各自的 vol 由 delta 反推 strike；ATM strike 依 ATM 慣例
+//   4. 以 SABR（β 固定，lognormal 展開）擬合 (strike, vol) 點，作為 strike 方向的插值
+//
+// # Broker fly → smile fly
+//
+// Smile butterfly 直接作為 SF(Δ)。Broker butterfly 報的是 market strangle：以單一 vol
+// σ_MS = σ_ATM + BF 計算 Δ call / put 的 strike K_c、K_p 與價格 V_MS。求 SF(Δ) 使
+// smile 在同樣 strike 的 strangle 價格等於 V_MS（誤差以 strangle vega 正規化為 vol 單位）。
+// 同時有 25D 與 10D 時，兩個 smile fly 交替求解（Gauss-Seidel）直到收斂。
+//
+// # 時間方向
+//
+// 在固定 forward moneyness k = ln(K / F(T)) 下對 total variance σ² τ 做線性插值；
+// 第一個 / 最後一個 tenor 之外維持該 tenor 在同一 moneyness 的 vol（flat extrapolation）。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::instrument::fx::fxvanillaoption::FxVanillaOptionGenerator;
+use crate::instrument::instrument::OptionType;
+use crate::market::fxmarket::{ATMConvention, DeltaConvention, FxMatket};
+use crate::market::market::Market;
+use crate::marketdata::fx::fxvolatilityquotesheet::{ButterflyType, FxVolatilityQuote, FxVolatilityQuoteSheet};
+use crate::marketdata::marketdataset::MarketDataSet;
+use crate::math::rootsolver::{RootSolver, RootSolverConfig};
+use crate::model::fx::fxforwardcurve::FxForwardCurve;
+use crate::model::fx::fxvolatility::{volatility_time, FxVolatility};
+use crate::model::fx::garmankohlhagen::{GarmanKohlhagen, GarmanKohlhagenError};
+use crate::model::volatility::sabr::{SabrCalibrator, SabrCalibratorConfig, SabrError, SabrParameters};
+use crate::time::period::Period;
+
+
+const DELTA_25: f64 = 0.25;
+const DELTA_10: f64 = 0.10;
+
+/// 查詢時間與 tenor 到期時間的差小於此值（年）時，直接使用該 tenor 的 smile。
+const EXPIRY_TIME_EPSILON: f64 = 1e-12;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxVolatilitySurfaceError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum FxVolatilitySurfaceError {
+    #[error("no FX volatility quotes for {0}")]
+    MissingQuotes(String),
+
+    #[error("missing market data: {0}")]
+    MissingMarketData(String),
+
+    #[error("failed to parse tenor key \"{0}\": {1}")]
+    TenorParse(String, String),
+
+    #[error("tenor {0} expires on or before the reference date")]
+    ExpiredTenor(String),
+
+    #[error("tenor {tenor}: {source}")]
+    Model {
+        tenor:  String,
+        #[source]
+        source: GarmanKohlhagenError,
+    },
+
+    #[error("tenor {tenor}: {source}")]
+    Sabr {
+        tenor:  String,
+        #[source]
+        source: SabrError,
+    },
+
+    #[error("tenor {tenor}: broker butterfly conversion failed: {reason}")]
+    Butterfly { tenor: String, reason: String },
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxSmileSection
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 單一到期日的 smile（SABR 參數與校準用的 strike / vol 點）。
+pub struct FxSmileSection {
+    tenor:       String,
+    expiry_date: NaiveDate,
+    expiry_time: f64,
+    forward:     f64,
+    atm_strike:  f64,
+    parameters:  SabrParameters,
+    /// 校準使用的 (strike, vol)，依 strike 由小到大。
+    pillars:     Vec<(f64, f64)>,
+}
+
+impl FxSmileSection {
+    pub fn tenor(&self) -> &str { &self.tenor }
+    pub fn expiry_date(&self) -> NaiveDate { self.expiry_date }
+    pub fn expiry_time(&self) -> f64 { self.expiry_time }
+    pub fn forward(&self) -> f64 { self.forward }
+    pub fn atm_strike(&self) -> f64 { self.atm_strike }
+    pub fn parameters(&self) -> &SabrParameters { &self.parameters }
+    pub fn pillars(&self) -> &[(f64, f64)] { &self.pillars }
+
+    pub fn volatility(&self, strike: f64) -> f64 {
+        self.parameters.lognormal_volatility(self.forward, strike, self.expiry_time)
+    }
+
+    /// forward moneyness ln(K / F) 對應的 vol。
+    fn volatility_at_moneyness(&self, log_moneyness: f64) -> f64 {
+        self.volatility(self.forward * log_moneyness.exp())
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxVolatilitySurface
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FxVolatilitySurface {
+    fx_market:        Arc<FxMatket>,
+    fx_forward_curve: FxForwardCurve,
+    reference_date:   NaiveDate,
+    /// 依到期日由近到遠。
+    sections:         Vec<FxSmileSection>,
+}
+
+impl FxVolatilitySurface {
+    pub fn fx_market(&self) -> &Arc<FxMatket> { &self.fx_market }
+    pub fn reference_date(&self) -> NaiveDate { self.reference_date }
+    pub fn sections(&self) -> &[FxSmileSection] { &self.sections }
+}
+
+impl FxVolatility for FxVolatilitySurface {
+    fn volatility(&self, expiry_date: NaiveDate, strike: f64) -> f64 {
+        let expiry_time = volatility_time(self.reference_date, expiry_date);
+        let forward = self.fx_forward_curve.forward(self.fx_market.settlement_date(expiry_date));
+        let log_moneyness = (strike / forward).ln();
+
+        let index = self.sections.partition_point(|s| s.expiry_time < expiry_time);
+        if index == 0 {
+            return self.sections[0].volatility_at_moneyness(log_moneyness);
+        }
+        if index == self.sections.len() {
+            return self.sections[index - 1].volatility_at_moneyness(log_moneyness);
+        }
+        let after = &self.sections[index];
+        if (after.expiry_time - expiry_time).abs() < EXPIRY_TIME_EPSILON {
+            return after.volatility_at_moneyness(log_moneyness);
+        }
+
+        let before = &self.sections[index - 1];
+        let variance = |s: &FxSmileSection| s.volatility_at_moneyness(log_moneyness).powi(2) * s.expiry_time;
+        let weight = (expiry_time - before.expiry_time) / (after.expiry_time - before.expiry_time);
+        let total_variance = variance(before) + weight * (variance(after) - variance(before));
+        (total_variance / expiry_time).sqrt()
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxVolatilitySurfaceCalibratorConfig
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone)]
+pub struct FxVolatilitySurfaceCalibratorConfig {
+    /// SABR β；FX 慣例為 1（lognormal backbone）。
+    pub sabr_beta:           f64,
+    /// broker fly 轉換的收斂門檻（strangle 價差換算成 vol）。
+    pub butterfly_tolerance: f64,
+    /// 25D / 10D smile fly 交替求解的最大輪數。
+    pub butterfly_max_iter:  usize,
+    pub sabr:                SabrCalibratorConfig,
+}
+
+impl Default for FxVolatilitySurfaceCalibratorConfig {
+    fn default() -> Self {
+        Self {
+            sabr_beta:           1.0,
+            butterfly_tolerance: 1e-8,
+            butterfly_max_iter:  20,
+            sabr:                SabrCalibratorConfig::default(),
+        }
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// TenorSmileBuilder（單一 tenor 的 smile 校準）
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct TenorSmileBuilder<'a> {
+    tenor:             &'a str,
+    quote:             &'a FxVolatilityQuote,
+    forward:           f64,
+    expiry_time:       f64,
+    domestic_discount: f64,
+    foreign_discount:  f64,
+    delta_convention:  DeltaConvention,
+    atm_convention:    ATMConvention,
+    beta:              f64,
+    sabr_calibrator:   &'a SabrCalibrator,
+}
+
+impl TenorSmileBuilder<'_> {
+    fn model(&self, volatility: f64) -> GarmanKohlhagen {
+        GarmanKohlhagen::new(
+            self.forward,
+            volatility,
+            self.expiry_time,
+            self.domestic_discount,
+            self.foreign_discount,
+        )
+    }
+
+    fn model_error(&self, source: GarmanKohlhagenError) -> FxVolatilitySurfaceError {
+        FxVolatilitySurfaceError::Model { tenor: self.tenor.to_string(), source }
+    }
+
+    fn atm_strike(&self) -> f64 {
+        self.model(self.quote.atm).atm_strike(self.atm_convention, self.delta_convention)
+    }
+
+    /// (put, call) 在 delta `delta` 的 (strike, vol)，vol 為 smile vol。
+    fn delta_pillars(
+        &self,
+        delta:         f64,
+        risk_reversal: f64,
+        smile_fly:     f64,
+    ) -> Result<[(f64, f64); 2], FxVolatilitySurfaceError> {
+        let call_volatility = self.quote.atm + smile_fly + 0.5 * risk_reversal;
+        let put_volatility = self.quote.atm + smile_fly - 0.5 * risk_reversal;
+        let call_strike = self.model(call_volatility)
+            .strike_from_delta(OptionType::Call, delta, self.delta_convention)
+            .map_err(|e| self.model_error(e))?;
+        let put_strike = self.model(put_volatility)
+            .strike_from_delta(OptionType::Put, -delta, self.delta_convention)
+            .map_err(|e| self.model_error(e))?;
+        Ok([(put_strike, put_volatility), (call_strike, call_volatility)])
+    }
+
+    /// 給定 smile fly（25D，及選填的 10D）擬合 SABR。
+    fn fit(
+        &self,
+        smile_fly_25: f64,
+        smile_fly_10: Option<f64>,
+    ) -> Result<(SabrParameters, Vec<(f64, f64)>), FxVolatilitySurfaceError> {
+        let mut pillars = vec![(self.atm_strike(), self.quote.atm)];
+        pillars.extend(self.delta_pillars(DELTA_25, self.quote.risk_reversal_25, smile_fly_25)?);
+        if let (Some((risk_reversal_10, _)), Some(smile_fly_10)) = (self.quote.ten_delta(), smile_fly_10) {
+            pillars.extend(self.delta_pillars(DELTA_10, risk_reversal_10, smile_fly_10)?);
+        }
+        pillars.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let (strikes, volatilities): (Vec<f64>, Vec<f64>) = pillars.iter().copied().unzip();
+        let parameters = self.sabr_calibrator
+            .calibrate(self.forward, self.expiry_time, self.beta, &strikes, &volatilities)
+            .map_err(|source| FxVolatilitySurfaceError::Sabr { tenor: self.tenor.to_string(), source })?;
+        Ok((parameters, pillars))
+    }
+
+    /// smile 與 market strangle 在 broker strikes 上的價差（以 strangle vega 換算成 vol）。
+    fn strangle_mismatch(
+        &self,
+        parameters: &SabrParameters,
+        delta:      f64,
+        broker_fly: f64,
+    ) -> Result<f64, FxVolatilitySurfaceError> {
+        let market_strangle = self.model(self.quote.atm + broker_fly);
+        let call_strike = market_strangle
+            .strike_from_delta(OptionType::Call, delta, self.delta_convention)
+            .map_err(|e| self.model_error(e))?;
+        let put_strike = market_strangle
+            .strike_from_delta(OptionType::Put, -delta, self.delta_convention)
+            .map_err(|e| self.model_error(e))?;
+
+        let target = market_strangle.undiscounted_price(OptionType::Call, call_strike)
+            + market_strangle.undiscounted_price(OptionType::Put, put_strike);
+        let smile_price = |option_type: OptionType, strike: f64| {
+            let volatility = parameters.lognormal_volatility(self.forward, strike, self.expiry_time);
+            self.model(volatility).undiscounted_price(option_type, strike)
+        };
+        let smile = smile_price(OptionType::Call, call_strike) + smile_price(OptionType::Put, put_strike);
+        let vega = (market_strangle.vega(call_strike) + market_strangle.vega(put_strike)) / self.domestic_discount;
+        Ok((smile - target) / vega)
+    }
+
+    /// 固定另一個 delta 的 smile fly，求解 `delta` 的 smile fly 使 strangle 價格一致。
+    fn solve_smile_fly(
+        &self,
+        solver:     &RootSolver,
+        delta:      f64,
+        broker_fly: f64,
+        fit:        impl Fn(f64) -> Result<(SabrParameters, Vec<(f64, f64)>), FxVolatilitySurfaceError>,
+    ) -> Result<f64, FxVolatilitySurfaceError> {
+        let mismatch = |smile_fly: f64| {
+            fit(smile_fly).and_then(|(parameters, _)| self.strangle_mismatch(&parameters, delta, broker_fly))
+        };
+        // 起點無法擬合時直接回傳該錯誤；目標函數只能回傳 f64，
+        // 迭代中其他點失敗時回傳 NaN，由 solver 的失敗回報
+        mismatch(broker_fly)?;
+        let objective = |smile_fly: f64| mismatch(smile_fly).unwrap_or(f64::NAN);
+        solver.solve(objective, broker_fly, None).map_err(|e| FxVolatilitySurfaceError::Butterfly {
+            tenor:  self.tenor.to_string(),
+            reason: e.to_string(),
+        })
+    }
+
+    /// broker fly 轉成 smile fly：(SF25, SF10)。
+    fn smile_flies(
+        &self,
+        butterfly_type: ButterflyType,
+        config:         &FxVolatilitySurfaceCalibratorConfig,
+    ) -> Result<(f64, Option<f64>), FxVolatilitySurfaceError> {
+        let broker_fly_10 = self.quote.ten_delta().map(|(_, butterfly_10)| butterfly_10);
+        if butterfly_type == ButterflyType::Smile {
+            return Ok((self.quote.butterfly_25, broker_fly_10));
+        }
+
+        let solver = RootSolver::new(RootSolverConfig {
+            tolerance: config.butterfly_tolerance,
+            ..RootSolverConfig::default()
+        });
+        let broker_fly_25 = self.quote.butterfly_25;
+        let Some(broker_fly_10) = broker_fly_10 else {
+            let smile_fly_25 = self.solve_smile_fly(&solver, DELTA_25, broker_fly_25, |s| self.fit(s, None))?;
+            return Ok((smile_fly_25, None));
+        };
+
+        let (mut smile_fly_25, mut smile_fly_10) = (broker_fly_25, broker_fly_10);
+        for _ in 0..config.butterfly_max_iter {
+            let next_25 = self.solve_smile_fly(
+                &solver, DELTA_25, broker_fly_25, |s| self.fit(s, Some(smile_fly_10)),
+            )?;
+            let next_10 = self.solve_smile_fly(
+                &solver, DELTA_10, broker_fly_10, |s| self.fit(next_25, Some(s)),
+            )?;
+            let change = (next_25 - smile_fly_25).abs().max((next_10 - smile_fly_10).abs());
+            smile_fly_25 = next_25;
+            smile_fly_10 = next_10;
+            if change < config.butterfly_tolerance {
+                return Ok((smile_fly_25, Some(smile_fly_10)));
+            }
+        }
+        Err(FxVolatilitySurfaceError::Butterfly {
+            tenor:  self.tenor.to_string(),
+            reason: format!(
+                "25D / 10D smile flies did not converge within {} iterations", config.butterfly_max_iter,
+            ),
+        })
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FxVolatilitySurfaceCalibrator
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FxVolatilitySurfaceCalibrator {
+    config:          FxVolatilitySurfaceCalibratorConfig,
+    sabr_calibrator: SabrCalibrator,
+}
+
+impl FxVolatilitySurfaceCalibrator {
+    pub fn new(config: FxVolatilitySurfaceCalibratorConfig) -> Self {
+        let sabr_calibrator = SabrCalibrator::new(config.sabr.clone());
+        Self { config, sabr_calibrator }
+    }
+
+    pub fn with_defaults() -> Self {
+        Self::new(FxVolatilitySurfaceCalibratorConfig::default())
+    }
+
+    pub fn config(&self) -> &FxVolatilitySurfaceCalibratorConfig { &self.config }
+
+    /// 以 MarketDataSet volatility 區塊中該貨幣對的 quote sheet 校準。
+    pub fn calibrate(
+        &self,
+        fx_market:   &Arc<FxMatket>,
+        market_data: &MarketDataSet,
+        horizon:     NaiveDate,
+    ) -> Result<FxVolatilitySurface, FxVolatilitySurfaceError> {
+        let pair_code = fx_market.currency_pair().code();
+        let quote_sheet = market_data
+            .volatility()
+            .get_fx_quote_sheet(&pair_code)
+            .ok_or(FxVolatilitySurfaceError::MissingQuotes(pair_code))?;
+        self.calibrate_quote_sheet(fx_market, quote_sheet, market_data, horizon)
+    }
+
+    /// 以指定的 quote sheet 校準；spot 與折現曲線取自 `market_data`。
+    pub fn calibrate_quote_sheet(
+        &self,
+        fx_market:   &Arc<FxMatket>,
+        quote_sheet: &FxVolatilityQuoteSheet,
+        market_data: &MarketDataSet,
+        horizon:     NaiveDate,
+    ) -> Result<FxVolatilitySurface, FxVolatilitySurfaceError> {
+        let pair_code = fx_market.currency_pair().code();
+        if quote_sheet.is_empty() {
+            return Err(FxVolatilitySurfaceError::MissingQuotes(pair_code));
+        }
+        let fx_forward_curve = market_data
+            .fx_forward_curve(fx_market, horizon)
+            .ok_or_else(|| FxVolatilitySurfaceError::MissingMarketData(format!("FX forward curve of {}", pair_code)))?;
+        let curve = |name: &String| {
+            market_data
+                .get_curve(name)
+                .map(|c| c.to_discount_curve())
+                .ok_or_else(|| FxVolatilitySurfaceError::MissingMarketData(format!("curve {}", name)))
+        };
+        let domestic_curve = curve(fx_market.domestic_discount_curve_name())?;
+        let foreign_curve = curve(fx_market.foreign_discount_curve_name())?;
+        let spot_date = fx_forward_curve.spot_date();
+        let option_generator = FxVanillaOptionGenerator::with_defaults(fx_market.clone());
+
+        let mut sections = Vec::new();
+        for (tenor, quote) in quote_sheet.quotes() {
+            let period = Period::parse(tenor)
+                .map_err(|e| FxVolatilitySurfaceError::TenorParse(tenor.clone(), e.to_string()))?;
+            let expiry_date = option_generator.expiry_date(horizon, period);
+            let delivery_date = option_generator.delivery_date(horizon, period);
+            let expiry_time = volatility_time(horizon, expiry_date);
+            if expiry_time <= 0.0 {
+                return Err(FxVolatilitySurfaceError::ExpiredTenor(tenor.clone()));
+            }
+
+            let builder = TenorSmileBuilder {
+                tenor,
+                quote,
+                forward:           fx_forward_curve.forward(delivery_date),
+                expiry_time,
+                domestic_discount: domestic_curve.discount(delivery_date) / domestic_curve.discount(spot_date),
+                foreign_discount:  foreign_curve.discount(delivery_date) / foreign_curve.discount(spot_date),
+                delta_convention:  fx_market.delta_convention(horizon, expiry_date),
+                atm_convention:    fx_market.atm_convention(),
+                beta:              self.config.sabr_beta,
+                sabr_calibrator:   &self.sabr_calibrator,
+            };
+            let (smile_fly_25, smile_fly_10) = builder.smile_flies(quote_sheet.butterfly_type(), &self.config)?;
+            let (parameters, pillars) = builder.fit(smile_fly_25, smile_fly_10)?;
+            sections.push(FxSmileSection {
+                tenor: tenor.clone(),
+                expiry_date,
+                expiry_time,
+                forward: builder.forward,
+                atm_strike: builder.atm_strike(),
+                parameters,
+                pillars,
+            });
+        }
+        sections.sort_by_key(|s| s.expiry_date);
+
+        Ok(FxVolatilitySurface {
+            fx_market: fx_market.clone(),
+            fx_forward_curve,
+            reference_date: horizon,
+            sections,
+        })
+    }
+}
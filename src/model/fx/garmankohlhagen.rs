@@ -0,0 +1,247 @@
+// ── garmankohlhagen.rs ────────────────────────────────────────────────────────
+//
+// Garman-Kohlhagen：FX European option 的 Black 公式（ccy1 = foreign，ccy2 = domestic）。
+//
+// 令 s 為 spot date、T 為交割日，τ 為 horizon 至到期日的年化時間（僅供 vol 使用），
+// F = F(T)，D_d / D_f 為 s 到 T 的 domestic / foreign 折現因子，σ̂ = σ √τ，ω = ±1：
+//
+//   d1 = (ln(F / K) + σ̂² / 2) / σ̂，d2 = d1 − σ̂
+//   V  = D_d ω [F N(ω d1) − K N(ω d2)]      （ccy2 per 1 ccy1，於 spot date）
+//
+// # Delta（DeltaConvention，Pips* 為 premium-adjusted，見 FxMatket）
+//
+//   PercentageSpot    ：ω D_f N(ω d1)
+//   PercentageForward ：ω N(ω d1)
+//   PipsSpot          ：ω D_f (K / F) N(ω d2)
+//   PipsForward       ：ω (K / F) N(ω d2)
+//
+// # Strike from delta
+//
+// 不含 premium 時有解析解：K = F exp(−ω Φ⁻¹(ω Δ_F) σ̂ + σ̂² / 2)，Δ_F 為換算後的 forward delta。
+// premium-adjusted 以 Brent 數值求解（Clark §3.3）：
+//   - call 的 delta 對 K 非單調，於 [K_min, K_max] 內求解：K_max 為同 delta 的不含 premium strike，
+//     K_min 為 delta 最大處（σ̂ N(d2) = n(d2)）
+//   - put 的 delta 對 K 單調，於 [K_max e^{−10σ̂}, K_max] 內求解
+//
+// # ATM strike（ATMConvention）
+//
+//   AtTheMoneyForward：K = F
+//   DeltaNeutral      ：K = F e^{σ̂²/2}（不含 premium）、F e^{−σ̂²/2}（premium-adjusted）
+
+use thiserror::Error;
+
+use crate::instrument::instrument::OptionType;
+use crate::market::fxmarket::{ATMConvention, DeltaConvention};
+use crate::math::normaldistribution::{inverse_normal_cdf, normal_cdf, normal_pdf};
+use crate::math::rootsolver::{RootSolver, RootSolverError};
+
+
+/// premium-adjusted put 的 strike 搜尋下界距離（以 σ̂ 計）。
+const PUT_STRIKE_SEARCH_STD_DEVS: f64 = 10.0;
+/// K_min 求解時 d2 的搜尋區間（σ̂ < 5 時根必在其中）。
+const D2_SEARCH_LOWER: f64 = -5.0;
+const D2_SEARCH_UPPER: f64 = 10.0;
+/// σ̂ = 0 時 |ln(F/K)| 小於此值視為 ATM（d1 = 0）。
+const ATM_LOG_MONEYNESS_EPSILON: f64 = 1e-12;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// GarmanKohlhagenError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum GarmanKohlhagenError {
+    #[error("delta {delta} is not attainable for a {option_type:?} option under {convention:?}")]
+    DeltaOutOfRange {
+        delta:       f64,
+        option_type: OptionType,
+        convention:  DeltaConvention,
+    },
+
+    #[error("strike from delta failed: {0}")]
+    Solver(#[from] RootSolverError),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// GarmanKohlhagen
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct GarmanKohlhagen {
+    forward:           f64,
+    volatility:        f64,
+    expiry_time:       f64,
+    domestic_discount: f64,
+    foreign_discount:  f64,
+}
+
+impl GarmanKohlhagen {
+    /// - `forward`：交割日的 outright F
+    /// - `expiry_time`：horizon 至到期日的年化時間
+    /// - `domestic_discount` / `foreign_discount`：spot date 至交割日的折現因子
+    pub fn new(
+        forward:           f64,
+        volatility:        f64,
+        expiry_time:       f64,
+        domestic_discount: f64,
+        foreign_discount:  f64,
+    ) -> Self {
+        Self {
+            forward,
+            volatility,
+            expiry_time,
+            domestic_discount,
+            foreign_discount,
+        }
+    }
+
+    pub fn forward(&self) -> f64 { self.forward }
+    pub fn volatility(&self) -> f64 { self.volatility }
+    pub fn expiry_time(&self) -> f64 { self.expiry_time }
+    pub fn domestic_discount(&self) -> f64 { self.domestic_discount }
+    pub fn foreign_discount(&self) -> f64 { self.foreign_discount }
+
+    /// spot 匯率 S = F D_d / D_f。
+    pub fn spot(&self) -> f64 {
+        self.forward * self.domestic_discount / self.foreign_discount
+    }
+
+    /// σ̂ = σ √τ（已到期時為 0）。
+    pub fn std_dev(&self) -> f64 {
+        self.volatility * self.expiry_time.max(0.0).sqrt()
+    }
+
+    pub fn d1(&self, strike: f64) -> f64 {
+        let std_dev = self.std_dev();
+        let log_moneyness = (self.forward / strike).ln();
+        if std_dev > 0.0 {
+            log_moneyness / std_dev + 0.5 * std_dev
+        } else if log_moneyness.abs() < ATM_LOG_MONEYNESS_EPSILON {
+            0.0
+        } else {
+            log_moneyness.signum() * f64::INFINITY
+        }
+    }
+
+    pub fn d2(&self, strike: f64) -> f64 {
+        self.d1(strike) - self.std_dev()
+    }
+
+    /// 交割日價值 ω [F N(ω d1) − K N(ω d2)]（未折現）。
+    pub fn undiscounted_price(&self, option_type: OptionType, strike: f64) -> f64 {
+        let omega = option_type.sign();
+        omega * (self.forward * normal_cdf(omega * self.d1(strike)) - strike * normal_cdf(omega * self.d2(strike)))
+    }
+
+    /// spot date 的價值（ccy2 per 1 ccy1）。
+    pub fn price(&self, option_type: OptionType, strike: f64) -> f64 {
+        self.domestic_discount * self.undiscounted_price(option_type, strike)
+    }
+
+    pub fn delta(&self, option_type: OptionType, strike: f64, convention: DeltaConvention) -> f64 {
+        let omega = option_type.sign();
+        let forward_delta = if Self::is_premium_adjusted(convention) {
+            omega * strike / self.forward * normal_cdf(omega * self.d2(strike))
+        } else {
+            omega * normal_cdf(omega * self.d1(strike))
+        };
+        forward_delta * self.spot_factor(convention)
+    }
+
+    /// spot gamma：D_f n(d1) / (S σ̂)。
+    pub fn gamma(&self, strike: f64) -> f64 {
+        self.foreign_discount * normal_pdf(self.d1(strike)) / (self.spot() * self.std_dev())
+    }
+
+    /// vega（每 1.00 vol）：D_d F n(d1) √τ。
+    pub fn vega(&self, strike: f64) -> f64 {
+        self.domestic_discount * self.forward * normal_pdf(self.d1(strike)) * self.expiry_time.max(0.0).sqrt()
+    }
+
+    /// 依 delta 慣例由 delta 反推 strike。
+    ///
+    /// # Errors
+    ///
+    /// - delta 正負號與 option 類型不符，或超出該慣例可達的範圍
+    /// - premium-adjusted 數值求解失敗
+    pub fn strike_from_delta(
+        &self,
+        option_type: OptionType,
+        delta:       f64,
+        convention:  DeltaConvention,
+    ) -> Result<f64, GarmanKohlhagenError> {
+        let out_of_range = || GarmanKohlhagenError::DeltaOutOfRange { delta, option_type, convention };
+        let omega = option_type.sign();
+        let forward_delta = delta / self.spot_factor(convention);
+        if self.std_dev() <= 0.0 || !(omega * forward_delta > 0.0 && omega * forward_delta < 1.0) {
+            return Err(out_of_range());
+        }
+
+        let unadjusted_strike = self.unadjusted_strike(option_type, forward_delta);
+        if !Self::is_premium_adjusted(convention) {
+            return Ok(unadjusted_strike);
+        }
+
+        let solver = RootSolver::with_defaults();
+        let objective = |strike: f64| self.delta(option_type, strike, convention) - delta;
+        let lower_strike = match option_type {
+            OptionType::Call => {
+                let minimum_strike = self.premium_adjusted_call_minimum_strike(&solver)?;
+                if objective(minimum_strike) < 0.0 {
+                    return Err(out_of_range());
+                }
+                minimum_strike
+            }
+            OptionType::Put => unadjusted_strike * (-PUT_STRIKE_SEARCH_STD_DEVS * self.std_dev()).exp(),
+        };
+        Ok(solver.solve(objective, lower_strike, Some(unadjusted_strike))?)
+    }
+
+    /// 依 ATM 慣例計算 ATM strike；DeltaNeutral 另依 delta 慣例決定是否含 premium。
+    pub fn atm_strike(&self, atm_convention: ATMConvention, delta_convention: DeltaConvention) -> f64 {
+        match atm_convention {
+            ATMConvention::AtTheMoneyForward => self.forward,
+            ATMConvention::DeltaNeutral => {
+                let variance = self.std_dev() * self.std_dev();
+                if Self::is_premium_adjusted(delta_convention) {
+                    self.forward * (-0.5 * variance).exp()
+                } else {
+                    self.forward * (0.5 * variance).exp()
+                }
+            }
+        }
+    }
+
+    // ── 內部 ──────────────────────────────────────────────────────────────────
+
+    fn is_premium_adjusted(convention: DeltaConvention) -> bool {
+        matches!(convention, DeltaConvention::PipsSpot | DeltaConvention::PipsForward)
+    }
+
+    /// spot delta 相對 forward delta 的乘數。
+    fn spot_factor(&self, convention: DeltaConvention) -> f64 {
+        match convention {
+            DeltaConvention::PipsSpot | DeltaConvention::PercentageSpot       => self.foreign_discount,
+            DeltaConvention::PipsForward | DeltaConvention::PercentageForward => 1.0,
+        }
+    }
+
+    /// 不含 premium 的 forward delta 對應的 strike。
+    fn unadjusted_strike(&self, option_type: OptionType, forward_delta: f64) -> f64 {
+        let omega = option_type.sign();
+        let std_dev = self.std_dev();
+        let d1 = omega * inverse_normal_cdf(omega * forward_delta);
+        self.forward * (-d1 * std_dev + 0.5 * std_dev * std_dev).exp()
+    }
+
+    /// premium-adjusted call delta 最大處的 strike：σ̂ N(d2) = n(d2)。
+    fn premium_adjusted_call_minimum_strike(&self, solver: &RootSolver) -> Result<f64, RootSolverError> {
+        let std_dev = self.std_dev();
+        let d2 = solver.solve(
+            |d2| std_dev * normal_cdf(d2) - normal_pdf(d2),
+            D2_SEARCH_LOWER,
+            Some(D2_SEARCH_UPPER),
+        )?;
+        Ok(self.forward * (-d2 * std_dev - 0.5 * std_dev * std_dev).exp())
+    }
+}
@@ -0,0 +1,313 @@
+// ── bumpedinterestratecurve.rs ────────────────────────────────────────────────
+//
+// 對任意 InterestRateCurve 套用利率擾動（bump）的包裝曲線，
+// 供 DV01 / key-rate duration 報表與情境重估使用，不需重新校準。
+//
+// # 數學定義
+//
+// 每種 bump 都表示為對瞬時遠期利率的加項 Δf(t)，及其累積 I(t) = ∫₀ᵗ Δf：
+//
+//   D'(t) = D(t) × exp(−I(t))
+//   R'(t) = R(t) + I(t) / t
+//   f'(t) = f(t) + Δf(t)
+//
+// | Bump            | I(t)                         | Δf(t)                        |
+// |-----------------|------------------------------|------------------------------|
+// | ParallelZero    | s t                          | s                            |
+// | KeyRate（三角） | s w(t) t                     | s (w(t) + t w'(t))           |
+// | BucketedForward | s (clamp(t, a, b) − a)       | s 1{a ≤ t < b}               |
+//
+// KeyRate 的 w(t) 為第 k 個 key tenor 上的三角形權重：在 tenor_k 為 1，
+// 線性下降至相鄰 tenor 為 0；第一個 tenor 左側、最後一個 tenor 右側保持平坦，
+// 因此所有 key-rate bump 相加等於 parallel zero bump。
+//
+// # Arc identity
+//
+// `bump_curve()` 每次回傳新的 Arc，CachedInterestRateIndex 以 Arc 指標作為
+// cache key，因此 bumped curve 不會誤用 base curve 的 projected rate cache。
+// YearFractionCalculator 直接沿用 base curve 的設定（clone）。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::model::interestrate::interestratecurve::{
+    DiscountCurve, InstForwardCurve, InterestRateCurve, YearFractionCalculator, ZeroRateCurve,
+    ZERO_TIME_EPSILON,
+};
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CurveBumpError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum CurveBumpError {
+    #[error("key-rate tenors must be non-empty and strictly increasing")]
+    InvalidKeyTenors,
+
+    #[error("key-rate index {index} out of range: {len} tenors")]
+    KeyIndexOutOfRange {
+        index: usize,
+        len:   usize,
+    },
+
+    #[error("bucket [{start}, {end}) is empty or negative")]
+    InvalidBucket {
+        start: f64,
+        end:   f64,
+    },
+
+    #[error("bump size must be finite, got {0}")]
+    InvalidSize(f64),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CurveBump
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 擾動定義。時間一律為 base curve day counter 下的年化時間，
+/// `size` 為連續複利的絕對量（1bp = 1e-4）。
+#[derive(Debug, Clone, PartialEq)]
+pub enum CurveBump {
+    /// 所有 zero rate 平移 `size`。
+    ParallelZero {
+        size: f64,
+    },
+    /// 在 `tenors[index]` 的三角形 zero rate bump。
+    KeyRate {
+        tenors: Vec<f64>,
+        index:  usize,
+        size:   f64,
+    },
+    /// 瞬時遠期利率在 [start, end) 區間平移 `size`。
+    BucketedForward {
+        start: f64,
+        end:   f64,
+        size:  f64,
+    },
+}
+
+impl CurveBump {
+    /// 每個 key tenor 一個 KeyRate bump，順序與 `tenors` 一致。
+    pub fn key_rate_set(tenors: &[f64], size: f64) -> Vec<CurveBump> {
+        (0..tenors.len())
+            .map(|index| CurveBump::KeyRate { tenors: tenors.to_vec(), index, size })
+            .collect()
+    }
+
+    /// 以相鄰邊界切出的 forward bucket，`boundaries` 長度 n 產生 n − 1 個 bump。
+    pub fn bucketed_forward_set(boundaries: &[f64], size: f64) -> Vec<CurveBump> {
+        boundaries
+            .windows(2)
+            .map(|w| CurveBump::BucketedForward { start: w[0], end: w[1], size })
+            .collect()
+    }
+
+    pub fn size(&self) -> f64 {
+        match self {
+            CurveBump::ParallelZero { size }        => *size,
+            CurveBump::KeyRate { size, .. }         => *size,
+            CurveBump::BucketedForward { size, .. } => *size,
+        }
+    }
+
+    fn validate(&self) -> Result<(), CurveBumpError> {
+        if !self.size().is_finite() {
+            return Err(CurveBumpError::InvalidSize(self.size()));
+        }
+        match self {
+            CurveBump::ParallelZero { .. } => Ok(()),
+            CurveBump::KeyRate { tenors, index, .. } => {
+                if tenors.is_empty()
+                    || tenors.iter().any(|t| !t.is_finite())
+                    || tenors.windows(2).any(|w| w[1] <= w[0])
+                {
+                    return Err(CurveBumpError::InvalidKeyTenors);
+                }
+                if *index >= tenors.len() {
+                    return Err(CurveBumpError::KeyIndexOutOfRange {
+                        index: *index,
+                        len:   tenors.len(),
+                    });
+                }
+                Ok(())
+            }
+            CurveBump::BucketedForward { start, end, .. } => {
+                if !(start.is_finite() && end.is_finite()) || *start < 0.0 || end <= start {
+                    return Err(CurveBumpError::InvalidBucket { start: *start, end: *end });
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// KeyRate 三角形權重 w(t) 與其斜率 w'(t)。
+    fn key_rate_weight(tenors: &[f64], index: usize, t: f64) -> (f64, f64) {
+        let key = tenors[index];
+        if t <= key {
+            match index.checked_sub(1).map(|i| tenors[i]) {
+                None => (1.0, 0.0),
+                Some(left) if t <= left => (0.0, 0.0),
+                Some(left) => ((t - left) / (key - left), 1.0 / (key - left)),
+            }
+        } else {
+            match tenors.get(index + 1) {
+                None => (1.0, 0.0),
+                Some(&right) if t >= right => (0.0, 0.0),
+                Some(&right) => ((right - t) / (right - key), -1.0 / (right - key)),
+            }
+        }
+    }
+
+    /// I(t) = ∫₀ᵗ Δf(s) ds
+    fn integrated_shift(&self, t: f64) -> f64 {
+        match self {
+            CurveBump::ParallelZero { size } => size * t,
+            CurveBump::KeyRate { tenors, index, size } => {
+                size * Self::key_rate_weight(tenors, *index, t).0 * t
+            }
+            CurveBump::BucketedForward { start, end, size } => {
+                size * (t.clamp(*start, *end) - start)
+            }
+        }
+    }
+
+    /// Δf(t)
+    fn forward_shift(&self, t: f64) -> f64 {
+        match self {
+            CurveBump::ParallelZero { size } => *size,
+            CurveBump::KeyRate { tenors, index, size } => {
+                let (w, dw) = Self::key_rate_weight(tenors, *index, t);
+                size * (w + t * dw)
+            }
+            CurveBump::BucketedForward { start, end, size } => {
+                if t >= *start && t < *end { *size } else { 0.0 }
+            }
+        }
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Sub-curve implementations
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct BumpedDiscountCurve {
+    yfc:  YearFractionCalculator,
+    base: Arc<dyn DiscountCurve>,
+    bump: CurveBump,
+}
+
+impl DiscountCurve for BumpedDiscountCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn discount(&self, d: NaiveDate) -> f64 {
+        let t = self.yfc.year_fraction(d);
+        self.base.discount(d) * (-self.bump.integrated_shift(t)).exp()
+    }
+}
+
+
+struct BumpedZeroRateCurve {
+    yfc:  YearFractionCalculator,
+    base: Arc<dyn ZeroRateCurve>,
+    bump: CurveBump,
+}
+
+impl ZeroRateCurve for BumpedZeroRateCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn zero_rate(&self, d: NaiveDate) -> f64 {
+        let t = self.yfc.year_fraction(d);
+        let shift = if t.abs() < ZERO_TIME_EPSILON {
+            self.bump.forward_shift(0.0)
+        } else {
+            self.bump.integrated_shift(t) / t
+        };
+        self.base.zero_rate(d) + shift
+    }
+}
+
+
+struct BumpedInstForwardCurve {
+    yfc:  YearFractionCalculator,
+    base: Arc<dyn InstForwardCurve>,
+    bump: CurveBump,
+}
+
+impl InstForwardCurve for BumpedInstForwardCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn inst_forward(&self, d: NaiveDate) -> f64 {
+        let t = self.yfc.year_fraction(d);
+        self.base.inst_forward(d) + self.bump.forward_shift(t)
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// BumpedInterestRateCurve
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct BumpedInterestRateCurve {
+    yfc:  YearFractionCalculator,
+    base: Arc<dyn InterestRateCurve>,
+    bump: CurveBump,
+}
+
+impl BumpedInterestRateCurve {
+    /// # Errors
+    ///
+    /// bump 定義不合法（key tenor 非遞增、index 越界、空 bucket、size 非有限值）。
+    pub fn new(base: Arc<dyn InterestRateCurve>, bump: CurveBump) -> Result<Self, CurveBumpError> {
+        bump.validate()?;
+        Ok(Self {
+            yfc: base.year_fraction_calculator().clone(),
+            base,
+            bump,
+        })
+    }
+
+    pub fn base_curve(&self) -> &Arc<dyn InterestRateCurve> { &self.base }
+    pub fn bump(&self) -> &CurveBump { &self.bump }
+}
+
+impl InterestRateCurve for BumpedInterestRateCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn to_discount_curve(&self) -> Arc<dyn DiscountCurve> {
+        Arc::new(BumpedDiscountCurve {
+            yfc:  self.yfc.clone(),
+            base: self.base.to_discount_curve(),
+            bump: self.bump.clone(),
+        })
+    }
+
+    fn to_zero_rate_curve(&self) -> Arc<dyn ZeroRateCurve> {
+        Arc::new(BumpedZeroRateCurve {
+            yfc:  self.yfc.clone(),
+            base: self.base.to_zero_rate_curve(),
+            bump: self.bump.clone(),
+        })
+    }
+
+    fn to_inst_forward_curve(&self) -> Arc<dyn InstForwardCurve> {
+        Arc::new(BumpedInstForwardCurve {
+            yfc:  self.yfc.clone(),
+            base: self.base.to_inst_forward_curve(),
+            bump: self.bump.clone(),
+        })
+    }
+}
+
+
+/// 回傳套用 `bump` 後的新曲線（新的 Arc identity）。
+pub fn bump_curve(
+    curve: &Arc<dyn InterestRateCurve>,
+    bump:  CurveBump,
+) -> Result<Arc<dyn InterestRateCurve>, CurveBumpError> {
+    Ok(Arc::new(BumpedInterestRateCurve::new(curve.clone(), bump)?))
+}
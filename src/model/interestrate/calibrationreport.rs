@@ -0,0 +1,310 @@
+// ── calibrationreport.rs ──────────────────────────────────────────────────────
+//
+// 校準後的重新定價報表（repricing report）。
+//
+// # 內容
+//
+// 每個校準商品一列：
+//   - market_rate：報價經 generator `market_rate()` 轉換後的等效利率
+//   - model_rate ：在校準曲線下使 NPV = 0 的報價，再經同一轉換
+//   - residual_bp：(model_rate − market_rate) × 10⁴
+//   - objective_evaluations：該 pillar 的 root solver 目標函數求值次數
+//     （只有逐點求解的校準器會提供；其餘校準器沒有逐點求解，為 None 表示不適用）。
+//     RootSolver 不回報迭代次數，因此記錄求值次數：每次迭代至少求值一次，
+//     另含初值、bracket 搜尋與 QuasiNewton 失敗後 fallback 的求值，故不小於迭代次數
+//
+// 整體另記錄校準耗時（秒）、容忍度與是否全部通過。
+// 報表以 serde 序列化為 JSON，供日終批次封存；
+// `ensure_within_tolerance()` 在任一商品超出容忍度時回傳錯誤。
+//
+// # model_rate 的求法
+//
+// 報表不接觸 generator：校準前以 `generate_repricing_helpers` 依 pillars 產生兩組商品，
+// 市場報價 q 與位移後的 q + Δq（Δq = QUOTE_BUMP），之後只在校準曲線下重新定價。
+// 所有報價型別的 NPV 對 q 皆為線性，故
+//
+//   s = −NPV(q) / (NPV(q + Δq) − NPV(q))
+//   model_rate = market_rate(q) + s · (market_rate(q + Δq) − market_rate(q))
+//
+// 不需要每種商品各自實作 par rate。報價→利率的轉換若非線性（債券殖利率），
+// model_rate 為一階近似，殘差在 bp 等級以下時誤差可忽略。
+// NPV 對報價不敏感或無法定價時 model_rate 為 None，並視為超出容忍度。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::instrument::instrument::SimpleInstrument;
+use crate::marketdata::interestrate::interestratequotesheet::InterestRateQuoteSheetError;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::model::interestrate::interestratecurvecalibrator::{
+    CalibrationEnvironment,
+    CalibrationError,
+    InterestRateCurveCalibrationHelper,
+    InterestRateCurvePillar,
+    calibration_pricing_condition,
+    resolve_quote_key,
+};
+use crate::pricer::pricer::Pricer;
+use crate::pricer::simpleinstrumentpricer::SimpleInstrumentPricer;
+use crate::pricingcondition::PricingCondition;
+
+
+/// 求 model_rate 用的報價位移 Δq。
+const QUOTE_BUMP: f64 = 1e-4;
+
+/// NPV 對報價的變動相對於 NPV 本身小於此值時，視為對報價不敏感。
+const MIN_RELATIVE_NPV_CHANGE: f64 = 1e-12;
+
+/// 1bp。
+const BASIS_POINT: f64 = 1e-4;
+
+
+/// 商品 → 定價用 market data（curve name → curve）的對應方式。
+pub type RepricingMarketData<'a> =
+    dyn Fn(&dyn SimpleInstrument, &Arc<dyn InterestRateCurve>) -> HashMap<String, Arc<dyn InterestRateCurve>> + 'a;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// RepricingHelper
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 單一 pillar 的報表用商品：市場報價下與報價位移 Δq 後各一個 helper。
+pub struct RepricingHelper {
+    quote_sheet:  String,
+    quote_key:    String,
+    market_quote: f64,
+    market:       InterestRateCurveCalibrationHelper,
+    bumped:       InterestRateCurveCalibrationHelper,
+}
+
+impl RepricingHelper {
+    pub fn quote_sheet(&self) -> &str { &self.quote_sheet }
+    pub fn quote_key(&self) -> &str { &self.quote_key }
+    pub fn market_quote(&self) -> f64 { self.market_quote }
+    pub fn market(&self) -> &InterestRateCurveCalibrationHelper { &self.market }
+    pub fn bumped(&self) -> &InterestRateCurveCalibrationHelper { &self.bumped }
+}
+
+/// 依 pillars 產生報表用商品，順序與 `pillars` 一致。
+///
+/// 與 `generate_calibration_helpers` 相同，透過 quote sheet 設定 generator 後產生商品，
+/// 屬於校準流程的一部分，須在 `calibrate` 之前呼叫；每個 pillar 最後以市場報價產生，
+/// 結束時 generator 的報價設定與單純校準後相同。
+pub fn generate_repricing_helpers(
+    pillars:     &[InterestRateCurvePillar],
+    environment: &CalibrationEnvironment,
+) -> Result<Vec<RepricingHelper>, CalibrationError> {
+    pillars
+        .iter()
+        .map(|pillar| {
+            let sheet_name = pillar.quote_generator_name();
+            let sheet = environment.quote_book
+                .get(sheet_name)
+                .ok_or_else(|| CalibrationError::SheetNotFound(sheet_name.clone()))?;
+            let key = resolve_quote_key(pillar, sheet)?;
+            let market_quote = *sheet
+                .get_quote(&key)
+                .ok_or_else(|| InterestRateQuoteSheetError::MaturityNotFound(key.clone()))?;
+
+            let bumped = sheet.generate_calibration_helper_with_quote(
+                &key,
+                market_quote + QUOTE_BUMP,
+                environment.position,
+                environment.horizon,
+                environment.generator_collection,
+            )?;
+            let market = sheet.generate_calibration_helper_with_quote(
+                &key,
+                market_quote,
+                environment.position,
+                environment.horizon,
+                environment.generator_collection,
+            )?;
+
+            Ok(RepricingHelper {
+                quote_sheet: sheet_name.clone(),
+                quote_key: key,
+                market_quote,
+                market,
+                bumped,
+            })
+        })
+        .collect()
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// HelperReport / CalibrationReport
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HelperReport {
+    pub quote_sheet:           String,
+    pub quote_key:             String,
+    pub pillar_date:           NaiveDate,
+    pub market_quote:          f64,
+    pub market_rate:           f64,
+    pub model_rate:            Option<f64>,
+    pub residual_bp:           Option<f64>,
+    /// 該 pillar 的 root solver 目標函數求值次數（不是迭代次數，見檔頭說明）。
+    /// 只有逐點求解的校準器（IterativeBootstrapper）有此數字，
+    /// 其餘校準器為 None（不適用，JSON 中為 null）。
+    pub objective_evaluations: Option<usize>,
+    pub within_tolerance:      bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CalibrationReport {
+    pub calibrator:           String,
+    pub reference_date:       NaiveDate,
+    pub elapsed_seconds:      f64,
+    pub tolerance_bp:         f64,
+    /// 只計入能重新定價的商品。
+    pub max_abs_residual_bp:  f64,
+    pub all_within_tolerance: bool,
+    /// 順序與校準輸入的 pillars 一致。
+    pub helpers:              Vec<HelperReport>,
+}
+
+impl CalibrationReport {
+    /// 超出容忍度（或無法重新定價）的商品。
+    pub fn failed_helpers(&self) -> impl Iterator<Item = &HelperReport> {
+        self.helpers.iter().filter(|h| !h.within_tolerance)
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// # Errors
+    ///
+    /// 任一商品超出容忍度時回傳 `CalibrationError::OutOfTolerance`。
+    pub fn ensure_within_tolerance(&self) -> Result<(), CalibrationError> {
+        if self.all_within_tolerance {
+            return Ok(());
+        }
+        Err(CalibrationError::OutOfTolerance {
+            failed:              self.failed_helpers().count(),
+            max_abs_residual_bp: self.max_abs_residual_bp,
+            tolerance_bp:        self.tolerance_bp,
+        })
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CalibrationReporter
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct CalibrationReporter {
+    horizon:      NaiveDate,
+    tolerance_bp: f64,
+}
+
+impl CalibrationReporter {
+    pub fn new(horizon: NaiveDate, tolerance_bp: f64) -> Self {
+        Self { horizon, tolerance_bp }
+    }
+
+    /// 以校準出的曲線重新定價 `generate_repricing_helpers` 產生的商品並組成報表。
+    ///
+    /// 只對既有商品定價，不會重新產生商品或變更 generator 的設定。
+    ///
+    /// - `objective_evaluations`：若提供，順序須與 `helpers` 一致；
+    ///   非逐點求解的校準器傳入 None，報表中各商品的求值次數即為 None（不適用）
+    /// - `market_data`：商品的 curve name 對應方式，通常為校準器的
+    ///   `repricing_market_data`
+    pub fn report(
+        &self,
+        calibrator:            &str,
+        curve:                 &Arc<dyn InterestRateCurve>,
+        helpers:               &[RepricingHelper],
+        elapsed:               Duration,
+        objective_evaluations: Option<&[usize]>,
+        market_data:           &RepricingMarketData<'_>,
+    ) -> CalibrationReport {
+        let pricing_condition = calibration_pricing_condition(self.horizon);
+        let helpers: Vec<HelperReport> = helpers
+            .iter()
+            .enumerate()
+            .map(|(i, helper)| {
+                let evaluations = objective_evaluations.and_then(|e| e.get(i).copied());
+                self.helper_report(helper, curve, evaluations, market_data, &pricing_condition)
+            })
+            .collect();
+
+        let max_abs_residual_bp = helpers
+            .iter()
+            .filter_map(|h| h.residual_bp)
+            .fold(0.0, |acc: f64, r| acc.max(r.abs()));
+
+        CalibrationReport {
+            calibrator:           calibrator.to_string(),
+            reference_date:       curve.reference_date(),
+            elapsed_seconds:      elapsed.as_secs_f64(),
+            tolerance_bp:         self.tolerance_bp,
+            max_abs_residual_bp,
+            all_within_tolerance: helpers.iter().all(|h| h.within_tolerance),
+            helpers,
+        }
+    }
+
+    fn helper_report(
+        &self,
+        helper:                &RepricingHelper,
+        curve:                 &Arc<dyn InterestRateCurve>,
+        objective_evaluations: Option<usize>,
+        market_data:           &RepricingMarketData<'_>,
+        pricing_condition:     &PricingCondition,
+    ) -> HelperReport {
+        let npv = |instrument: &Arc<dyn SimpleInstrument>| {
+            let data = market_data(instrument.as_ref(), curve);
+            SimpleInstrumentPricer
+                .market_value(instrument.as_ref(), &data, pricing_condition)
+                .map(|npv| npv.amount())
+        };
+        let market_rate = helper.market.market_rate();
+        let bumped_rate = helper.bumped.market_rate();
+
+        // NPV 對報價線性：NPV(q + s·Δq) = 0
+        let model_rate = npv(helper.market.instrument())
+            .zip(npv(helper.bumped.instrument()))
+            .and_then(|(npv_market, npv_bumped)| {
+                let change = npv_bumped - npv_market;
+                if change.is_nan()
+                    || change.abs() <= MIN_RELATIVE_NPV_CHANGE * npv_market.abs().max(npv_bumped.abs())
+                {
+                    return None;
+                }
+                let step = -npv_market / change;
+                Some(market_rate + step * (bumped_rate - market_rate))
+            })
+            .filter(|rate| rate.is_finite());
+
+        let residual_bp = model_rate.map(|rate| (rate - market_rate) / BASIS_POINT);
+        let within_tolerance = residual_bp.is_some_and(|r| r.abs() <= self.tolerance_bp);
+
+        HelperReport {
+            quote_sheet: helper.quote_sheet.clone(),
+            quote_key: helper.quote_key.clone(),
+            pillar_date: helper.market.instrument().max_date(),
+            market_quote: helper.market_quote,
+            market_rate,
+            model_rate,
+            residual_bp,
+            objective_evaluations,
+            within_tolerance,
+        }
+    }
+}
+
+
+/// 報表用的校準器名稱（型別名稱的最後一段）。
+pub fn calibrator_name<T: ?Sized>() -> &'static str {
+    let full = std::any::type_name::<T>();
+    full.rsplit("::").next().unwrap_or(full)
+}
@@ -0,0 +1,108 @@
+// ── crosscurrencybasiscurvecalibrator.rs ──────────────────────────────────────
+//
+// 以 FX forward points 與 xccy basis swap 校準擔保折現曲線
+// （例如 USD collateral 的 TWD / JPY 折現曲線）。
+//
+// # 輸入
+//
+//   - FX market：決定被校準曲線與擔保幣別曲線（domestic / foreign discount curve name 其一）
+//   - quote book：
+//       FxForwardPoints   sheet（spot 在 generator type 上）→ FxImpliedDeposit
+//       CrossCurrencySwap sheet（basis spread）              → CrossCurrencyBasisSwap
+//   - MarketDataSet 中已存在的固定曲線：擔保幣別 OIS 曲線、被校準幣別 leg 的 projection curve
+//
+// # 流程
+//
+//   1. 確認 spec 的曲線名稱為 FX market 的其中一條折現曲線，另一條（擔保幣別）已存在
+//   2. 交給 MultiCurveCalibrator：商品引用的其他曲線皆視為固定曲線，
+//      只求解被校準曲線的節點（短天期由 forward points 決定，長天期由 basis swap 決定）
+//   3. 校準結果寫回 MarketDataSet 並回傳
+//
+// 被校準曲線若也被商品當作 projection curve 引用（兩者同名），會一併參與求解。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::configuration::InterestRateInstrumentGeneratorCollection;
+use crate::instrument::instrument::Position;
+use crate::market::fxmarket::FxMatket;
+use crate::marketdata::marketdataset::MarketDataSet;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::model::interestrate::interestratecurvecalibrator::CalibrationError;
+use crate::model::interestrate::leastsquarecalibrator::LeastSquareCalibratorConfig;
+use crate::model::interestrate::multicurvecalibrator::{CurveCalibrationSpec, MultiCurveCalibrator};
+
+
+pub struct CrossCurrencyBasisCurveCalibrator {
+    calibrator: MultiCurveCalibrator,
+}
+
+impl CrossCurrencyBasisCurveCalibrator {
+    pub fn new(config: LeastSquareCalibratorConfig) -> Self {
+        Self { calibrator: MultiCurveCalibrator::new(config) }
+    }
+
+    pub fn with_defaults() -> Self {
+        Self { calibrator: MultiCurveCalibrator::with_defaults() }
+    }
+
+    /// 擔保幣別的折現曲線名稱：FX market 中 `curve_name` 以外的另一條。
+    ///
+    /// # Errors
+    ///
+    /// `curve_name` 不是 FX market 的折現曲線。
+    pub fn collateral_curve_name<'a>(
+        fx_market:  &'a FxMatket,
+        curve_name: &str,
+    ) -> Result<&'a String, CalibrationError> {
+        if curve_name == fx_market.domestic_discount_curve_name() {
+            Ok(fx_market.foreign_discount_curve_name())
+        } else if curve_name == fx_market.foreign_discount_curve_name() {
+            Ok(fx_market.domestic_discount_curve_name())
+        } else {
+            Err(CalibrationError::CurveGeneration(format!(
+                "curve '{}' is not a discount curve of FX market {}",
+                curve_name,
+                fx_market.currency_pair().code(),
+            )))
+        }
+    }
+
+    /// 校準 `spec` 描述的擔保折現曲線，寫入 `market_data_set` 並回傳。
+    ///
+    /// # Errors
+    ///
+    /// - `spec` 的曲線不是 FX market 的折現曲線
+    /// - 擔保幣別曲線或商品引用的其他曲線不在 `market_data_set` 中
+    /// - 求解失敗
+    pub fn calibrate(
+        &self,
+        fx_market:            &FxMatket,
+        spec:                 CurveCalibrationSpec,
+        market_data_set:      &mut MarketDataSet,
+        generator_collection: &InterestRateInstrumentGeneratorCollection,
+        reference_date:       NaiveDate,
+        horizon:              NaiveDate,
+    ) -> Result<Arc<dyn InterestRateCurve>, CalibrationError> {
+        let curve_name = spec.curve_name().to_string();
+        let collateral_curve_name = Self::collateral_curve_name(fx_market, &curve_name)?;
+        if market_data_set.get_curve(collateral_curve_name).is_none() {
+            return Err(CalibrationError::MissingCurve(collateral_curve_name.clone()));
+        }
+
+        self.calibrator.calibrate(
+            vec![spec],
+            market_data_set,
+            generator_collection,
+            reference_date,
+            Position::Buy,
+            horizon,
+        )?;
+
+        market_data_set
+            .get_curve(&curve_name)
+            .cloned()
+            .ok_or(CalibrationError::MissingCurve(curve_name))
+    }
+}
@@ -20,6 +20,9 @@ pub enum CurveGenerationError {
         provided: usize,
     },
 
+    #[error("invalid curve parameters: {0}")]
+    InvalidParameters(String),
+
     #[error("day counter generation failed: {0}")]
     DayCounterGeneration(String),
 }
\ No newline at end of file
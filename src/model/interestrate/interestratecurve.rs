@@ -5,6 +5,10 @@ use chrono::NaiveDate;
 use crate::time::daycounter::daycounter::DayCounter;
 
 
+/// year fraction 的絕對值小於此值時視為 reference date 當日（t = 0）。
+pub(crate) const ZERO_TIME_EPSILON: f64 = 1e-12;
+
+
 // ─────────────────────────────────────────────────────────────────────────────
 // YearFractionCalculator
 // ─────────────────────────────────────────────────────────────────────────────
@@ -125,4 +129,18 @@ pub trait InterestRateCurveGenerator: Send + Sync {
         let _ = dates; // 預設忽略 dates
         self.generate(reference_date, values)
     }
+
+    /// 是否能以單一 pillar 建構曲線。
+    /// 為 true 時 IterativeBootstrapper 直接以本 generator 求解第一個 pillar，
+    /// 不經過 FlatForwardCurve（例如帶有固定跳躍項的階梯遠期曲線）。
+    fn builds_from_single_pillar(&self) -> bool {
+        false
+    }
+
+    /// 第 i 個 pillar 的值是否只影響前一個 pillar 之後的曲線。
+    /// 為 false 時 IterativeBootstrapper 在逐點求解後聯合修正，
+    /// 確保前段商品在最終曲線下仍重新定價（例如 knot 可能早於前一個 pillar 的階梯遠期曲線）。
+    fn is_pillar_local(&self) -> bool {
+        true
+    }
 }
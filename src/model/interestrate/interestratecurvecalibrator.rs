@@ -1,11 +1,18 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use chrono::NaiveDate;
 use thiserror::Error;
 
 use crate::configuration::InterestRateInstrumentGeneratorCollection;
 use crate::instrument::instrument::{Position, SimpleInstrument};
+use crate::model::interestrate::calibrationreport::{
+    CalibrationReport,
+    CalibrationReporter,
+    calibrator_name,
+    generate_repricing_helpers,
+};
 use crate::marketdata::interestrate::interestratequotesheet::{
     InterestRateQuoteSheet,
     InterestRateQuoteSheetError,
@@ -13,7 +20,10 @@ use crate::marketdata::interestrate::interestratequotesheet::{
 use crate::model::interestrate::interestratecurve::{
     InterestRateCurve,
     InterestRateCurveGenerator,
+    YearFractionCalculator,
 };
+use crate::pricingcondition::{DecimalRounding, PricingCondition};
+use crate::time::daycounter::daycounter::DayCounterGenerator;
 
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -37,6 +47,28 @@ pub enum CalibrationError {
 
     #[error("curve generation failed: {0}")]
     CurveGeneration(String),
+
+    #[error("curve '{0}' is referenced by a calibration instrument but is neither calibrated nor in market data")]
+    MissingCurve(String),
+
+    #[error("sensitivity dimension mismatch: expected {expected}, got {provided}")]
+    SensitivityDimension {
+        expected: usize,
+        provided: usize,
+    },
+
+    #[error("singular calibration jacobian: {0}")]
+    SingularJacobian(String),
+
+    #[error("calibration did not converge: {0}")]
+    NotConverged(String),
+
+    #[error("{failed} calibration helper(s) outside tolerance {tolerance_bp}bp (max |residual| {max_abs_residual_bp}bp)")]
+    OutOfTolerance {
+        failed:              usize,
+        max_abs_residual_bp: f64,
+        tolerance_bp:        f64,
+    },
 }
 
 
@@ -51,18 +83,30 @@ pub enum MaturityKey {
     NthQuote(usize),
 }
 
+#[derive(Clone)]
 pub struct InterestRateCurvePillar {
     maturity_key:         MaturityKey,
     quote_generator_name: String,
+    weight:               f64,
 }
 
 impl InterestRateCurvePillar {
     pub fn new(maturity_key: MaturityKey, quote_generator_name: String) -> Self {
-        Self { maturity_key, quote_generator_name }
+        Self { maturity_key, quote_generator_name, weight: 1.0 }
+    }
+
+    /// 設定校準權重（預設 1.0）。
+    ///
+    /// 只有允許殘差的校準器（如 `LeastSquareCalibrator`）會使用此值，
+    /// `IterativeBootstrapper` 為精確擬合，忽略權重。
+    pub fn with_weight(mut self, weight: f64) -> Self {
+        self.weight = weight;
+        self
     }
 
     pub fn maturity_key(&self) -> &MaturityKey { &self.maturity_key }
     pub fn quote_generator_name(&self) -> &String { &self.quote_generator_name }
+    pub fn weight(&self) -> f64 { self.weight }
 }
 
 
@@ -92,6 +136,111 @@ impl<T> InterestRateCurveCalibrationHelper<T> {
 }
 
 
+// ─────────────────────────────────────────────────────────────────────────────
+// CalibrationEnvironment
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 校準時共用的市場與商品環境，對應 `calibrate` 的後四個參數。
+///
+/// 供 `calibrate_with_jacobian` 等延伸介面使用，避免參數列過長。
+pub struct CalibrationEnvironment<'a> {
+    pub quote_book:           &'a HashMap<String, InterestRateQuoteSheet>,
+    pub generator_collection: &'a InterestRateInstrumentGeneratorCollection,
+    pub position:             Position,
+    pub horizon:              NaiveDate,
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// generate_calibration_helpers
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 將 pillar 的 MaturityKey 解析為 quote sheet 中實際的 key。
+///
+/// `NthQuote(n)` 依 key 字典序取第 n 個。
+pub fn resolve_quote_key(
+    pillar: &InterestRateCurvePillar,
+    sheet:  &InterestRateQuoteSheet,
+) -> Result<String, CalibrationError> {
+    match pillar.maturity_key() {
+        MaturityKey::Tenor(s) | MaturityKey::Date(s) => Ok(s.clone()),
+        MaturityKey::NthQuote(n) => {
+            let mut keys: Vec<&String> = sheet.keys().collect();
+            keys.sort();
+            keys.get(*n)
+                .map(|k| k.to_string())
+                .ok_or_else(|| CalibrationError::NthQuoteOutOfRange {
+                    index: *n,
+                    sheet: pillar.quote_generator_name().clone(),
+                    len:   keys.len(),
+                })
+        }
+    }
+}
+
+/// 依 pillars 從 quote book 產生校準商品，順序與 `pillars` 一致。
+///
+/// `InterestRateCurveCalibrator::generate_calibration_set` 的預設實作委派至此；
+/// 不實作該 trait 的校準驅動（如 MultiCurveCalibrator）亦可直接使用。
+pub fn generate_calibration_helpers(
+    pillars:              &[InterestRateCurvePillar],
+    quote_book:           &HashMap<String, InterestRateQuoteSheet>,
+    generator_collection: &InterestRateInstrumentGeneratorCollection,
+    position:             Position,
+    horizon:              NaiveDate,
+) -> Result<Vec<InterestRateCurveCalibrationHelper>, CalibrationError> {
+    pillars
+        .iter()
+        .map(|pillar| {
+            let sheet = quote_book
+                .get(pillar.quote_generator_name())
+                .ok_or_else(|| {
+                    CalibrationError::SheetNotFound(
+                        pillar.quote_generator_name().clone()
+                    )
+                })?;
+
+            let key = resolve_quote_key(pillar, sheet)?;
+
+            sheet
+                .generate_calibration_helper(&key, position, horizon, generator_collection)
+                .map_err(CalibrationError::from)
+        })
+        .collect()
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// 校準共用設定
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 為校準建構 PricingCondition。
+///
+/// horizon = reference_date，保證 DF(horizon) = 1 的不變式。
+/// 校準期間不做 rounding（避免影響數值精度）。
+pub(crate) fn calibration_pricing_condition(horizon: NaiveDate) -> PricingCondition {
+    PricingCondition::new(
+        horizon,
+        true,   // include_horizon_flow
+        true,   // estimate_horizon_index
+        DecimalRounding::new(false, false, false),
+    )
+}
+
+/// 以曲線的 day counter 建立自 `reference_date` 起算的 YearFractionCalculator。
+pub(crate) fn make_yfc(
+    day_counter_generator: &DayCounterGenerator,
+    reference_date:        NaiveDate,
+) -> Result<YearFractionCalculator, CalibrationError> {
+    let day_counter = day_counter_generator
+        .generate(None)
+        .map_err(|e| CalibrationError::CurveGeneration(
+            format!("day counter generation failed: {}", e)
+        ))?;
+    Ok(YearFractionCalculator::new(reference_date, Arc::new(day_counter)))
+}
+
+
 // ─────────────────────────────────────────────────────────────────────────────
 // InterestRateCurveCalibrator
 // ─────────────────────────────────────────────────────────────────────────────
@@ -104,37 +253,13 @@ pub trait InterestRateCurveCalibrator {
         position:             Position,
         horizon:              NaiveDate,
     ) -> Result<Vec<InterestRateCurveCalibrationHelper>, CalibrationError> {
-        pillars
-            .iter()
-            .map(|pillar| {
-                let sheet = quote_book
-                    .get(pillar.quote_generator_name())
-                    .ok_or_else(|| {
-                        CalibrationError::SheetNotFound(
-                            pillar.quote_generator_name().clone()
-                        )
-                    })?;
-
-                let key: String = match pillar.maturity_key() {
-                    MaturityKey::Tenor(s) | MaturityKey::Date(s) => s.clone(),
-                    MaturityKey::NthQuote(n) => {
-                        let mut keys: Vec<&String> = sheet.keys().collect();
-                        keys.sort();
-                        keys.get(*n)
-                            .map(|k| k.to_string())
-                            .ok_or_else(|| CalibrationError::NthQuoteOutOfRange {
-                                index: *n,
-                                sheet: pillar.quote_generator_name().clone(),
-                                len:   keys.len(),
-                            })?
-                    }
-                };
-
-                sheet
-                    .generate_calibration_helper(&key, position, horizon, generator_collection)
-                    .map_err(CalibrationError::from)
-            })
-            .collect()
+        generate_calibration_helpers(
+            pillars,
+            quote_book,
+            generator_collection,
+            position,
+            horizon,
+        )
     }
 
     fn calibrate(
@@ -147,4 +272,56 @@ pub trait InterestRateCurveCalibrator {
         position:             Position,
         horizon:              NaiveDate,
     ) -> Result<Arc<dyn InterestRateCurve>, CalibrationError>;
+
+    /// 重新定價報表時，商品 curve name → 曲線的對應。
+    /// 預設為單曲線假設：全部指向校準出的曲線。
+    fn repricing_market_data(
+        &self,
+        instrument: &dyn SimpleInstrument,
+        curve:      &Arc<dyn InterestRateCurve>,
+    ) -> HashMap<String, Arc<dyn InterestRateCurve>> {
+        instrument
+            .curve_name_map()
+            .values()
+            .map(|name| (name.clone(), curve.clone()))
+            .collect()
+    }
+
+    /// 校準並產生重新定價報表（見 `calibrationreport.rs`）。
+    ///
+    /// 報表用商品在校準前產生，報表本身只對其定價。
+    /// 預設實作沒有逐點的 root solver，各商品的 `objective_evaluations` 為 None（不適用）；
+    /// 逐點求解的校準器可覆寫以填入求值次數。
+    fn calibrate_with_report(
+        &self,
+        curve_generator: Arc<dyn InterestRateCurveGenerator>,
+        reference_date:  NaiveDate,
+        pillars:         Vec<InterestRateCurvePillar>,
+        environment:     &CalibrationEnvironment,
+        tolerance_bp:    f64,
+    ) -> Result<(Arc<dyn InterestRateCurve>, CalibrationReport), CalibrationError> {
+        let helpers = generate_repricing_helpers(&pillars, environment)?;
+
+        let start = Instant::now();
+        let curve = self.calibrate(
+            curve_generator,
+            reference_date,
+            pillars,
+            environment.quote_book,
+            environment.generator_collection,
+            environment.position,
+            environment.horizon,
+        )?;
+        let elapsed = start.elapsed();
+
+        let report = CalibrationReporter::new(environment.horizon, tolerance_bp).report(
+            calibrator_name::<Self>(),
+            &curve,
+            &helpers,
+            elapsed,
+            None,
+            &|instrument, curve| self.repricing_market_data(instrument, curve),
+        );
+        Ok((curve, report))
+    }
 }
@@ -17,6 +17,21 @@
 //
 // 左外插強制為 FlatForwardRate，保證切換時曲線的連續性。
 //
+// 若 generator 的 `builds_from_single_pillar()` 為 true（如 MeetingDateCurveGenerator），
+// 第一個 pillar 直接以 generator 求解，曲線上的固定跳躍項從一開始就生效。
+// 這類 generator 以 `from_parts` 建構 bootstrapper。
+//
+// # 聯合修正
+//
+// 逐點求解時假設後段 pillar 不影響前段商品。generator 的 `is_pillar_local()`
+// 為 false 時（如 knot 可能早於前一個 pillar 的 MeetingDateCurveGenerator）此假設不成立，
+// 前段商品在最終曲線下不再精確定價。
+//
+// 這類 generator 在逐點求解後以完整曲線重新定價所有商品；任一 |NPV| ≥ REPRICING_TOLERANCE 時，
+// 以 Gauss-Seidel 掃描聯合修正：其餘值固定、依序重解每個 pillar，
+// 直到全部商品重新定價，超過 MAX_JOINT_SWEEPS 輪仍未通過則回傳錯誤。
+// pillar-local 的 generator（預設）不做檢查與修正，行為與成本不變。
+//
 // # 初值與 Bracket 策略
 //
 // 初值根據 InterpolationTarget 從 market_rate 推導：
@@ -38,10 +53,13 @@
 // 解最後一個 pillar 時只需重算最後 1 期，
 // 省略前 39 期的 CompoundingRateIndex 逐日計算。
 
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use chrono::NaiveDate;
+use nalgebra::DVector;
 
 use crate::configuration::InterestRateInstrumentGeneratorCollection;
 use crate::instrument::instrument::{
@@ -52,6 +70,12 @@ use crate::instrument::instrument::{
 use crate::marketdata::interestrate::interestratequotesheet::InterestRateQuoteSheet;
 use crate::math::rootsolver::{RootSolver, RootSolverConfig};
 use crate::model::interestrate::bootstrappingtrait::BootstrappingTrait;
+use crate::model::interestrate::calibrationreport::{
+    CalibrationReport,
+    CalibrationReporter,
+    calibrator_name,
+    generate_repricing_helpers,
+};
 use crate::model::interestrate::flatforwardcurve::FlatForwardCurve;
 use crate::model::interestrate::interestratecurve::{
     InterestRateCurve,
@@ -59,21 +83,49 @@ use crate::model::interestrate::interestratecurve::{
     YearFractionCalculator,
 };
 use crate::model::interestrate::interestratecurvecalibrator::{
+    CalibrationEnvironment,
     CalibrationError,
     InterestRateCurveCalibrationHelper,
     InterestRateCurvePillar,
     InterestRateCurveCalibrator,
+    calibration_pricing_condition,
+    make_yfc,
 };
 use crate::model::interestrate::piecewisepolyinterestratecurve::{
     ExtrapolationMethod,
+    InterpolationTarget,
     PiecewisePolyInterestRateCurveGenerator,
 };
+use crate::model::interestrate::quotejacobian::{
+    QuoteJacobian,
+    QuoteJacobianProblem,
+    quote_sources,
+};
 use crate::pricer::pricer::Pricer;
 use crate::pricer::simpleinstrumentpricer::SimpleInstrumentPricer;
-use crate::pricingcondition::{DecimalRounding, PricingCondition};
+use crate::pricingcondition::PricingCondition;
 use crate::time::daycounter::daycounter::DayCounterGenerator;
 
 
+/// quote Jacobian 中 ∂NPV/∂(pillar value) 的前向差分擾動量。
+const JACOBIAN_FD_STEP: f64 = 1e-6;
+
+/// 聯合修正（Gauss-Seidel 掃描）的最大輪數。
+const MAX_JOINT_SWEEPS: usize = 50;
+
+/// 聯合修正的重新定價檢查：最終曲線下各商品 |NPV|（商品幣別金額）須小於此值。
+/// 與 root solver 的 tolerance 無關，後者是單一 pillar 求解的收斂條件。
+const REPRICING_TOLERANCE: f64 = 1e-8;
+
+
+/// `run` 的結果；evaluations 為各 pillar 的 root solver 求值次數（pillars 輸入順序）。
+struct BootstrapOutcome {
+    curve:       Arc<dyn InterestRateCurve>,
+    jacobian:    Option<QuoteJacobian>,
+    evaluations: Vec<usize>,
+}
+
+
 // ─────────────────────────────────────────────────────────────────────────────
 // IterativeBootstrapper
 // ─────────────────────────────────────────────────────────────────────────────
@@ -111,12 +163,31 @@ impl IterativeBootstrapper {
             ));
         }
 
-        Ok(Self {
+        Ok(Self::from_parts(
             root_solver_config,
-            bootstrapping_trait: BootstrappingTrait::new(generator.interpolation_target()),
-            day_counter_generator: generator.day_counter_generator().clone(),
+            generator.interpolation_target(),
+            generator.day_counter_generator().clone(),
             apply_partial_freeze_cash_flows,
-        })
+        ))
+    }
+
+    /// 直接指定 InterpolationTarget 與 day counter 的建構方式，
+    /// 供非 PiecewisePoly 的 generator（如 MeetingDateCurveGenerator）使用。
+    ///
+    /// 呼叫端須自行保證：generator 能以單一 pillar 建構曲線，
+    /// 或其左側外插與 FlatForwardCurve 一致。
+    pub fn from_parts(
+        root_solver_config:              RootSolverConfig,
+        interpolation_target:            InterpolationTarget,
+        day_counter_generator:           Arc<DayCounterGenerator>,
+        apply_partial_freeze_cash_flows: bool,
+    ) -> Self {
+        Self {
+            root_solver_config,
+            bootstrapping_trait: BootstrappingTrait::new(interpolation_target),
+            day_counter_generator,
+            apply_partial_freeze_cash_flows,
+        }
     }
 
     /// 預設不啟用 partial freeze 的建構方式。
@@ -126,19 +197,6 @@ impl IterativeBootstrapper {
         Self::new(RootSolverConfig::default(), generator, false)
     }
 
-    /// 為校準建構 PricingCondition。
-    ///
-    /// horizon = reference_date，保證 DF(horizon) = 1 的不變式。
-    /// 校準期間不做 rounding（避免影響數值精度）。
-    fn calibration_pricing_condition(horizon: NaiveDate) -> PricingCondition {
-        PricingCondition::new(
-            horizon,
-            true,   // include_horizon_flow
-            true,   // estimate_horizon_index
-            DecimalRounding::new(false, false, false),
-        )
-    }
-
     /// 從校準商品建構 market_data HashMap。
     ///
     /// 單曲線校準假設：商品 curve_name_map 中所有 curve name
@@ -154,16 +212,6 @@ impl IterativeBootstrapper {
         market_data
     }
 
-    /// 建立 FlatForwardCurve 用的 YearFractionCalculator。
-    fn make_yfc(&self, reference_date: NaiveDate) -> Result<YearFractionCalculator, CalibrationError> {
-        let day_counter = self.day_counter_generator
-            .generate(None)
-            .map_err(|e| CalibrationError::CurveGeneration(
-                format!("day counter generation failed: {}", e)
-            ))?;
-        Ok(YearFractionCalculator::new(reference_date, Arc::new(day_counter)))
-    }
-
     /// 第一個 pillar：使用 FlatForwardCurve 求解。
     ///
     /// 回傳已經過 InterpolationTarget 轉換的值，
@@ -177,12 +225,14 @@ impl IterativeBootstrapper {
         solver:            &RootSolver,
         pricing_condition: &PricingCondition,
         yfc:               &YearFractionCalculator,
+        evaluations:       &Cell<usize>,
     ) -> Result<f64, CalibrationError> {
         // FlatForwardCurve 的求解目標是 zero rate（= inst forward under flat forward）
         let initial_guess = market_rate;
         let (lower, upper) = self.bootstrapping_trait.bracket(initial_guess);
 
         let objective = |rate: f64| -> f64 {
+            evaluations.set(evaluations.get() + 1);
             let curve: Arc<dyn InterestRateCurve> = Arc::new(
                 FlatForwardCurve::new(yfc.clone(), rate)
             );
@@ -207,6 +257,7 @@ impl IterativeBootstrapper {
     }
 
     /// 後續 pillar（i ≥ 1）：使用 PiecewisePoly curve generator 求解。
+    /// 單一 pillar 即可建構曲線的 generator 從 i = 0 起就走此路徑。
     fn solve_subsequent_pillar(
         &self,
         i:                 usize,
@@ -221,6 +272,7 @@ impl IterativeBootstrapper {
         solver:            &RootSolver,
         pricing_condition: &PricingCondition,
         yfc:               &YearFractionCalculator,
+        evaluations:       &Cell<usize>,
     ) -> Result<f64, CalibrationError> {
         let initial_guess = self.bootstrapping_trait.initial_value(
             market_rate, yfc, pillar_date,
@@ -230,6 +282,7 @@ impl IterativeBootstrapper {
         let current_dates = &pillar_dates[..=i];
 
         let objective = |value: f64| -> f64 {
+            evaluations.set(evaluations.get() + 1);
             let mut trial_values = solved_values.to_vec();
             trial_values.push(value);
 
@@ -279,6 +332,7 @@ impl IterativeBootstrapper {
         solver:            &RootSolver,
         pricing_condition: &PricingCondition,
         yfc:               &YearFractionCalculator,
+        evaluations:       &Cell<usize>,
     ) -> Result<f64, CalibrationError> {
         let initial_guess = self.bootstrapping_trait.initial_value(
             market_rate, yfc, pillar_date,
@@ -321,6 +375,7 @@ impl IterativeBootstrapper {
             .settlement_date(horizon);
 
         let objective = |value: f64| -> f64 {
+            evaluations.set(evaluations.get() + 1);
             let mut trial_values = solved_values.to_vec();
             trial_values.push(value);
 
@@ -361,47 +416,65 @@ impl IterativeBootstrapper {
                 format!("pillar {} ({:?}) freeze solve failed: {}", i, pillar_date, e)
             ))
     }
-}
 
+    /// 校準並同時回傳 d(pillar value)/d(quote) Jacobian。
+    ///
+    /// 精確擬合下 dv/dq = −J⁻¹G，見 `quotejacobian.rs`；
+    /// Jacobian 的 column 順序與 `pillars` 一致。
+    pub fn calibrate_with_jacobian(
+        &self,
+        curve_generator: Arc<dyn InterestRateCurveGenerator>,
+        reference_date:  NaiveDate,
+        pillars:         &[InterestRateCurvePillar],
+        environment:     &CalibrationEnvironment,
+    ) -> Result<(Arc<dyn InterestRateCurve>, QuoteJacobian), CalibrationError> {
+        let outcome = self.run(&curve_generator, reference_date, pillars, environment, true)?;
+        let jacobian = outcome.jacobian.ok_or_else(|| CalibrationError::CurveGeneration(
+            "quote jacobian was not computed".to_string()
+        ))?;
+        Ok((outcome.curve, jacobian))
+    }
 
-impl InterestRateCurveCalibrator for IterativeBootstrapper {
-    fn calibrate(
+    fn run(
         &self,
-        curve_generator:      Arc<dyn InterestRateCurveGenerator>,
-        reference_date:       NaiveDate,
-        pillars:              Vec<InterestRateCurvePillar>,
-        quote_book:           &HashMap<String, InterestRateQuoteSheet>,
-        generator_collection: &InterestRateInstrumentGeneratorCollection,
-        position:             Position,
-        horizon:              NaiveDate,
-    ) -> Result<Arc<dyn InterestRateCurve>, CalibrationError> {
+        curve_generator: &Arc<dyn InterestRateCurveGenerator>,
+        reference_date:  NaiveDate,
+        pillars:         &[InterestRateCurvePillar],
+        environment:     &CalibrationEnvironment,
+        with_jacobian:   bool,
+    ) -> Result<BootstrapOutcome, CalibrationError> {
+        let horizon = environment.horizon;
+
         // 1. 產生所有校準商品（含 market_rate）
         let helpers = Self::generate_calibration_set(
-            &pillars,
-            quote_book,
-            generator_collection,
-            position,
+            pillars,
+            environment.quote_book,
+            environment.generator_collection,
+            environment.position,
             horizon,
         )?;
 
-        // 2. 按 max_date 排序（短天期 → 長天期）
-        let mut sorted_helpers: Vec<InterestRateCurveCalibrationHelper> = helpers;
-        sorted_helpers.sort_by_key(|h| h.instrument().max_date());
+        // 2. 按 max_date 排序（短天期 → 長天期），記錄原始 pillar 位置
+        let mut sorted_helpers: Vec<(usize, InterestRateCurveCalibrationHelper)> =
+            helpers.into_iter().enumerate().collect();
+        sorted_helpers.sort_by_key(|(_, h)| h.instrument().max_date());
+
+        let order: Vec<usize> = sorted_helpers.iter().map(|(i, _)| *i).collect();
 
         // 3. 從排序後的 helpers 中取出 pillar dates、market_rates、instruments
         let pillar_dates: Vec<NaiveDate> = sorted_helpers
             .iter()
-            .map(|h| h.instrument().max_date())
+            .map(|(_, h)| h.instrument().max_date())
             .collect();
 
         let market_rates: Vec<f64> = sorted_helpers
             .iter()
-            .map(|h| h.market_rate())
+            .map(|(_, h)| h.market_rate())
             .collect();
 
         let sorted_instruments: Vec<Arc<dyn SimpleInstrument>> = sorted_helpers
             .into_iter()
-            .map(|h| h.into_instrument())
+            .map(|(_, h)| h.into_instrument())
             .collect();
 
         let n = pillar_dates.len();
@@ -414,13 +487,14 @@ impl InterestRateCurveCalibrator for IterativeBootstrapper {
         // 4. 逐點求解
         let pricer = SimpleInstrumentPricer;
         let solver = RootSolver::new(self.root_solver_config.clone());
-        let pricing_condition = Self::calibration_pricing_condition(horizon);
-        let yfc = self.make_yfc(reference_date)?;
+        let pricing_condition = calibration_pricing_condition(horizon);
+        let yfc = make_yfc(&self.day_counter_generator, reference_date)?;
 
         let mut solved_values: Vec<f64> = Vec::with_capacity(n);
+        let evaluations: Vec<Cell<usize>> = (0..n).map(|_| Cell::new(0)).collect();
 
         for i in 0..n {
-            let value = if i == 0 {
+            let value = if i == 0 && !curve_generator.builds_from_single_pillar() {
                 self.solve_first_pillar(
                     &sorted_instruments[i],
                     market_rates[i],
@@ -429,8 +503,9 @@ impl InterestRateCurveCalibrator for IterativeBootstrapper {
                     &solver,
                     &pricing_condition,
                     &yfc,
+                    &evaluations[i],
                 )?
-            } else if self.apply_partial_freeze_cash_flows {
+            } else if i > 0 && self.apply_partial_freeze_cash_flows {
                 self.solve_subsequent_pillar_with_freeze(
                     i,
                     &sorted_instruments[i],
@@ -438,11 +513,12 @@ impl InterestRateCurveCalibrator for IterativeBootstrapper {
                     pillar_dates[i],
                     &pillar_dates,
                     &solved_values,
-                    &curve_generator,
+                    curve_generator,
                     reference_date,
                     &solver,
                     &pricing_condition,
                     &yfc,
+                    &evaluations[i],
                 )?
             } else {
                 self.solve_subsequent_pillar(
@@ -452,21 +528,170 @@ impl InterestRateCurveCalibrator for IterativeBootstrapper {
                     pillar_dates[i],
                     &pillar_dates,
                     &solved_values,
-                    &curve_generator,
+                    curve_generator,
                     reference_date,
                     &pricer,
                     &solver,
                     &pricing_condition,
                     &yfc,
+                    &evaluations[i],
                 )?
             };
 
             solved_values.push(value);
         }
 
-        // 5. 用完整的 solved_values 建構最終曲線
-        curve_generator
-            .generate_with_dates(reference_date, &pillar_dates, solved_values)
-            .map_err(|e| CalibrationError::CurveGeneration(e.to_string()))
+        // 5. 建構最終曲線；非 pillar-local 的 generator 重新定價所有商品，未全部通過時聯合修正
+        let curve = if curve_generator.is_pillar_local() {
+            curve_generator
+                .generate_with_dates(reference_date, &pillar_dates, solved_values.clone())
+                .map_err(|e| CalibrationError::CurveGeneration(e.to_string()))?
+        } else {
+            let npv_on = |curve: &Arc<dyn InterestRateCurve>, instrument: &Arc<dyn SimpleInstrument>| -> f64 {
+                let market_data = Self::build_market_data(instrument.as_ref(), curve);
+                pricer
+                    .market_value(instrument.as_ref(), &market_data, &pricing_condition)
+                    .map(|npv| npv.amount())
+                    .unwrap_or(f64::NAN)
+            };
+            let npv_with = |values: Vec<f64>, instrument: &Arc<dyn SimpleInstrument>| -> f64 {
+                curve_generator
+                    .generate_with_dates(reference_date, &pillar_dates, values)
+                    .map_or(f64::NAN, |curve| npv_on(&curve, instrument))
+            };
+
+            let mut sweeps = 0;
+            loop {
+                let curve = curve_generator
+                    .generate_with_dates(reference_date, &pillar_dates, solved_values.clone())
+                    .map_err(|e| CalibrationError::CurveGeneration(e.to_string()))?;
+                let unpriced = sorted_instruments
+                    .iter()
+                    .filter(|instrument| {
+                        let npv = npv_on(&curve, instrument);
+                        npv.is_nan() || npv.abs() >= REPRICING_TOLERANCE
+                    })
+                    .count();
+                if unpriced == 0 {
+                    break curve;
+                }
+                if sweeps == MAX_JOINT_SWEEPS {
+                    return Err(CalibrationError::CurveGeneration(format!(
+                        "{} calibration instrument(s) do not reprice after {} joint sweeps",
+                        unpriced, MAX_JOINT_SWEEPS,
+                    )));
+                }
+
+                for i in 0..n {
+                    let objective = |value: f64| -> f64 {
+                        evaluations[i].set(evaluations[i].get() + 1);
+                        let mut trial_values = solved_values.clone();
+                        trial_values[i] = value;
+                        npv_with(trial_values, &sorted_instruments[i])
+                    };
+                    let (lower, upper) = self.bootstrapping_trait.bracket(solved_values[i]);
+                    let value = solver
+                        .solve(objective, solved_values[i], Some(upper))
+                        .or_else(|_| solver.solve(objective, lower, Some(upper)))
+                        .map_err(|e| CalibrationError::CurveGeneration(
+                            format!("pillar {} ({:?}) joint sweep failed: {}", i, pillar_dates[i], e)
+                        ))?;
+                    solved_values[i] = value;
+                }
+                sweeps += 1;
+            }
+        };
+
+        // root solver 求值次數，還原為 pillars 的輸入順序
+        let mut pillar_evaluations = vec![0; n];
+        for (sorted_index, original_index) in order.iter().enumerate() {
+            pillar_evaluations[*original_index] = evaluations[sorted_index].get();
+        }
+
+        if !with_jacobian {
+            return Ok(BootstrapOutcome { curve, jacobian: None, evaluations: pillar_evaluations });
+        }
+
+        // 6. d(pillar value)/d(quote)：精確擬合，列縮放為 1、無平滑懲罰
+        let curve_names: Vec<String> = sorted_instruments
+            .iter()
+            .flat_map(|instrument| instrument.curve_name_map().values().cloned())
+            .collect();
+        let market_builder = |values: &DVector<f64>| {
+            let curve = curve_generator
+                .generate_with_dates(reference_date, &pillar_dates, values.iter().cloned().collect())
+                .ok()?;
+            Some(
+                curve_names
+                    .iter()
+                    .map(|name| (name.clone(), curve.clone()))
+                    .collect::<HashMap<String, Arc<dyn InterestRateCurve>>>()
+            )
+        };
+
+        let sources = quote_sources(pillars, &order, environment.quote_book)?;
+        let row_scales = vec![1.0; n];
+        let jacobian = QuoteJacobianProblem {
+            instruments:       &sorted_instruments,
+            sources:           &sources,
+            row_scales:        &row_scales,
+            penalty:           None,
+            market_builder:    &market_builder,
+            pricing_condition: &pricing_condition,
+            environment,
+            fd_step:           JACOBIAN_FD_STEP,
+        }
+        .solve(pillar_dates.clone(), &DVector::from_vec(solved_values))?;
+
+        Ok(BootstrapOutcome { curve, jacobian: Some(jacobian), evaluations: pillar_evaluations })
+    }
+}
+
+
+impl InterestRateCurveCalibrator for IterativeBootstrapper {
+    fn calibrate(
+        &self,
+        curve_generator:      Arc<dyn InterestRateCurveGenerator>,
+        reference_date:       NaiveDate,
+        pillars:              Vec<InterestRateCurvePillar>,
+        quote_book:           &HashMap<String, InterestRateQuoteSheet>,
+        generator_collection: &InterestRateInstrumentGeneratorCollection,
+        position:             Position,
+        horizon:              NaiveDate,
+    ) -> Result<Arc<dyn InterestRateCurve>, CalibrationError> {
+        let environment = CalibrationEnvironment {
+            quote_book,
+            generator_collection,
+            position,
+            horizon,
+        };
+        self.run(&curve_generator, reference_date, &pillars, &environment, false)
+            .map(|outcome| outcome.curve)
+    }
+
+    /// 與預設實作相同，另外填入每個 pillar 的 root solver 求值次數。
+    fn calibrate_with_report(
+        &self,
+        curve_generator: Arc<dyn InterestRateCurveGenerator>,
+        reference_date:  NaiveDate,
+        pillars:         Vec<InterestRateCurvePillar>,
+        environment:     &CalibrationEnvironment,
+        tolerance_bp:    f64,
+    ) -> Result<(Arc<dyn InterestRateCurve>, CalibrationReport), CalibrationError> {
+        let helpers = generate_repricing_helpers(&pillars, environment)?;
+
+        let start = Instant::now();
+        let outcome = self.run(&curve_generator, reference_date, &pillars, environment, false)?;
+        let elapsed = start.elapsed();
+
+        let report = CalibrationReporter::new(environment.horizon, tolerance_bp).report(
+            calibrator_name::<Self>(),
+            &outcome.curve,
+            &helpers,
+            elapsed,
+            Some(&outcome.evaluations),
+            &|instrument, curve| self.repricing_market_data(instrument, curve),
+        );
+        Ok((outcome.curve, report))
     }
 }
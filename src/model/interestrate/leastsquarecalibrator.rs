@@ -0,0 +1,682 @@
+// ── leastsquarecalibrator.rs ──────────────────────────────────────────────────
+//
+// 全域最小平方（Global Least-Square）曲線校準器，對應 QuantLib GlobalBootstrapper。
+//
+// # 設計說明
+//
+// 與 IterativeBootstrapper 逐點求根不同，本校準器一次求解所有曲線節點值 v：
+//
+//   min_v  ½ Σ_i r_i(v)²  +  ½ λ Σ_k (Δ²v)_k²
+//
+// 其中 r_i 為第 i 個校準商品的加權、正規化殘差，第二項為可選的平滑懲罰
+// （節點值的二階差分），λ = `smoothing_weight`。
+//
+// 因此：
+//   - 校準商品數可以多於節點數（overdetermined，最小平方擬合）
+//   - λ > 0 時可產生平滑曲線，節點數亦可多於商品數
+//   - 任何 InterpolationTarget / PolynomialType 均適用：
+//     曲線一律透過 `generate_with_dates` 建構，不需要 FlatForwardCurve 起步，
+//     因此也不限制左外插方式
+//
+// # 曲線節點
+//
+// 節點日期取自 PiecewisePolyInterestRateCurveGenerator 已設定的 `dates()`；
+// 若未設定，則使用校準商品 max_date（去重、排序）作為節點。
+//
+// # 殘差正規化
+//
+//   r_i = w_i × NPV_i / s_i,    s_i = ‖∂NPV_i/∂v‖₂（於初始猜測處計算一次）
+//
+// NPV 的量綱隨名目本金與天期變化，直接相加會讓長天期商品主導目標函數。
+// 以初始 Jacobian 的列範數 s_i 正規化後，殘差約略落在「節點值空間」
+// （利率或 log discount），各商品 1bp 的誤差有可比較的量級。
+// 使用範數而非列總和，避免 basis swap 等雙浮動腿商品因正負相消得到 s_i ≈ 0。
+//
+// # 求解器
+//
+// 使用 argmin 的 TrustRegion + Steihaug（truncated CG）子問題。
+// Gradient 與 Hessian 採 Gauss-Newton 近似：
+//
+//   g = Jᵀr + λDᵀDv
+//   H = JᵀJ + λDᵀD
+//
+// J 以前向差分計算，每次需要 n + 1 次曲線建構與 m × (n + 1) 次定價。
+// 同一個 param 的 gradient 與 hessian 共用一份 Jacobian 快取。
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use argmin::core::{CostFunction, Error as ArgminError, Executor, Gradient, Hessian, State, TerminationReason};
+use argmin::solver::trustregion::{Steihaug, TrustRegion};
+use chrono::NaiveDate;
+use nalgebra::{DMatrix, DVector};
+
+use crate::configuration::InterestRateInstrumentGeneratorCollection;
+use crate::instrument::instrument::{Position, SimpleInstrument};
+use crate::marketdata::interestrate::interestratequotesheet::InterestRateQuoteSheet;
+use crate::model::interestrate::bootstrappingtrait::BootstrappingTrait;
+use crate::model::interestrate::interestratecurve::{
+    InterestRateCurve,
+    InterestRateCurveGenerator,
+    YearFractionCalculator,
+};
+use crate::model::interestrate::interestratecurvecalibrator::{
+    CalibrationEnvironment,
+    CalibrationError,
+    InterestRateCurvePillar,
+    InterestRateCurveCalibrator,
+    calibration_pricing_condition,
+    make_yfc,
+};
+use crate::model::interestrate::piecewisepolyinterestratecurve::{
+    InterpolationTarget,
+    PiecewisePolyInterestRateCurveGenerator,
+};
+use crate::model::interestrate::quotejacobian::{
+    QuoteJacobian,
+    QuoteJacobianProblem,
+    quote_sources,
+};
+use crate::pricer::pricer::Pricer;
+use crate::pricer::simpleinstrumentpricer::SimpleInstrumentPricer;
+use crate::pricingcondition::PricingCondition;
+use crate::time::daycounter::daycounter::DayCounterGenerator;
+
+
+/// smoothing_weight 不大於此值時視為不平滑（λ = 0）。
+const SMOOTHING_WEIGHT_EPSILON: f64 = 1e-14;
+
+/// 初始 Jacobian 的列範數小於此值時，視為商品對所有節點不敏感。
+const MIN_SENSITIVITY: f64 = 1e-14;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// LeastSquareCalibratorConfig
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// LeastSquareCalibrator 的參數設定。
+///
+/// 使用 [`LeastSquareCalibratorConfig::default()`] 取得合理的預設值，再按需覆蓋。
+#[derive(Clone, Debug)]
+pub struct LeastSquareCalibratorConfig {
+    /// Trust region 外層最大迭代次數。
+    pub max_iter:               u64,
+    /// 收斂判斷：目標函數 < ½ × tolerance²（即殘差向量 L2 範數 < tolerance）。
+    /// overdetermined 或平滑時通常無法達到，跑滿 `max_iter` 後取最佳解；
+    /// 商品數等於節點數且不平滑時，跑滿 `max_iter` 仍未達到即為 `NotConverged`。
+    pub tolerance:              f64,
+    /// 二階差分平滑懲罰權重 λ，0 表示不平滑。
+    pub smoothing_weight:       f64,
+    /// 前向差分 Jacobian 的節點值擾動量。
+    pub finite_difference_step: f64,
+    /// Trust region 初始半徑（節點值空間）。
+    pub initial_radius:         f64,
+    /// Trust region 最大半徑。
+    pub max_radius:             f64,
+}
+
+impl LeastSquareCalibratorConfig {
+    /// 檢查 `smoothing_weight`、`finite_difference_step` 與 trust region 半徑。
+    pub(crate) fn validate(&self) -> Result<(), CalibrationError> {
+        if self.smoothing_weight < 0.0 {
+            return Err(CalibrationError::CurveGeneration(
+                "smoothing_weight must be non-negative".to_string()
+            ));
+        }
+        if self.finite_difference_step <= 0.0 {
+            return Err(CalibrationError::CurveGeneration(
+                "finite_difference_step must be positive".to_string()
+            ));
+        }
+        if self.initial_radius <= 0.0 || self.max_radius < self.initial_radius {
+            return Err(CalibrationError::CurveGeneration(
+                "trust region radius must satisfy 0 < initial_radius <= max_radius".to_string()
+            ));
+        }
+        Ok(())
+    }
+
+    /// 是否加上平滑懲罰（λ > 0）。
+    pub(crate) fn is_smoothed(&self) -> bool {
+        self.smoothing_weight > SMOOTHING_WEIGHT_EPSILON
+    }
+}
+
+impl Default for LeastSquareCalibratorConfig {
+    fn default() -> Self {
+        Self {
+            max_iter:               100,
+            tolerance:              1e-10,
+            smoothing_weight:       0.0,
+            finite_difference_step: 1e-6,
+            initial_radius:         1e-2,
+            max_radius:             1.0,
+        }
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// LeastSquareProblem（argmin 目標函數）
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// 與曲線型態無關：由 `market_builder` 把參數向量轉成定價用的
+// market_data（curve name → curve），單曲線與多曲線聯合校準共用同一套求解流程。
+
+/// 參數向量 → 定價用 market_data。曲線建構失敗時回傳 None。
+pub(crate) type MarketBuilder<'a> =
+    dyn Fn(&DVector<f64>) -> Option<HashMap<String, Arc<dyn InterestRateCurve>>> + 'a;
+
+/// 最近一次計算的 (param, 縮放後殘差, 縮放後 Jacobian)。
+type JacobianCacheEntry = (DVector<f64>, DVector<f64>, DMatrix<f64>);
+
+struct LeastSquareProblem<'a> {
+    instruments:       &'a [Arc<dyn SimpleInstrument>],
+    /// w_i / s_i：權重與正規化因子合併後的列縮放。
+    row_scales:        Vec<f64>,
+    market_builder:    &'a MarketBuilder<'a>,
+    pricing_condition: &'a PricingCondition,
+    /// 平滑懲罰矩陣 P（已含 √λ），目標函數加上 ½‖Pv‖²。
+    penalty:           Option<DMatrix<f64>>,
+    fd_step:           f64,
+    jacobian_cache:    RwLock<Option<JacobianCacheEntry>>,
+}
+
+impl LeastSquareProblem<'_> {
+    /// 以參數建構曲線並計算每個商品的原始 NPV（未縮放）。
+    fn raw_npvs(&self, values: &DVector<f64>) -> Option<DVector<f64>> {
+        let market_data = (self.market_builder)(values)?;
+
+        let pricer = SimpleInstrumentPricer;
+        let mut npvs = DVector::zeros(self.instruments.len());
+        for (i, instrument) in self.instruments.iter().enumerate() {
+            let npv = pricer
+                .market_value(instrument.as_ref(), &market_data, self.pricing_condition)?
+                .amount();
+            if !npv.is_finite() {
+                return None;
+            }
+            npvs[i] = npv;
+        }
+        Some(npvs)
+    }
+
+    /// 前向差分 Jacobian ∂NPV_i/∂v_j（未縮放）。
+    fn raw_jacobian(
+        &self,
+        values:    &DVector<f64>,
+        base_npvs: &DVector<f64>,
+    ) -> Option<DMatrix<f64>> {
+        let m = self.instruments.len();
+        let n = values.len();
+        let mut jacobian = DMatrix::zeros(m, n);
+
+        for j in 0..n {
+            let mut bumped = values.clone();
+            bumped[j] += self.fd_step;
+            let bumped_npvs = self.raw_npvs(&bumped)?;
+            let column = (bumped_npvs - base_npvs) / self.fd_step;
+            jacobian.set_column(j, &column);
+        }
+        Some(jacobian)
+    }
+
+    fn residuals(&self, values: &DVector<f64>) -> Option<DVector<f64>> {
+        let npvs = self.raw_npvs(values)?;
+        Some(npvs.component_mul(&DVector::from_column_slice(&self.row_scales)))
+    }
+
+    /// 取得 (縮放後殘差, 縮放後 Jacobian)，同一個 param 只計算一次。
+    fn residuals_and_jacobian(
+        &self,
+        values: &DVector<f64>,
+    ) -> Result<(DVector<f64>, DMatrix<f64>), ArgminError> {
+        {
+            let cache = self.jacobian_cache
+                .read()
+                .map_err(|_| ArgminError::msg("jacobian cache poisoned"))?;
+            if let Some((cached_values, residuals, jacobian)) = cache.as_ref()
+                && cached_values == values
+            {
+                return Ok((residuals.clone(), jacobian.clone()));
+            }
+        }
+
+        let npvs = self.raw_npvs(values)
+            .ok_or_else(|| ArgminError::msg("curve generation or pricing failed"))?;
+        let raw_jacobian = self.raw_jacobian(values, &npvs)
+            .ok_or_else(|| ArgminError::msg("jacobian evaluation failed"))?;
+
+        let scales = DVector::from_column_slice(&self.row_scales);
+        let residuals = npvs.component_mul(&scales);
+        let jacobian = DMatrix::from_diagonal(&scales) * raw_jacobian;
+
+        let mut cache = self.jacobian_cache
+            .write()
+            .map_err(|_| ArgminError::msg("jacobian cache poisoned"))?;
+        *cache = Some((values.clone(), residuals.clone(), jacobian.clone()));
+        Ok((residuals, jacobian))
+    }
+}
+
+impl CostFunction for LeastSquareProblem<'_> {
+    type Param  = DVector<f64>;
+    type Output = f64;
+
+    fn cost(&self, values: &Self::Param) -> Result<Self::Output, ArgminError> {
+        // 曲線建構或定價失敗時回傳 +∞，讓 trust region 拒絕該步並縮小半徑
+        let Some(residuals) = self.residuals(values) else {
+            return Ok(f64::INFINITY);
+        };
+
+        let mut cost = 0.5 * residuals.norm_squared();
+        if let Some(p) = &self.penalty {
+            cost += 0.5 * (p * values).norm_squared();
+        }
+        Ok(cost)
+    }
+}
+
+impl Gradient for LeastSquareProblem<'_> {
+    type Param    = DVector<f64>;
+    type Gradient = DVector<f64>;
+
+    fn gradient(&self, values: &Self::Param) -> Result<Self::Gradient, ArgminError> {
+        let (residuals, jacobian) = self.residuals_and_jacobian(values)?;
+
+        let mut gradient = jacobian.transpose() * residuals;
+        if let Some(p) = &self.penalty {
+            gradient += p.transpose() * (p * values);
+        }
+        Ok(gradient)
+    }
+}
+
+impl Hessian for LeastSquareProblem<'_> {
+    type Param   = DVector<f64>;
+    type Hessian = DMatrix<f64>;
+
+    fn hessian(&self, values: &Self::Param) -> Result<Self::Hessian, ArgminError> {
+        let (_, jacobian) = self.residuals_and_jacobian(values)?;
+
+        let mut hessian = jacobian.transpose() * &jacobian;
+        if let Some(p) = &self.penalty {
+            hessian += p.transpose() * p;
+        }
+        Ok(hessian)
+    }
+}
+
+
+/// 二階差分平滑懲罰矩陣（(n-2) × n，已乘上 √λ）。
+///
+/// λ = 0 或 n < 3 時回傳 None。
+pub(crate) fn second_difference_penalty(n: usize, smoothing_weight: f64) -> Option<DMatrix<f64>> {
+    if smoothing_weight <= SMOOTHING_WEIGHT_EPSILON || n < 3 {
+        return None;
+    }
+    let scale = smoothing_weight.sqrt();
+    let rows = n - 2;
+    let mut d = DMatrix::zeros(rows, n);
+    for k in 0..rows {
+        d[(k, k)]     =  scale;
+        d[(k, k + 1)] = -2.0 * scale;
+        d[(k, k + 2)] =  scale;
+    }
+    Some(d)
+}
+
+
+/// `solve_least_square` 的結果。
+pub(crate) struct LeastSquareSolution {
+    pub(crate) values:     DVector<f64>,
+    /// 實際使用的殘差列縮放 w_i / s_i。
+    pub(crate) row_scales: Vec<f64>,
+}
+
+/// 以 trust region 求解加權最小平方問題，回傳最佳參數向量與列縮放。
+///
+/// 殘差先以初始 Jacobian 的列範數正規化（見檔頭說明），再乘上 `weights`。
+/// 單曲線（LeastSquareCalibrator）與多曲線聯合校準（MultiCurveCalibrator）共用。
+pub(crate) fn solve_least_square(
+    instruments:       &[Arc<dyn SimpleInstrument>],
+    weights:           &[f64],
+    initial_values:    DVector<f64>,
+    market_builder:    &MarketBuilder<'_>,
+    pricing_condition: &PricingCondition,
+    penalty:           Option<DMatrix<f64>>,
+    config:            &LeastSquareCalibratorConfig,
+) -> Result<LeastSquareSolution, CalibrationError> {
+    let mut problem = LeastSquareProblem {
+        instruments,
+        row_scales:     vec![1.0; instruments.len()],
+        market_builder,
+        pricing_condition,
+        penalty,
+        fd_step:        config.finite_difference_step,
+        jacobian_cache: RwLock::new(None),
+    };
+
+    // 以初始 Jacobian 的列範數正規化殘差
+    let (_, initial_jacobian) = problem
+        .residuals_and_jacobian(&initial_values)
+        .map_err(|e| CalibrationError::CurveGeneration(
+            format!("initial curve evaluation failed: {}", e)
+        ))?;
+
+    let mut row_scales = Vec::with_capacity(instruments.len());
+    for (i, weight) in weights.iter().enumerate() {
+        let sensitivity = initial_jacobian.row(i).norm();
+        if !sensitivity.is_finite() || sensitivity < MIN_SENSITIVITY {
+            return Err(CalibrationError::CurveGeneration(format!(
+                "instrument {} (maturity {:?}) is insensitive to all curve nodes",
+                i, instruments[i].max_date(),
+            )));
+        }
+        row_scales.push(weight / sensitivity);
+    }
+    problem.row_scales = row_scales.clone();
+    problem.jacobian_cache = RwLock::new(None);
+
+    let trust_region = TrustRegion::new(Steihaug::new())
+        .with_radius(config.initial_radius)
+        .and_then(|tr| tr.with_max_radius(config.max_radius))
+        .map_err(|e| CalibrationError::CurveGeneration(e.to_string()))?;
+
+    // 商品數等於節點數且不平滑時殘差可降至 0，必須達到 target cost
+    let exact_fit = instruments.len() == initial_values.len() && problem.penalty.is_none();
+    let target_cost = 0.5 * config.tolerance * config.tolerance;
+    let result = Executor::new(problem, trust_region)
+        .configure(|state| {
+            state
+                .param(initial_values)
+                .max_iters(config.max_iter)
+                .target_cost(target_cost)
+        })
+        .run()
+        .map_err(|e| CalibrationError::CurveGeneration(
+            format!("least-square solve failed: {}", e)
+        ))?;
+
+    match result.state().get_termination_reason() {
+        Some(TerminationReason::TargetCostReached) => {}
+        // overdetermined 或平滑時目標函數通常無法降至 target cost，跑滿 max_iter 為正常結束
+        Some(TerminationReason::MaxItersReached) if !exact_fit => {}
+        reason => {
+            return Err(CalibrationError::NotConverged(format!(
+                "least-square solve stopped: {} (best cost {:e}, target {:e})",
+                reason.map_or("not terminated", TerminationReason::text),
+                result.state().get_best_cost(),
+                target_cost,
+            )));
+        }
+    }
+
+    let values = result
+        .state()
+        .get_best_param()
+        .cloned()
+        .ok_or_else(|| CalibrationError::CurveGeneration(
+            "least-square solve returned no parameters".to_string()
+        ))?;
+
+    Ok(LeastSquareSolution { values, row_scales })
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// LeastSquareCalibrator
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct LeastSquareCalibrator {
+    config:                LeastSquareCalibratorConfig,
+    bootstrapping_trait:   BootstrappingTrait,
+    day_counter_generator: Arc<DayCounterGenerator>,
+    node_dates:            Vec<NaiveDate>,
+}
+
+impl LeastSquareCalibrator {
+    /// 從 PiecewisePolyInterestRateCurveGenerator 建構 LeastSquareCalibrator。
+    ///
+    /// 節點日期取自 `generator.dates()`；若為空，校準時改用商品 max_date。
+    ///
+    /// # Errors
+    ///
+    /// `smoothing_weight`、`finite_difference_step` 或 trust region 半徑不合法時
+    /// 回傳 CalibrationError。
+    pub fn new(
+        config:    LeastSquareCalibratorConfig,
+        generator: &PiecewisePolyInterestRateCurveGenerator,
+    ) -> Result<Self, CalibrationError> {
+        Self::from_parts(
+            config,
+            generator.interpolation_target(),
+            generator.day_counter_generator().clone(),
+            generator.dates().to_vec(),
+        )
+    }
+
+    /// 不經 PiecewisePolyInterestRateCurveGenerator 建構，
+    /// 供其他以節點值定義的曲線（如 SmithWilsonCurveGenerator）使用。
+    ///
+    /// `interpolation_target` 只用於決定初始猜測，需與 generator 的 values 意義一致。
+    ///
+    /// # Errors
+    ///
+    /// 同 [`LeastSquareCalibrator::new`]。
+    pub fn from_parts(
+        config:                LeastSquareCalibratorConfig,
+        interpolation_target:  InterpolationTarget,
+        day_counter_generator: Arc<DayCounterGenerator>,
+        node_dates:            Vec<NaiveDate>,
+    ) -> Result<Self, CalibrationError> {
+        config.validate()?;
+
+        Ok(Self {
+            config,
+            bootstrapping_trait: BootstrappingTrait::new(interpolation_target),
+            day_counter_generator,
+            node_dates,
+        })
+    }
+
+    /// 使用預設參數的建構方式。
+    pub fn with_defaults(
+        generator: &PiecewisePolyInterestRateCurveGenerator,
+    ) -> Result<Self, CalibrationError> {
+        Self::new(LeastSquareCalibratorConfig::default(), generator)
+    }
+
+    pub fn config(&self) -> &LeastSquareCalibratorConfig { &self.config }
+    pub fn node_dates(&self) -> &[NaiveDate] { &self.node_dates }
+
+    /// 決定本次校準的節點日期。
+    fn resolve_node_dates(&self, maturities: &[NaiveDate]) -> Vec<NaiveDate> {
+        let mut dates = if self.node_dates.is_empty() {
+            maturities.to_vec()
+        } else {
+            self.node_dates.clone()
+        };
+        dates.sort();
+        dates.dedup();
+        dates
+    }
+
+    /// 每個節點的初始猜測：取到期日 ≥ 節點日期的第一個商品之 market_rate，
+    /// 若節點超過最長商品，取最長商品。
+    fn initial_values(
+        &self,
+        node_dates:   &[NaiveDate],
+        maturities:   &[NaiveDate],
+        market_rates: &[f64],
+        yfc:          &YearFractionCalculator,
+    ) -> DVector<f64> {
+        let last = maturities.len() - 1;
+        DVector::from_iterator(
+            node_dates.len(),
+            node_dates.iter().map(|node_date| {
+                let idx = maturities
+                    .iter()
+                    .position(|d| d >= node_date)
+                    .unwrap_or(last);
+                self.bootstrapping_trait.initial_value(market_rates[idx], yfc, *node_date)
+            }),
+        )
+    }
+
+    /// 校準並同時回傳 d(node value)/d(quote) Jacobian。
+    ///
+    /// Jacobian 的 column 順序與 `pillars` 一致，見 `quotejacobian.rs`。
+    pub fn calibrate_with_jacobian(
+        &self,
+        curve_generator: Arc<dyn InterestRateCurveGenerator>,
+        reference_date:  NaiveDate,
+        pillars:         &[InterestRateCurvePillar],
+        environment:     &CalibrationEnvironment,
+    ) -> Result<(Arc<dyn InterestRateCurve>, QuoteJacobian), CalibrationError> {
+        let (curve, jacobian) = self.run(&curve_generator, reference_date, pillars, environment, true)?;
+        let jacobian = jacobian.ok_or_else(|| CalibrationError::CurveGeneration(
+            "quote jacobian was not computed".to_string()
+        ))?;
+        Ok((curve, jacobian))
+    }
+
+    fn run(
+        &self,
+        curve_generator: &Arc<dyn InterestRateCurveGenerator>,
+        reference_date:  NaiveDate,
+        pillars:         &[InterestRateCurvePillar],
+        environment:     &CalibrationEnvironment,
+        with_jacobian:   bool,
+    ) -> Result<(Arc<dyn InterestRateCurve>, Option<QuoteJacobian>), CalibrationError> {
+        // 1. 產生所有校準商品（順序與 pillars 一致，權重一一對應）
+        let helpers = Self::generate_calibration_set(
+            pillars,
+            environment.quote_book,
+            environment.generator_collection,
+            environment.position,
+            environment.horizon,
+        )?;
+
+        if helpers.is_empty() {
+            return Err(CalibrationError::CurveGeneration(
+                "no calibration instruments provided".to_string(),
+            ));
+        }
+
+        if pillars.iter().any(|p| !p.weight().is_finite() || p.weight() <= 0.0) {
+            return Err(CalibrationError::CurveGeneration(
+                "calibration weights must be positive and finite".to_string(),
+            ));
+        }
+
+        // 2. 按 max_date 排序（記錄原始 pillar 位置，權重隨商品一起排序）
+        let mut indexed: Vec<(usize, _)> = helpers.into_iter().enumerate().collect();
+        indexed.sort_by_key(|(_, h)| h.instrument().max_date());
+
+        let order: Vec<usize> = indexed.iter().map(|(i, _)| *i).collect();
+        let maturities: Vec<NaiveDate> = indexed
+            .iter()
+            .map(|(_, h)| h.instrument().max_date())
+            .collect();
+        let market_rates: Vec<f64> = indexed.iter().map(|(_, h)| h.market_rate()).collect();
+        let weights: Vec<f64> = order.iter().map(|i| pillars[*i].weight()).collect();
+        let instruments: Vec<Arc<dyn SimpleInstrument>> = indexed
+            .into_iter()
+            .map(|(_, h)| h.into_instrument())
+            .collect();
+
+        // 3. 節點與初始猜測
+        let node_dates = self.resolve_node_dates(&maturities);
+        if instruments.len() < node_dates.len() && !self.config.is_smoothed() {
+            return Err(CalibrationError::CurveGeneration(format!(
+                "{} calibration instruments for {} curve nodes: \
+                 underdetermined without smoothing_weight > 0",
+                instruments.len(),
+                node_dates.len(),
+            )));
+        }
+
+        let yfc = make_yfc(&self.day_counter_generator, reference_date)?;
+        let initial_values = self.initial_values(&node_dates, &maturities, &market_rates, &yfc);
+        let pricing_condition = calibration_pricing_condition(environment.horizon);
+
+        // 4. 單曲線：所有商品引用的 curve name 均指向正在校準的曲線
+        let curve_names: Vec<String> = instruments
+            .iter()
+            .flat_map(|instrument| instrument.curve_name_map().values().cloned())
+            .collect();
+
+        let market_builder = |values: &DVector<f64>| {
+            let curve = curve_generator
+                .generate_with_dates(reference_date, &node_dates, values.iter().cloned().collect())
+                .ok()?;
+            Some(
+                curve_names
+                    .iter()
+                    .map(|name| (name.clone(), curve.clone()))
+                    .collect::<HashMap<String, Arc<dyn InterestRateCurve>>>()
+            )
+        };
+
+        // 5. Trust region 求解
+        let penalty = second_difference_penalty(node_dates.len(), self.config.smoothing_weight);
+        let solution = solve_least_square(
+            &instruments,
+            &weights,
+            initial_values,
+            &market_builder,
+            &pricing_condition,
+            penalty.clone(),
+            &self.config,
+        )?;
+
+        // 6. 用求得的節點值建構最終曲線
+        let curve = curve_generator
+            .generate_with_dates(reference_date, &node_dates, solution.values.iter().cloned().collect())
+            .map_err(|e| CalibrationError::CurveGeneration(e.to_string()))?;
+
+        if !with_jacobian {
+            return Ok((curve, None));
+        }
+
+        // 7. d(node value)/d(quote)
+        let sources = quote_sources(pillars, &order, environment.quote_book)?;
+        let jacobian = QuoteJacobianProblem {
+            instruments:       &instruments,
+            sources:           &sources,
+            row_scales:        &solution.row_scales,
+            penalty:           penalty.as_ref(),
+            market_builder:    &market_builder,
+            pricing_condition: &pricing_condition,
+            environment,
+            fd_step:           self.config.finite_difference_step,
+        }
+        .solve(node_dates.clone(), &solution.values)?;
+
+        Ok((curve, Some(jacobian)))
+    }
+}
+
+
+impl InterestRateCurveCalibrator for LeastSquareCalibrator {
+    fn calibrate(
+        &self,
+        curve_generator:      Arc<dyn InterestRateCurveGenerator>,
+        reference_date:       NaiveDate,
+        pillars:              Vec<InterestRateCurvePillar>,
+        quote_book:           &HashMap<String, InterestRateQuoteSheet>,
+        generator_collection: &InterestRateInstrumentGeneratorCollection,
+        position:             Position,
+        horizon:              NaiveDate,
+    ) -> Result<Arc<dyn InterestRateCurve>, CalibrationError> {
+        let environment = CalibrationEnvironment {
+            quote_book,
+            generator_collection,
+            position,
+            horizon,
+        };
+        self.run(&curve_generator, reference_date, &pillars, &environment, false)
+            .map(|(curve, _)| curve)
+    }
+}
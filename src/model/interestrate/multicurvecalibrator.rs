@@ -0,0 +1,518 @@
+// ── multicurvecalibrator.rs ───────────────────────────────────────────────────
+//
+// 多曲線同時校準驅動（Multi-Curve Simultaneous Bootstrapping）。
+//
+// # 設計說明
+//
+// 單曲線校準器（IterativeBootstrapper / LeastSquareCalibrator）假設商品
+// curve_name_map 中的所有 curve name 都指向正在校準的那一條曲線。
+// 實務上 OIS 折現 + 3M / 6M 投影曲線的 basis swap 會同時引用多條曲線，
+// 需要一個能處理曲線間相依關係的驅動：
+//
+//   1. 每條曲線以 `CurveCalibrationSpec` 描述（曲線名稱、generator、pillars）
+//   2. 由各校準商品的 `curve_name_map()` 建立相依圖：
+//        curve A 的商品引用 curve B  ⇒  A 依賴 B
+//   3. 以 Tarjan 演算法求強連通分量（SCC），並依拓撲順序排列：
+//        - 單一曲線的分量：在已校準曲線固定的前提下單獨校準
+//        - 多條曲線互相依賴的分量：所有節點值堆疊成一個向量聯合求解
+//   4. 每個分量校準完成後立即寫入 `MarketDataSet::insert_curve`，
+//      後續分量即可把它當作固定曲線使用
+//
+// 不在 spec 中的 curve name 必須已存在於 MarketDataSet（外部固定曲線），
+// 否則回傳 `CalibrationError::MissingCurve`。
+//
+// # 求解
+//
+// 每個分量使用與 LeastSquareCalibrator 相同的 trust region 最小平方求解
+// （`solve_least_square`）。商品數等於節點數時即為精確擬合（bootstrapping），
+// 商品數多於節點數時為最小平方擬合。
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use nalgebra::{DMatrix, DVector};
+
+use crate::configuration::InterestRateInstrumentGeneratorCollection;
+use crate::instrument::instrument::{Position, SimpleInstrument};
+use crate::marketdata::marketdataset::MarketDataSet;
+use crate::model::interestrate::bootstrappingtrait::BootstrappingTrait;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::model::interestrate::interestratecurvecalibrator::{
+    CalibrationError,
+    InterestRateCurvePillar,
+    calibration_pricing_condition,
+    generate_calibration_helpers,
+    make_yfc,
+};
+use crate::model::interestrate::leastsquarecalibrator::{
+    LeastSquareCalibratorConfig,
+    second_difference_penalty,
+    solve_least_square,
+};
+use crate::model::interestrate::piecewisepolyinterestratecurve::PiecewisePolyInterestRateCurveGenerator;
+use crate::pricingcondition::PricingCondition;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CurveCalibrationSpec
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 單條曲線的校準設定。
+///
+/// 節點日期取自 `generator.dates()`；若未設定，使用校準商品 max_date。
+pub struct CurveCalibrationSpec {
+    curve_name: String,
+    generator:  Arc<PiecewisePolyInterestRateCurveGenerator>,
+    pillars:    Vec<InterestRateCurvePillar>,
+}
+
+impl CurveCalibrationSpec {
+    pub fn new(
+        curve_name: impl Into<String>,
+        generator:  Arc<PiecewisePolyInterestRateCurveGenerator>,
+        pillars:    Vec<InterestRateCurvePillar>,
+    ) -> Self {
+        Self { curve_name: curve_name.into(), generator, pillars }
+    }
+
+    pub fn curve_name(&self) -> &str { &self.curve_name }
+    pub fn generator(&self) -> &Arc<PiecewisePolyInterestRateCurveGenerator> { &self.generator }
+    pub fn pillars(&self) -> &[InterestRateCurvePillar] { &self.pillars }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CurveDependencyGraph
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 待校準曲線之間的相依圖。
+///
+/// 邊 A → B 表示 curve A 的校準商品引用了 curve B。
+/// 只記錄待校準曲線之間的邊；外部固定曲線不在圖中。
+pub struct CurveDependencyGraph {
+    edges: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl CurveDependencyGraph {
+    /// `dependencies`：curve name → 該曲線校準商品引用的所有 curve name。
+    /// 不在 key 集合中的 curve name 視為外部固定曲線，自動忽略；自我引用亦忽略。
+    pub fn new(dependencies: &HashMap<String, HashSet<String>>) -> Self {
+        let edges = dependencies
+            .iter()
+            .map(|(curve, deps)| {
+                let internal: BTreeSet<String> = deps
+                    .iter()
+                    .filter(|d| *d != curve && dependencies.contains_key(*d))
+                    .cloned()
+                    .collect();
+                (curve.clone(), internal)
+            })
+            .collect();
+        Self { edges }
+    }
+
+    pub fn curve_names(&self) -> impl Iterator<Item = &String> {
+        self.edges.keys()
+    }
+
+    pub fn dependencies(&self, curve_name: &str) -> Option<&BTreeSet<String>> {
+        self.edges.get(curve_name)
+    }
+
+    /// 校準順序：強連通分量依拓撲順序排列，被依賴的分量在前。
+    ///
+    /// 長度為 1 的分量可單獨校準；長度 > 1 的分量必須聯合求解。
+    pub fn calibration_groups(&self) -> Vec<Vec<String>> {
+        // Tarjan：分量在其所有可達分量之後才輸出，
+        // 邊方向為「依賴」，因此輸出順序即為「依賴先於被依賴」。
+        let names: Vec<&String> = self.edges.keys().collect();
+        let position: HashMap<&String, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (*n, i))
+            .collect();
+
+        let n = names.len();
+        let mut index:    Vec<Option<usize>> = vec![None; n];
+        let mut lowlink:  Vec<usize>         = vec![0; n];
+        let mut on_stack: Vec<bool>          = vec![false; n];
+        let mut stack:    Vec<usize>         = Vec::new();
+        let mut groups:   Vec<Vec<String>>   = Vec::new();
+        let mut counter = 0;
+
+        for root in 0..n {
+            if index[root].is_some() {
+                continue;
+            }
+
+            // 以顯式堆疊模擬遞迴：(節點, 下一個要走訪的鄰居序號)
+            let mut call_stack: Vec<(usize, usize)> = vec![(root, 0)];
+            index[root] = Some(counter);
+            lowlink[root] = counter;
+            counter += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some(&mut (v, ref mut next)) = call_stack.last_mut() {
+                let neighbours: Vec<usize> = self.edges[names[v]]
+                    .iter()
+                    .filter_map(|d| position.get(d).copied())
+                    .collect();
+
+                if *next < neighbours.len() {
+                    let w = neighbours[*next];
+                    *next += 1;
+                    match index[w] {
+                        None => {
+                            index[w] = Some(counter);
+                            lowlink[w] = counter;
+                            counter += 1;
+                            stack.push(w);
+                            on_stack[w] = true;
+                            call_stack.push((w, 0));
+                        }
+                        Some(w_index) if on_stack[w] => {
+                            lowlink[v] = lowlink[v].min(w_index);
+                        }
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                if let Some(&(parent, _)) = call_stack.last() {
+                    lowlink[parent] = lowlink[parent].min(lowlink[v]);
+                }
+
+                if Some(lowlink[v]) == index[v] {
+                    let mut group = Vec::new();
+                    while let Some(w) = stack.pop() {
+                        on_stack[w] = false;
+                        group.push(names[w].clone());
+                        if w == v {
+                            break;
+                        }
+                    }
+                    group.sort();
+                    groups.push(group);
+                }
+            }
+        }
+
+        groups
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// PreparedCurve（校準前的每曲線中間資料）
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct PreparedCurve {
+    generator:      Arc<PiecewisePolyInterestRateCurveGenerator>,
+    node_dates:     Vec<NaiveDate>,
+    initial_values: Vec<f64>,
+    instruments:    Vec<Arc<dyn SimpleInstrument>>,
+    weights:        Vec<f64>,
+    referenced:     HashSet<String>,
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// MultiCurveCalibrator
+// ─────────────────────────────────────────────────────────────────────────────
+
+type NamedCurve = (String, Arc<dyn InterestRateCurve>);
+
+pub struct MultiCurveCalibrator {
+    config: LeastSquareCalibratorConfig,
+}
+
+impl MultiCurveCalibrator {
+    pub fn new(config: LeastSquareCalibratorConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn with_defaults() -> Self {
+        Self::new(LeastSquareCalibratorConfig::default())
+    }
+
+    pub fn config(&self) -> &LeastSquareCalibratorConfig { &self.config }
+
+    /// 產生單條曲線的校準商品、節點與初始猜測。
+    fn prepare(
+        spec:                 &CurveCalibrationSpec,
+        market_data_set:      &MarketDataSet,
+        generator_collection: &InterestRateInstrumentGeneratorCollection,
+        reference_date:       NaiveDate,
+        position:             Position,
+        horizon:              NaiveDate,
+    ) -> Result<PreparedCurve, CalibrationError> {
+        let helpers = generate_calibration_helpers(
+            spec.pillars(),
+            market_data_set.quote_book(),
+            generator_collection,
+            position,
+            horizon,
+        )?;
+
+        if helpers.is_empty() {
+            return Err(CalibrationError::CurveGeneration(format!(
+                "no calibration instruments provided for curve '{}'",
+                spec.curve_name(),
+            )));
+        }
+
+        let mut indexed: Vec<(f64, _)> = spec.pillars()
+            .iter()
+            .map(|p| p.weight())
+            .zip(helpers)
+            .collect();
+        if indexed.iter().any(|(w, _)| !w.is_finite() || *w <= 0.0) {
+            return Err(CalibrationError::CurveGeneration(format!(
+                "calibration weights of curve '{}' must be positive and finite",
+                spec.curve_name(),
+            )));
+        }
+        indexed.sort_by_key(|(_, h)| h.instrument().max_date());
+
+        let maturities: Vec<NaiveDate> = indexed
+            .iter()
+            .map(|(_, h)| h.instrument().max_date())
+            .collect();
+        let market_rates: Vec<f64> = indexed.iter().map(|(_, h)| h.market_rate()).collect();
+        let weights: Vec<f64> = indexed.iter().map(|(w, _)| *w).collect();
+        let instruments: Vec<Arc<dyn SimpleInstrument>> = indexed
+            .into_iter()
+            .map(|(_, h)| h.into_instrument())
+            .collect();
+
+        let referenced: HashSet<String> = instruments
+            .iter()
+            .flat_map(|instrument| instrument.curve_name_map().values().cloned())
+            .collect();
+
+        let generator = spec.generator().clone();
+        let mut node_dates = if generator.dates().is_empty() {
+            maturities.clone()
+        } else {
+            generator.dates().to_vec()
+        };
+        node_dates.sort();
+        node_dates.dedup();
+
+        let yfc = make_yfc(generator.day_counter_generator(), reference_date)?;
+        let bootstrapping_trait = BootstrappingTrait::new(generator.interpolation_target());
+
+        let last = maturities.len() - 1;
+        let initial_values = node_dates
+            .iter()
+            .map(|node_date| {
+                let idx = maturities
+                    .iter()
+                    .position(|d| d >= node_date)
+                    .unwrap_or(last);
+                bootstrapping_trait.initial_value(market_rates[idx], &yfc, *node_date)
+            })
+            .collect();
+
+        Ok(PreparedCurve {
+            generator,
+            node_dates,
+            initial_values,
+            instruments,
+            weights,
+            referenced,
+        })
+    }
+
+    /// 依相依順序校準所有曲線，並寫入 `market_data_set`。
+    ///
+    /// 回傳實際使用的校準分組（拓撲順序），供檢查耦合情形。
+    ///
+    /// # Errors
+    ///
+    /// - 曲線名稱重複
+    /// - 商品引用的曲線既不在 `specs` 中也不在 `market_data_set` 中
+    /// - 任一分量求解失敗
+    pub fn calibrate(
+        &self,
+        specs:                Vec<CurveCalibrationSpec>,
+        market_data_set:      &mut MarketDataSet,
+        generator_collection: &InterestRateInstrumentGeneratorCollection,
+        reference_date:       NaiveDate,
+        position:             Position,
+        horizon:              NaiveDate,
+    ) -> Result<Vec<Vec<String>>, CalibrationError> {
+        // 1. 準備每條曲線的商品與節點
+        let mut prepared: HashMap<String, PreparedCurve> = HashMap::new();
+        for spec in &specs {
+            if prepared.contains_key(spec.curve_name()) {
+                return Err(CalibrationError::CurveGeneration(format!(
+                    "curve '{}' specified more than once",
+                    spec.curve_name(),
+                )));
+            }
+            let curve = Self::prepare(
+                spec,
+                market_data_set,
+                generator_collection,
+                reference_date,
+                position,
+                horizon,
+            )?;
+            prepared.insert(spec.curve_name().to_string(), curve);
+        }
+
+        // 2. 外部曲線必須已存在
+        for curve in prepared.values() {
+            for name in &curve.referenced {
+                if !prepared.contains_key(name) && market_data_set.get_curve(name).is_none() {
+                    return Err(CalibrationError::MissingCurve(name.clone()));
+                }
+            }
+        }
+
+        // 3. 相依圖與校準順序
+        let dependencies: HashMap<String, HashSet<String>> = prepared
+            .iter()
+            .map(|(name, curve)| (name.clone(), curve.referenced.clone()))
+            .collect();
+        let groups = CurveDependencyGraph::new(&dependencies).calibration_groups();
+
+        // 4. 逐分量求解並寫回
+        let pricing_condition = calibration_pricing_condition(horizon);
+        for group in &groups {
+            let curves = self.calibrate_group(
+                group,
+                &prepared,
+                market_data_set,
+                reference_date,
+                &pricing_condition,
+            )?;
+            for (name, curve) in curves {
+                market_data_set.insert_curve(name, curve);
+            }
+        }
+
+        Ok(groups)
+    }
+
+    /// 聯合求解一個強連通分量內的所有曲線。
+    fn calibrate_group(
+        &self,
+        group:             &[String],
+        prepared:          &HashMap<String, PreparedCurve>,
+        market_data_set:   &MarketDataSet,
+        reference_date:    NaiveDate,
+        pricing_condition: &PricingCondition,
+    ) -> Result<Vec<NamedCurve>, CalibrationError> {
+        let members: Vec<(&String, &PreparedCurve)> = group
+            .iter()
+            .map(|name| (name, &prepared[name]))
+            .collect();
+
+        // 固定曲線：分量內商品引用、但不屬於本分量者（外部或先前已校準）
+        let mut fixed_curves: HashMap<String, Arc<dyn InterestRateCurve>> = HashMap::new();
+        for (_, curve) in &members {
+            for name in &curve.referenced {
+                if group.contains(name) {
+                    continue;
+                }
+                let fixed = market_data_set
+                    .get_curve(name)
+                    .ok_or_else(|| CalibrationError::MissingCurve(name.clone()))?;
+                fixed_curves.insert(name.clone(), fixed.clone());
+            }
+        }
+
+        // 參數向量：各曲線節點值依 group 順序堆疊
+        let mut offsets = Vec::with_capacity(members.len());
+        let mut initial_values = Vec::new();
+        let mut instruments = Vec::new();
+        let mut weights = Vec::new();
+        for (_, curve) in &members {
+            offsets.push(initial_values.len());
+            initial_values.extend_from_slice(&curve.initial_values);
+            instruments.extend(curve.instruments.iter().cloned());
+            weights.extend_from_slice(&curve.weights);
+        }
+        let n = initial_values.len();
+
+        if instruments.len() < n && !self.config.is_smoothed() {
+            return Err(CalibrationError::CurveGeneration(format!(
+                "curves {:?}: {} calibration instruments for {} curve nodes",
+                group, instruments.len(), n,
+            )));
+        }
+
+        let build_curves = |values: &DVector<f64>| -> Option<Vec<Arc<dyn InterestRateCurve>>> {
+            members
+                .iter()
+                .zip(&offsets)
+                .map(|((_, curve), offset)| {
+                    let segment = values.rows(*offset, curve.node_dates.len());
+                    curve.generator
+                        .generate_with_dates(reference_date, &curve.node_dates, segment.iter().cloned().collect())
+                        .ok()
+                })
+                .collect()
+        };
+
+        let market_builder = |values: &DVector<f64>| {
+            let curves = build_curves(values)?;
+            let mut market_data = fixed_curves.clone();
+            for ((name, _), curve) in members.iter().zip(curves) {
+                market_data.insert((*name).clone(), curve);
+            }
+            Some(market_data)
+        };
+
+        // 平滑懲罰：各曲線各自的二階差分，組成 block-diagonal
+        let blocks: Vec<(usize, DMatrix<f64>)> = members
+            .iter()
+            .zip(&offsets)
+            .filter_map(|((_, curve), offset)| {
+                second_difference_penalty(curve.node_dates.len(), self.config.smoothing_weight)
+                    .map(|p| (*offset, p))
+            })
+            .collect();
+        let penalty = if blocks.is_empty() {
+            None
+        } else {
+            let rows: usize = blocks.iter().map(|(_, p)| p.nrows()).sum();
+            let mut penalty = DMatrix::zeros(rows, n);
+            let mut row = 0;
+            for (offset, block) in blocks {
+                penalty
+                    .view_mut((row, offset), (block.nrows(), block.ncols()))
+                    .copy_from(&block);
+                row += block.nrows();
+            }
+            Some(penalty)
+        };
+
+        let solution = solve_least_square(
+            &instruments,
+            &weights,
+            DVector::from_vec(initial_values),
+            &market_builder,
+            pricing_condition,
+            penalty,
+            &self.config,
+        )
+        .map_err(|e| CalibrationError::CurveGeneration(
+            format!("curves {:?}: {}", group, e)
+        ))?;
+
+        let curves = build_curves(&solution.values).ok_or_else(|| {
+            CalibrationError::CurveGeneration(format!(
+                "curves {:?}: final curve generation failed",
+                group,
+            ))
+        })?;
+
+        Ok(group.iter().cloned().zip(curves).collect())
+    }
+}
@@ -0,0 +1,219 @@
+// ── parametricinterestratecurve.rs ────────────────────────────────────────────
+//
+// 以參數型 zero rate 函數（Nelson-Siegel / Svensson）定義的利率曲線。
+//
+// # 數學定義
+//
+//   R(t) = 參數型函數（見 math::curve::parametriccurve）
+//   D(t) = exp(−R(t) × t)
+//   f(t) = d/dt [R(t) × t] = R(t) + t × R'(t)
+//
+// 曲線定義於所有 t ≥ 0，沒有 pillar，也沒有外插設定。
+//
+// # Generator
+//
+// `ParametricInterestRateCurveGenerator::generate()` 的 `values` 即參數向量，
+// 順序見各參數型曲線的 `from_parameters()`；
+// `generate_with_dates()` 沿用 trait 預設（忽略 dates）。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::math::curve::curve::{DerivativeCurve, ValueCurve};
+use crate::math::curve::parametriccurve::nelsonsiegel::NelsonSiegel;
+use crate::math::curve::parametriccurve::parametriccurve::ParametricCurve;
+use crate::math::curve::parametriccurve::svensson::Svensson;
+use crate::model::interestrate::curvegenerationerror::CurveGenerationError;
+use crate::model::interestrate::interestratecurve::{
+    DiscountCurve, InstForwardCurve, InterestRateCurve, InterestRateCurveGenerator,
+    YearFractionCalculator, ZeroRateCurve,
+};
+use crate::time::daycounter::daycounter::DayCounterGenerator;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ParametricCurveType
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParametricCurveType {
+    NelsonSiegel,
+    Svensson,
+}
+
+impl ParametricCurveType {
+    pub fn parameter_count(&self) -> usize {
+        match self {
+            ParametricCurveType::NelsonSiegel => NelsonSiegel::PARAMETER_COUNT,
+            ParametricCurveType::Svensson     => Svensson::PARAMETER_COUNT,
+        }
+    }
+
+    /// 依參數向量建立對應的 math curve。
+    pub fn build(
+        &self,
+        parameters: &[f64],
+    ) -> Result<Arc<dyn ParametricCurve>, CurveGenerationError> {
+        if parameters.len() != self.parameter_count() {
+            return Err(CurveGenerationError::WrongParameterCount {
+                expected: self.parameter_count(),
+                provided: parameters.len(),
+            });
+        }
+
+        let curve: Option<Arc<dyn ParametricCurve>> = match self {
+            ParametricCurveType::NelsonSiegel =>
+                NelsonSiegel::from_parameters(parameters).map(|c| Arc::new(c) as _),
+            ParametricCurveType::Svensson =>
+                Svensson::from_parameters(parameters).map(|c| Arc::new(c) as _),
+        };
+
+        curve.ok_or_else(|| CurveGenerationError::InvalidParameters(format!(
+            "{:?} requires finite parameters and positive decay scales, got {:?}",
+            self, parameters,
+        )))
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ParametricCurveInner
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct ParametricCurveInner {
+    yfc:         YearFractionCalculator,
+    curve:       Arc<dyn ParametricCurve>,
+    value_curve: Arc<dyn ValueCurve>,
+    deriv_curve: Arc<dyn DerivativeCurve>,
+}
+
+impl ParametricCurveInner {
+    fn discount_at(&self, t: f64) -> f64 {
+        (-self.value_curve.value(t) * t).exp()
+    }
+
+    fn zero_rate_at(&self, t: f64) -> f64 {
+        self.value_curve.value(t)
+    }
+
+    fn inst_forward_at(&self, t: f64) -> f64 {
+        self.value_curve.value(t) + t * self.deriv_curve.derivative(t)
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Wrapper structs
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct ParametricDiscountCurve(Arc<ParametricCurveInner>);
+pub struct ParametricZeroRateCurve(Arc<ParametricCurveInner>);
+pub struct ParametricInstForwardCurve(Arc<ParametricCurveInner>);
+
+impl DiscountCurve for ParametricDiscountCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.0.yfc }
+    fn discount(&self, d: NaiveDate) -> f64 {
+        if d == self.0.yfc.reference_date() { return 1.0; }
+        self.0.discount_at(self.year_fraction(d))
+    }
+}
+
+impl ZeroRateCurve for ParametricZeroRateCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.0.yfc }
+    fn zero_rate(&self, d: NaiveDate) -> f64 {
+        self.0.zero_rate_at(self.year_fraction(d))
+    }
+}
+
+impl InstForwardCurve for ParametricInstForwardCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.0.yfc }
+    fn inst_forward(&self, d: NaiveDate) -> f64 {
+        self.0.inst_forward_at(self.year_fraction(d))
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ParametricInterestRateCurve
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct ParametricInterestRateCurve {
+    inner: Arc<ParametricCurveInner>,
+}
+
+impl ParametricInterestRateCurve {
+    pub fn new(yfc: YearFractionCalculator, curve: Arc<dyn ParametricCurve>) -> Self {
+        let value_curve = curve.to_value_curve();
+        let deriv_curve = curve.to_derivative_curve();
+        Self {
+            inner: Arc::new(ParametricCurveInner {
+                yfc,
+                curve,
+                value_curve,
+                deriv_curve,
+            }),
+        }
+    }
+
+    pub fn parametric_curve(&self) -> &Arc<dyn ParametricCurve> { &self.inner.curve }
+    pub fn parameters(&self) -> Vec<f64> { self.inner.curve.parameters() }
+}
+
+impl InterestRateCurve for ParametricInterestRateCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.inner.yfc }
+
+    fn to_discount_curve(&self) -> Arc<dyn DiscountCurve> {
+        Arc::new(ParametricDiscountCurve(Arc::clone(&self.inner)))
+    }
+
+    fn to_zero_rate_curve(&self) -> Arc<dyn ZeroRateCurve> {
+        Arc::new(ParametricZeroRateCurve(Arc::clone(&self.inner)))
+    }
+
+    fn to_inst_forward_curve(&self) -> Arc<dyn InstForwardCurve> {
+        Arc::new(ParametricInstForwardCurve(Arc::clone(&self.inner)))
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ParametricInterestRateCurveGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct ParametricInterestRateCurveGenerator {
+    day_counter_generator: Arc<DayCounterGenerator>,
+    curve_type:            ParametricCurveType,
+}
+
+impl ParametricInterestRateCurveGenerator {
+    pub fn new(
+        day_counter_generator: Arc<DayCounterGenerator>,
+        curve_type:            ParametricCurveType,
+    ) -> Self {
+        Self { day_counter_generator, curve_type }
+    }
+
+    pub fn curve_type(&self) -> ParametricCurveType { self.curve_type }
+    pub fn parameter_count(&self) -> usize { self.curve_type.parameter_count() }
+    pub fn day_counter_generator(&self) -> &Arc<DayCounterGenerator> { &self.day_counter_generator }
+}
+
+impl InterestRateCurveGenerator for ParametricInterestRateCurveGenerator {
+    fn generate(
+        &self,
+        reference_date: NaiveDate,
+        values:         Vec<f64>,
+    ) -> Result<Arc<dyn InterestRateCurve>, CurveGenerationError> {
+        let curve = self.curve_type.build(&values)?;
+
+        let day_counter = self.day_counter_generator
+            .generate(None)
+            .map_err(|e| CurveGenerationError::DayCounterGeneration(e.to_string()))?;
+
+        let yfc = YearFractionCalculator::new(reference_date, Arc::new(day_counter));
+
+        Ok(Arc::new(ParametricInterestRateCurve::new(yfc, curve)))
+    }
+}
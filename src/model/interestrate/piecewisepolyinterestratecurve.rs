@@ -127,7 +127,10 @@ impl PiecewiseCurveInner {
             InterpolationTarget::ZeroRate =>
                 (-self.value_curve.value(t) * t).exp(),
             InterpolationTarget::InstantaneousForwardRate =>
-                (-self.integral_curve().integral(0.0, t)).exp(),
+                (-PiecewisePolyInterestRateCurve::forward_integral(
+                    self.integral_curve(), self.polynomial.polynomial_type(), t, self.min_t,
+                    self.left_extrapolation, self.left_anchor_inst_forward,
+                )).exp(),
         }
     }
 
@@ -211,6 +214,7 @@ impl PiecewisePolyInterestRateCurve {
         );
         let right_anchor_discount = Self::compute_discount(
             &interpolation_target, max_t, &value_curve, &polynomial,
+            left_extrapolation, left_anchor_inst_forward,
         );
 
         Self {
@@ -235,10 +239,12 @@ impl PiecewisePolyInterestRateCurve {
     /// 計算指定 t 處的 discount，用於建構時的錨點計算。
     /// 按需臨時建立所需的 math curve，不儲存。
     fn compute_discount(
-        target:      &InterpolationTarget,
-        t:           f64,
-        value_curve: &Arc<dyn ValueCurve>,
-        polynomial:  &PiecewisePolynomial,
+        target:                   &InterpolationTarget,
+        t:                        f64,
+        value_curve:              &Arc<dyn ValueCurve>,
+        polynomial:               &PiecewisePolynomial,
+        left_extrapolation:       ExtrapolationMethod,
+        left_anchor_inst_forward: f64,
     ) -> f64 {
         match target {
             InterpolationTarget::LogDiscount =>
@@ -246,7 +252,34 @@ impl PiecewisePolyInterestRateCurve {
             InterpolationTarget::ZeroRate =>
                 (-value_curve.value(t) * t).exp(),
             InterpolationTarget::InstantaneousForwardRate =>
-                (-polynomial.to_integral_curve().integral(0.0, t)).exp(),
+                (-Self::forward_integral(
+                    &polynomial.to_integral_curve(), polynomial.polynomial_type(), t, polynomial.min_x(),
+                    left_extrapolation, left_anchor_inst_forward,
+                )).exp(),
+        }
+    }
+
+    /// ∫₀ᵗ f（InstantaneousForwardRate 目標），t ≥ min_t。
+    ///
+    /// MonotoneConvex 的 [0, min_t] 依左外插方式計算：FlatForwardRate 為錨點常數，
+    /// Default 為左端的平坦延伸，確保與 `discount_at` 的左側分支連續。
+    /// 其餘型別維持 integral(0, t)（積分截斷在 min_t）。
+    fn forward_integral(
+        integral_curve:           &Arc<dyn CurveIntegral>,
+        polynomial_type:          PolynomialType,
+        t:                        f64,
+        min_t:                    f64,
+        left_extrapolation:       ExtrapolationMethod,
+        left_anchor_inst_forward: f64,
+    ) -> f64 {
+        if polynomial_type != PolynomialType::MonotoneConvex {
+            return integral_curve.integral(0.0, t);
+        }
+        match left_extrapolation {
+            ExtrapolationMethod::FlatForwardRate =>
+                left_anchor_inst_forward * min_t + integral_curve.integral(min_t, t),
+            ExtrapolationMethod::Default =>
+                integral_curve.integral(0.0, t),
         }
     }
 
@@ -303,6 +336,10 @@ impl InterestRateCurve for PiecewisePolyInterestRateCurve {
 //
 // `generate()`（trait）：用已設好的 dates + 傳入的 reference_date。
 // `generate_with_dates()`：臨時傳入 dates，供 calibrator 直接使用。
+//
+// PolynomialType::MonotoneConvex 只接受 InstantaneousForwardRate：
+// values[0] 為第一個 pillar 的瞬時遠期，values[i]（i ≥ 1）為 [dates[i-1], dates[i]]
+// 的平均遠期，因此 D(dates[i]) 只由 values[..=i] 決定。
 
 pub struct PiecewisePolyInterestRateCurveGenerator {
     day_counter_generator: Arc<DayCounterGenerator>,
@@ -357,6 +394,14 @@ impl PiecewisePolyInterestRateCurveGenerator {
             });
         }
 
+        if self.polynomial_type == PolynomialType::MonotoneConvex
+            && self.interpolation_target != InterpolationTarget::InstantaneousForwardRate
+        {
+            return Err(CurveGenerationError::InvalidParameters(
+                "MonotoneConvex requires InterpolationTarget::InstantaneousForwardRate".to_string()
+            ));
+        }
+
         let day_counter = self.day_counter_generator
             .generate(None)
             .map_err(|e| CurveGenerationError::DayCounterGeneration(e.to_string()))?;
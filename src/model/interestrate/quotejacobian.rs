@@ -0,0 +1,341 @@
+// ── quotejacobian.rs ──────────────────────────────────────────────────────────
+//
+// 報價對曲線節點的 Jacobian（d pillar value / d quote）與報價敏感度。
+//
+// # 設計說明
+//
+// 風險需要的是對市場報價的 delta，而非對曲線內部節點值的 delta。
+// 若以「逐一擾動報價 → 重新校準整組曲線」計算，需要 N 次完整校準。
+// 本模組改用隱函數定理，只需在校準解上計算一次：
+//
+//   校準條件（加權最小平方的一階條件，精確擬合為其特例）：
+//     JᵀS²F(v, q) + PᵀPv = 0
+//
+//   對 q 微分（Gauss-Newton 近似，殘差 ≈ 0 時為精確）：
+//     dv/dq = −(JᵀS²J + PᵀP)⁻¹ JᵀS² G
+//
+//   其中
+//     F_i = 第 i 個校準商品的 NPV
+//     J   = ∂F/∂v（前向差分）
+//     S   = 殘差列縮放（精確擬合時為單位矩陣）
+//     P   = 平滑懲罰矩陣（無平滑時為 0）
+//     G   = ∂F/∂q = diag(g)，g_i 為商品 i 對自身報價的 NPV 敏感度，
+//           以 `generate_calibration_helper_with_quote` 重新產生商品後差分取得
+//
+// 商品數等於節點數且無平滑時，上式退化為 dv/dq = −J⁻¹G。
+//
+// # 報價敏感度
+//
+// 給定某商品（或投組）對曲線節點值的敏感度 ∂V/∂v，
+//   ∂V/∂q_k = Σ_j ∂V/∂v_j · ∂v_j/∂q_k
+// 結果依 quote sheet 名稱與 sheet key 分組回傳。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use nalgebra::{DMatrix, DVector};
+
+use crate::instrument::instrument::SimpleInstrument;
+use crate::marketdata::interestrate::interestratequotesheet::InterestRateQuoteSheet;
+use crate::model::interestrate::interestratecurve::{
+    InterestRateCurve,
+    InterestRateCurveGenerator,
+};
+use crate::model::interestrate::interestratecurvecalibrator::{
+    CalibrationEnvironment,
+    CalibrationError,
+    InterestRateCurvePillar,
+    resolve_quote_key,
+};
+use crate::model::interestrate::leastsquarecalibrator::MarketBuilder;
+use crate::pricer::pricer::Pricer;
+use crate::pricer::simpleinstrumentpricer::SimpleInstrumentPricer;
+use crate::pricingcondition::PricingCondition;
+
+
+/// 計算 g_i = ∂NPV_i/∂q_i 時的報價擾動量（報價單位，1bp）。
+const QUOTE_BUMP: f64 = 1e-4;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// QuoteKey
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 唯一識別一個市場報價：quote sheet 名稱 + sheet 內的 key。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuoteKey {
+    sheet_name: String,
+    quote_key:  String,
+}
+
+impl QuoteKey {
+    pub fn new(sheet_name: impl Into<String>, quote_key: impl Into<String>) -> Self {
+        Self { sheet_name: sheet_name.into(), quote_key: quote_key.into() }
+    }
+
+    pub fn sheet_name(&self) -> &str { &self.sheet_name }
+    pub fn quote_key(&self) -> &str { &self.quote_key }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// QuoteJacobian
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 校準結果的 d(node value)/d(quote)。
+///
+/// - 列（row）：曲線節點，與 `node_dates` 一一對應
+/// - 行（column）：報價，與校準時傳入的 `InterestRateCurvePillar` 順序一致
+pub struct QuoteJacobian {
+    node_dates:  Vec<NaiveDate>,
+    node_values: Vec<f64>,
+    quotes:      Vec<QuoteKey>,
+    matrix:      DMatrix<f64>,
+}
+
+impl QuoteJacobian {
+    pub fn new(
+        node_dates:  Vec<NaiveDate>,
+        node_values: Vec<f64>,
+        quotes:      Vec<QuoteKey>,
+        matrix:      DMatrix<f64>,
+    ) -> Result<Self, CalibrationError> {
+        if node_values.len() != node_dates.len() {
+            return Err(CalibrationError::SensitivityDimension {
+                expected: node_dates.len(),
+                provided: node_values.len(),
+            });
+        }
+        if matrix.nrows() != node_dates.len() || matrix.ncols() != quotes.len() {
+            return Err(CalibrationError::SensitivityDimension {
+                expected: node_dates.len() * quotes.len(),
+                provided: matrix.nrows() * matrix.ncols(),
+            });
+        }
+        Ok(Self { node_dates, node_values, quotes, matrix })
+    }
+
+    pub fn node_dates(&self) -> &[NaiveDate] { &self.node_dates }
+    pub fn node_values(&self) -> &[f64] { &self.node_values }
+    pub fn quotes(&self) -> &[QuoteKey] { &self.quotes }
+    pub fn matrix(&self) -> &DMatrix<f64> { &self.matrix }
+
+    /// 第 `pillar_index` 個 pillar 的報價對所有節點值的偏微分 ∂v/∂q。
+    pub fn pillar_column(&self, pillar_index: usize) -> Option<Vec<f64>> {
+        (pillar_index < self.matrix.ncols())
+            .then(|| self.matrix.column(pillar_index).iter().cloned().collect())
+    }
+
+    /// 將對節點值的敏感度 ∂V/∂v 轉換為對各報價的敏感度 ∂V/∂q。
+    ///
+    /// 回傳 sheet 名稱 → (sheet key → 敏感度)。敏感度以「每單位報價」表示，
+    /// 乘上 1e-4 即為報價 1bp 的 PV 變動。
+    pub fn quote_sensitivities(
+        &self,
+        pillar_sensitivities: &[f64],
+    ) -> Result<HashMap<String, HashMap<String, f64>>, CalibrationError> {
+        if pillar_sensitivities.len() != self.node_dates.len() {
+            return Err(CalibrationError::SensitivityDimension {
+                expected: self.node_dates.len(),
+                provided: pillar_sensitivities.len(),
+            });
+        }
+
+        let dv_dnode = DVector::from_column_slice(pillar_sensitivities);
+        let dv_dquote = self.matrix.transpose() * dv_dnode;
+
+        let mut result: HashMap<String, HashMap<String, f64>> = HashMap::new();
+        for (quote, sensitivity) in self.quotes.iter().zip(dv_dquote.iter()) {
+            *result
+                .entry(quote.sheet_name.clone())
+                .or_default()
+                .entry(quote.quote_key.clone())
+                .or_insert(0.0) += sensitivity;
+        }
+        Ok(result)
+    }
+
+    /// 以前向差分計算任意評價函數對節點值的敏感度 ∂V/∂v。
+    ///
+    /// `curve_generator` 必須與產生此 Jacobian 的校準使用同一個 generator；
+    /// `value` 回傳 None 表示定價失敗。
+    pub fn pillar_sensitivities<F>(
+        &self,
+        curve_generator: &dyn InterestRateCurveGenerator,
+        reference_date:  NaiveDate,
+        bump:            f64,
+        value:           F,
+    ) -> Result<Vec<f64>, CalibrationError>
+    where
+        F: Fn(&Arc<dyn InterestRateCurve>) -> Option<f64>,
+    {
+        let evaluate = |values: Vec<f64>| -> Result<f64, CalibrationError> {
+            let curve = curve_generator
+                .generate_with_dates(reference_date, &self.node_dates, values)
+                .map_err(|e| CalibrationError::CurveGeneration(e.to_string()))?;
+            value(&curve).ok_or_else(|| CalibrationError::CurveGeneration(
+                "valuation failed during pillar sensitivity".to_string()
+            ))
+        };
+
+        let base = evaluate(self.node_values.clone())?;
+        (0..self.node_values.len())
+            .map(|j| {
+                let mut bumped = self.node_values.clone();
+                bumped[j] += bump;
+                Ok((evaluate(bumped)? - base) / bump)
+            })
+            .collect()
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// QuoteSource / QuoteJacobianProblem（校準器內部使用）
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 校準商品的報價來源，與校準器內部排序後的商品一一對應。
+pub(crate) struct QuoteSource<'a> {
+    sheet:        &'a InterestRateQuoteSheet,
+    quote_key:    QuoteKey,
+    quote:        f64,
+    /// 在原始 pillars 中的位置，決定 Jacobian 的 column。
+    pillar_index: usize,
+}
+
+/// 依排序後的商品順序（`order[k]` = 第 k 個商品對應的 pillar 位置）
+/// 建立報價來源。
+pub(crate) fn quote_sources<'a>(
+    pillars:    &[InterestRateCurvePillar],
+    order:      &[usize],
+    quote_book: &'a HashMap<String, InterestRateQuoteSheet>,
+) -> Result<Vec<QuoteSource<'a>>, CalibrationError> {
+    order
+        .iter()
+        .map(|&pillar_index| {
+            let pillar = &pillars[pillar_index];
+            let sheet_name = pillar.quote_generator_name();
+            let sheet = quote_book
+                .get(sheet_name)
+                .ok_or_else(|| CalibrationError::SheetNotFound(sheet_name.clone()))?;
+            let key = resolve_quote_key(pillar, sheet)?;
+            let quote = *sheet.get_quote(&key).ok_or_else(|| {
+                CalibrationError::CurveGeneration(format!(
+                    "quote '{}' not found in sheet '{}'", key, sheet_name,
+                ))
+            })?;
+            Ok(QuoteSource {
+                sheet,
+                quote_key: QuoteKey::new(sheet_name.clone(), key),
+                quote,
+                pillar_index,
+            })
+        })
+        .collect()
+}
+
+/// 隱函數定理求 dv/dq 所需的全部輸入。
+pub(crate) struct QuoteJacobianProblem<'a> {
+    pub(crate) instruments:       &'a [Arc<dyn SimpleInstrument>],
+    pub(crate) sources:           &'a [QuoteSource<'a>],
+    /// 殘差列縮放 S；精確擬合傳入全 1。
+    pub(crate) row_scales:        &'a [f64],
+    pub(crate) penalty:           Option<&'a DMatrix<f64>>,
+    pub(crate) market_builder:    &'a MarketBuilder<'a>,
+    pub(crate) pricing_condition: &'a PricingCondition,
+    pub(crate) environment:       &'a CalibrationEnvironment<'a>,
+    pub(crate) fd_step:           f64,
+}
+
+impl QuoteJacobianProblem<'_> {
+    fn npvs(
+        &self,
+        instruments: &[Arc<dyn SimpleInstrument>],
+        market_data: &HashMap<String, Arc<dyn InterestRateCurve>>,
+    ) -> Result<DVector<f64>, CalibrationError> {
+        let pricer = SimpleInstrumentPricer;
+        let npvs: Option<Vec<f64>> = instruments
+            .iter()
+            .map(|instrument| {
+                pricer
+                    .market_value(instrument.as_ref(), market_data, self.pricing_condition)
+                    .map(|npv| npv.amount())
+            })
+            .collect();
+        npvs.map(DVector::from_vec).ok_or_else(|| CalibrationError::CurveGeneration(
+            "pricing failed during quote jacobian".to_string()
+        ))
+    }
+
+    fn market(&self, values: &DVector<f64>) -> Result<HashMap<String, Arc<dyn InterestRateCurve>>, CalibrationError> {
+        (self.market_builder)(values).ok_or_else(|| CalibrationError::CurveGeneration(
+            "curve generation failed during quote jacobian".to_string()
+        ))
+    }
+
+    /// 在校準解 `node_values` 上計算 dv/dq。
+    pub(crate) fn solve(
+        &self,
+        node_dates:  Vec<NaiveDate>,
+        node_values: &DVector<f64>,
+    ) -> Result<QuoteJacobian, CalibrationError> {
+        let m = self.instruments.len();
+        let n = node_values.len();
+
+        // F 與 J = ∂F/∂v
+        let base_market = self.market(node_values)?;
+        let base_npvs = self.npvs(self.instruments, &base_market)?;
+
+        let mut jacobian = DMatrix::zeros(m, n);
+        for j in 0..n {
+            let mut bumped = node_values.clone();
+            bumped[j] += self.fd_step;
+            let bumped_npvs = self.npvs(self.instruments, &self.market(&bumped)?)?;
+            jacobian.set_column(j, &((bumped_npvs - &base_npvs) / self.fd_step));
+        }
+
+        // g_i = ∂F_i/∂q_i：以擾動後報價重新產生商品，在同一條曲線上定價
+        let mut quote_derivatives = DVector::zeros(m);
+        for (i, source) in self.sources.iter().enumerate() {
+            let bumped_instrument = source.sheet
+                .generate_calibration_helper_with_quote(
+                    source.quote_key.quote_key(),
+                    source.quote + QUOTE_BUMP,
+                    self.environment.position,
+                    self.environment.horizon,
+                    self.environment.generator_collection,
+                )?
+                .into_instrument();
+            let bumped_npv = self.npvs(&[bumped_instrument], &base_market)?[0];
+            quote_derivatives[i] = (bumped_npv - base_npvs[i]) / QUOTE_BUMP;
+        }
+
+        // dv/dq = −(JᵀS²J + PᵀP)⁻¹ JᵀS² G
+        let scales = DVector::from_column_slice(self.row_scales);
+        let scaled_jacobian = DMatrix::from_diagonal(&scales) * &jacobian;
+        let mut normal_matrix = scaled_jacobian.transpose() * &scaled_jacobian;
+        if let Some(p) = self.penalty {
+            normal_matrix += p.transpose() * p;
+        }
+        let rhs = -(scaled_jacobian.transpose()
+            * DMatrix::from_diagonal(&scales.component_mul(&quote_derivatives)));
+
+        let sorted_columns = normal_matrix
+            .lu()
+            .solve(&rhs)
+            .ok_or_else(|| CalibrationError::SingularJacobian(
+                "normal matrix JᵀS²J + PᵀP is not invertible".to_string()
+            ))?;
+
+        // column 依原始 pillar 順序重排
+        let mut matrix = DMatrix::zeros(n, m);
+        let mut quotes = vec![QuoteKey::new("", ""); m];
+        for (k, source) in self.sources.iter().enumerate() {
+            matrix.set_column(source.pillar_index, &sorted_columns.column(k));
+            quotes[source.pillar_index] = source.quote_key.clone();
+        }
+
+        QuoteJacobian::new(node_dates, node_values.iter().cloned().collect(), quotes, matrix)
+    }
+}
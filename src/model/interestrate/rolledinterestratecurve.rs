@@ -0,0 +1,255 @@
+// ── rolledinterestratecurve.rs ────────────────────────────────────────────────
+//
+// 將已校準曲線「移動」到未來的 horizon date（forward-dated / rolled curve），
+// 供 theta、carry 與 roll-down 報表在 horizon 重估商品。
+//
+// # 兩種模式
+//
+// 令 r = base reference date、h = horizon、τ_h(d) = 以 h 為起點的年化時間。
+//
+// | RollMode          | D_h(d)                       | R_h(d)     | f_h(d)   |
+// |-------------------|------------------------------|------------|----------|
+// | ForwardsRealized  | D(d) / D(h)                  | −ln D_h/τ_h| f(d)     |
+// | ConstantZeroShape | exp(−R(d′) τ_h(d))           | R(d′)      | f(d′)    |
+//
+// 其中 d′ = r + (d − h)（以日曆日平移）。
+//
+//   - ForwardsRealized：今日隱含的遠期利率如期實現，同一絕對日期的遠期利率不變，
+//     carry 報表使用此模式。
+//   - ConstantZeroShape：以剩餘天期表示的 zero rate 曲線形狀不變（roll-down），
+//     商品隨時間「滑」到曲線較短端。
+//
+// ConstantZeroShape 下 discount / zero 一致；forward 取 f(d′)，
+// 在 actual 類 day counter 下與 discount 一致。
+//
+// # 使用方式
+//
+// 新曲線的 YearFractionCalculator reference date 為 horizon，day counter 沿用
+// base curve。以 `roll_market_data` 移動整組 market data 後，
+// 搭配 horizon = h 的 PricingCondition 即可用 SimpleInstrumentPricer 重估。
+// 每次回傳新的 Arc，CachedInterestRateIndex 不會誤用 base curve 的 cache。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{NaiveDate, TimeDelta};
+use thiserror::Error;
+
+use crate::model::interestrate::interestratecurve::{
+    DiscountCurve, InstForwardCurve, InterestRateCurve, YearFractionCalculator, ZeroRateCurve,
+    ZERO_TIME_EPSILON,
+};
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CurveRollError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum CurveRollError {
+    #[error("horizon {horizon} is before curve reference date {reference_date}")]
+    HorizonBeforeReference {
+        horizon:        NaiveDate,
+        reference_date: NaiveDate,
+    },
+
+    #[error("discount factor at horizon {0} must be positive")]
+    NonPositiveHorizonDiscount(NaiveDate),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// RollMode
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollMode {
+    /// 遠期利率如期實現：D_h(d) = D(d) / D(h)。
+    ForwardsRealized,
+    /// 以剩餘天期表示的 zero rate 形狀不變（roll-down）。
+    ConstantZeroShape,
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Sub-curve implementations
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct RolledDiscountCurve {
+    yfc:              YearFractionCalculator,
+    mode:             RollMode,
+    shift:            TimeDelta,
+    horizon_discount: f64,
+    base_discount:    Arc<dyn DiscountCurve>,
+    base_zero:        Arc<dyn ZeroRateCurve>,
+}
+
+impl DiscountCurve for RolledDiscountCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn discount(&self, d: NaiveDate) -> f64 {
+        match self.mode {
+            RollMode::ForwardsRealized => self.base_discount.discount(d) / self.horizon_discount,
+            RollMode::ConstantZeroShape => {
+                let t = self.yfc.year_fraction(d);
+                if t.abs() < ZERO_TIME_EPSILON {
+                    return 1.0;
+                }
+                (-self.base_zero.zero_rate(d - self.shift) * t).exp()
+            }
+        }
+    }
+}
+
+
+struct RolledZeroRateCurve {
+    yfc:      YearFractionCalculator,
+    mode:     RollMode,
+    shift:    TimeDelta,
+    discount: RolledDiscountCurve,
+    forward:  RolledInstForwardCurve,
+}
+
+impl ZeroRateCurve for RolledZeroRateCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn zero_rate(&self, d: NaiveDate) -> f64 {
+        let t = self.yfc.year_fraction(d);
+        if t.abs() < ZERO_TIME_EPSILON {
+            return self.forward.inst_forward(d);
+        }
+        match self.mode {
+            RollMode::ForwardsRealized  => -self.discount.discount(d).ln() / t,
+            RollMode::ConstantZeroShape => self.discount.base_zero.zero_rate(d - self.shift),
+        }
+    }
+}
+
+
+struct RolledInstForwardCurve {
+    yfc:   YearFractionCalculator,
+    shift: TimeDelta,
+    base:  Arc<dyn InstForwardCurve>,
+}
+
+impl InstForwardCurve for RolledInstForwardCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn inst_forward(&self, d: NaiveDate) -> f64 {
+        self.base.inst_forward(d - self.shift)
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// RolledInterestRateCurve
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct RolledInterestRateCurve {
+    yfc:              YearFractionCalculator,
+    base:             Arc<dyn InterestRateCurve>,
+    mode:             RollMode,
+    horizon_discount: f64,
+}
+
+impl RolledInterestRateCurve {
+    /// # Errors
+    ///
+    /// - horizon 早於 base curve 的 reference date
+    /// - ForwardsRealized 下 D(horizon) ≤ 0
+    pub fn new(
+        base:    Arc<dyn InterestRateCurve>,
+        horizon: NaiveDate,
+        mode:    RollMode,
+    ) -> Result<Self, CurveRollError> {
+        let reference_date = base.reference_date();
+        if horizon < reference_date {
+            return Err(CurveRollError::HorizonBeforeReference { horizon, reference_date });
+        }
+        let horizon_discount = base.to_discount_curve().discount(horizon);
+        if mode == RollMode::ForwardsRealized && (horizon_discount <= 0.0 || horizon_discount.is_nan()) {
+            return Err(CurveRollError::NonPositiveHorizonDiscount(horizon));
+        }
+        Ok(Self {
+            yfc: YearFractionCalculator::new(horizon, base.day_counter().clone()),
+            base,
+            mode,
+            horizon_discount,
+        })
+    }
+
+    pub fn base_curve(&self) -> &Arc<dyn InterestRateCurve> { &self.base }
+    pub fn mode(&self) -> RollMode { self.mode }
+    pub fn horizon(&self) -> NaiveDate { self.yfc.reference_date() }
+
+    /// ConstantZeroShape 的日期平移量 h − r；ForwardsRealized 為 0。
+    fn shift(&self) -> TimeDelta {
+        match self.mode {
+            RollMode::ForwardsRealized  => TimeDelta::zero(),
+            RollMode::ConstantZeroShape => self.horizon() - self.base.reference_date(),
+        }
+    }
+
+    fn discount_curve(&self) -> RolledDiscountCurve {
+        RolledDiscountCurve {
+            yfc:              self.yfc.clone(),
+            mode:             self.mode,
+            shift:            self.shift(),
+            horizon_discount: self.horizon_discount,
+            base_discount:    self.base.to_discount_curve(),
+            base_zero:        self.base.to_zero_rate_curve(),
+        }
+    }
+
+    fn inst_forward_curve(&self) -> RolledInstForwardCurve {
+        RolledInstForwardCurve {
+            yfc:   self.yfc.clone(),
+            shift: self.shift(),
+            base:  self.base.to_inst_forward_curve(),
+        }
+    }
+}
+
+impl InterestRateCurve for RolledInterestRateCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn to_discount_curve(&self) -> Arc<dyn DiscountCurve> {
+        Arc::new(self.discount_curve())
+    }
+
+    fn to_zero_rate_curve(&self) -> Arc<dyn ZeroRateCurve> {
+        Arc::new(RolledZeroRateCurve {
+            yfc:      self.yfc.clone(),
+            mode:     self.mode,
+            shift:    self.shift(),
+            discount: self.discount_curve(),
+            forward:  self.inst_forward_curve(),
+        })
+    }
+
+    fn to_inst_forward_curve(&self) -> Arc<dyn InstForwardCurve> {
+        Arc::new(self.inst_forward_curve())
+    }
+}
+
+
+/// 回傳以 `horizon` 為 reference date 的新曲線（新的 Arc identity）。
+pub fn roll_curve(
+    curve:   &Arc<dyn InterestRateCurve>,
+    horizon: NaiveDate,
+    mode:    RollMode,
+) -> Result<Arc<dyn InterestRateCurve>, CurveRollError> {
+    Ok(Arc::new(RolledInterestRateCurve::new(curve.clone(), horizon, mode)?))
+}
+
+/// 將整組定價用 market data（curve name → curve）移動到 `horizon`。
+pub fn roll_market_data(
+    market_data: &HashMap<String, Arc<dyn InterestRateCurve>>,
+    horizon:     NaiveDate,
+    mode:        RollMode,
+) -> Result<HashMap<String, Arc<dyn InterestRateCurve>>, CurveRollError> {
+    market_data
+        .iter()
+        .map(|(name, curve)| Ok((name.clone(), roll_curve(curve, horizon, mode)?)))
+        .collect()
+}
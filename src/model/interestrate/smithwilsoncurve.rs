@@ -0,0 +1,478 @@
+// ── smithwilsoncurve.rs ───────────────────────────────────────────────────────
+//
+// Smith-Wilson 外插曲線（EIOPA Solvency II / IFRS 17 慣例）。
+//
+// # 數學定義
+//
+// 給定流動點（liquid points）u₁ < … < uₙ ≤ LLP 及其 zero rate rⱼ，
+// 目標折現因子 pⱼ = exp(−rⱼ uⱼ)。曲線為
+//
+//   P(t) = e^{−ωt} + Σⱼ ζⱼ W(t, uⱼ)
+//
+// Wilson kernel（m = min(t, u)，M = max(t, u)）：
+//
+//   W(t, u) = e^{−ω(t+u)} [α m − e^{−αM} sinh(α m)]
+//
+// ζ 由 n × n 線性系統 W ζ = p − μ 求得（Wᵢⱼ = W(uᵢ, uⱼ)，μⱼ = e^{−ω uⱼ}），
+// 因此曲線在每個流動點精確重現輸入的折現因子。
+//
+//   ω = ln(1 + UFR)     UFR 為年複利的 ultimate forward rate
+//   f(t) = −P'(t) / P(t) → ω（t → ∞），α 控制收斂速度
+//
+// ∂W/∂t 分段解析：
+//
+//   t < u：e^{−ω(t+u)} [−ω H + α − α e^{−αu} cosh(αt)]
+//   t ≥ u：e^{−ω(t+u)} [−ω H + α e^{−αt} sinh(αu)]
+//
+// 其中 H = α m − e^{−αM} sinh(α m)。
+//
+// # α 的決定
+//
+// `alpha` 有指定時直接使用；未指定時依 EIOPA 技術文件：
+//   收斂點 CP = max(LLP + 40, 60)，取 α ≥ 0.05 中最小者使 |f(CP) − ω| ≤ 1bp，
+//   以二分法求解。
+//
+// # Generator
+//
+// `values` 為各流動點的連續複利 zero rate，對應 InterpolationTarget::ZeroRate。
+// 流動點超過 LLP 視為設定錯誤。
+//
+// 校準（以 swap 報價精確擬合流動點）使用 LeastSquareCalibrator::from_parts：
+//   商品數 = 節點數、smoothing_weight = 0 時即為精確求解。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use nalgebra::{DMatrix, DVector};
+use serde::Deserialize;
+
+use crate::manager::manager::{FrozenManager, JsonLoader, ManagerBuilder};
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
+use crate::model::interestrate::curvegenerationerror::CurveGenerationError;
+use crate::model::interestrate::interestratecurve::{
+    DiscountCurve, InstForwardCurve, InterestRateCurve, InterestRateCurveGenerator,
+    YearFractionCalculator, ZeroRateCurve,
+};
+use crate::time::daycounter::daycounter::DayCounterGenerator;
+
+
+/// EIOPA 規定的 α 下限。
+const EIOPA_MIN_ALPHA: f64 = 0.05;
+/// 收斂點 forward 與 ω 的容許差（1bp）。
+const CONVERGENCE_TOLERANCE: f64 = 1e-4;
+/// 收斂點 = max(LLP + CONVERGENCE_SPAN, MIN_CONVERGENCE_POINT)。
+const CONVERGENCE_SPAN: f64 = 40.0;
+const MIN_CONVERGENCE_POINT: f64 = 60.0;
+/// α 搜尋上限。
+const MAX_ALPHA: f64 = 20.0;
+/// 判斷流動點是否超過 LLP 的年化時間容許誤差（閏日、營業日調整造成的零頭）。
+const LLP_TOLERANCE: f64 = 0.05;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SmithWilsonKernel
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct SmithWilsonKernel {
+    omega:  f64,
+    alpha:  f64,
+    times:  Vec<f64>,
+    zeta:   Vec<f64>,
+}
+
+impl SmithWilsonKernel {
+    /// 解 W ζ = p − μ。
+    fn fit(
+        omega:     f64,
+        alpha:     f64,
+        times:     &[f64],
+        discounts: &[f64],
+    ) -> Result<Self, CurveGenerationError> {
+        let n = times.len();
+        let w = DMatrix::from_fn(n, n, |i, j| wilson(omega, alpha, times[i], times[j]));
+        let rhs = DVector::from_iterator(
+            n,
+            times.iter().zip(discounts).map(|(u, p)| p - (-omega * u).exp()),
+        );
+
+        let zeta = match w.clone().cholesky() {
+            Some(cholesky) => cholesky.solve(&rhs),
+            None => w.lu().solve(&rhs).ok_or_else(|| CurveGenerationError::InvalidParameters(
+                "Smith-Wilson kernel matrix is singular".to_string()
+            ))?,
+        };
+
+        Ok(Self {
+            omega,
+            alpha,
+            times: times.to_vec(),
+            zeta:  zeta.iter().cloned().collect(),
+        })
+    }
+
+    fn discount_at(&self, t: f64) -> f64 {
+        if t <= 0.0 { return 1.0; }
+        let kernel: f64 = self.times
+            .iter()
+            .zip(&self.zeta)
+            .map(|(u, z)| z * wilson(self.omega, self.alpha, t, *u))
+            .sum();
+        (-self.omega * t).exp() + kernel
+    }
+
+    fn discount_derivative_at(&self, t: f64) -> f64 {
+        let t = t.max(0.0);
+        let kernel: f64 = self.times
+            .iter()
+            .zip(&self.zeta)
+            .map(|(u, z)| z * wilson_derivative(self.omega, self.alpha, t, *u))
+            .sum();
+        -self.omega * (-self.omega * t).exp() + kernel
+    }
+
+    fn inst_forward_at(&self, t: f64) -> f64 {
+        -self.discount_derivative_at(t) / self.discount_at(t)
+    }
+
+    fn zero_rate_at(&self, t: f64) -> f64 {
+        if t <= 0.0 { return self.inst_forward_at(0.0); }
+        -self.discount_at(t).ln() / t
+    }
+}
+
+/// W(t, u)
+fn wilson(omega: f64, alpha: f64, t: f64, u: f64) -> f64 {
+    let m = t.min(u);
+    let big_m = t.max(u);
+    let h = alpha * m - (-alpha * big_m).exp() * (alpha * m).sinh();
+    (-omega * (t + u)).exp() * h
+}
+
+/// ∂W(t, u) / ∂t
+fn wilson_derivative(omega: f64, alpha: f64, t: f64, u: f64) -> f64 {
+    let m = t.min(u);
+    let big_m = t.max(u);
+    let h = alpha * m - (-alpha * big_m).exp() * (alpha * m).sinh();
+    let dh = if t < u {
+        alpha - alpha * (-alpha * u).exp() * (alpha * t).cosh()
+    } else {
+        alpha * (-alpha * t).exp() * (alpha * u).sinh()
+    };
+    (-omega * (t + u)).exp() * (dh - omega * h)
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Sub-curve implementations
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct SmithWilsonInner {
+    yfc:    YearFractionCalculator,
+    kernel: SmithWilsonKernel,
+}
+
+pub struct SmithWilsonDiscountCurve(Arc<SmithWilsonInner>);
+pub struct SmithWilsonZeroRateCurve(Arc<SmithWilsonInner>);
+pub struct SmithWilsonInstForwardCurve(Arc<SmithWilsonInner>);
+
+impl DiscountCurve for SmithWilsonDiscountCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.0.yfc }
+    fn discount(&self, d: NaiveDate) -> f64 {
+        if d == self.0.yfc.reference_date() { return 1.0; }
+        self.0.kernel.discount_at(self.year_fraction(d))
+    }
+}
+
+impl ZeroRateCurve for SmithWilsonZeroRateCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.0.yfc }
+    fn zero_rate(&self, d: NaiveDate) -> f64 {
+        self.0.kernel.zero_rate_at(self.year_fraction(d))
+    }
+}
+
+impl InstForwardCurve for SmithWilsonInstForwardCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.0.yfc }
+    fn inst_forward(&self, d: NaiveDate) -> f64 {
+        self.0.kernel.inst_forward_at(self.year_fraction(d))
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SmithWilsonCurve
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct SmithWilsonCurve {
+    inner: Arc<SmithWilsonInner>,
+}
+
+impl SmithWilsonCurve {
+    /// 以流動點的年化時間與連續複利 zero rate 建立曲線。
+    ///
+    /// - `ufr`：年複利 ultimate forward rate（如 0.0345）
+    /// - `alpha`：收斂速度；None 時依 EIOPA 收斂點準則求解
+    /// - `last_liquid_point`：LLP（年），所有流動點必須 ≤ LLP
+    ///
+    /// # Errors
+    ///
+    /// 流動點非嚴格遞增、非正、超過 LLP，或參數不合法時回傳 CurveGenerationError。
+    pub fn new(
+        yfc:               YearFractionCalculator,
+        ufr:               f64,
+        alpha:             Option<f64>,
+        last_liquid_point: f64,
+        times:             &[f64],
+        zero_rates:        &[f64],
+    ) -> Result<Self, CurveGenerationError> {
+        if times.len() != zero_rates.len() {
+            return Err(CurveGenerationError::LengthMismatch {
+                values_len: zero_rates.len(),
+                dates_len:  times.len(),
+            });
+        }
+        if times.is_empty() {
+            return Err(CurveGenerationError::InsufficientPoints { required: 1, provided: 0 });
+        }
+        if !ufr.is_finite() || ufr <= -1.0 {
+            return Err(CurveGenerationError::InvalidParameters(format!(
+                "UFR must be finite and greater than -100%, got {}", ufr
+            )));
+        }
+        if let Some(a) = alpha && (!a.is_finite() || a <= 0.0) {
+            return Err(CurveGenerationError::InvalidParameters(format!(
+                "alpha must be positive, got {}", a
+            )));
+        }
+        if times[0] <= 0.0 || times.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(CurveGenerationError::InvalidParameters(
+                "liquid points must be strictly increasing and after the reference date".to_string()
+            ));
+        }
+        let last = times[times.len() - 1];
+        if last > last_liquid_point + LLP_TOLERANCE {
+            return Err(CurveGenerationError::InvalidParameters(format!(
+                "liquid point at {:.4}Y is beyond the last liquid point {}Y",
+                last, last_liquid_point,
+            )));
+        }
+
+        let omega = ufr.ln_1p();
+        let discounts: Vec<f64> = times
+            .iter()
+            .zip(zero_rates)
+            .map(|(t, r)| (-r * t).exp())
+            .collect();
+
+        let kernel = match alpha {
+            Some(a) => SmithWilsonKernel::fit(omega, a, times, &discounts)?,
+            None    => Self::fit_convergence_alpha(omega, last_liquid_point, times, &discounts)?,
+        };
+
+        Ok(Self { inner: Arc::new(SmithWilsonInner { yfc, kernel }) })
+    }
+
+    /// EIOPA：最小的 α ≥ 0.05 使 |f(CP) − ω| ≤ 1bp。
+    fn fit_convergence_alpha(
+        omega:             f64,
+        last_liquid_point: f64,
+        times:             &[f64],
+        discounts:         &[f64],
+    ) -> Result<SmithWilsonKernel, CurveGenerationError> {
+        let convergence_point = (last_liquid_point + CONVERGENCE_SPAN).max(MIN_CONVERGENCE_POINT);
+        let gap = |kernel: &SmithWilsonKernel| {
+            (kernel.inst_forward_at(convergence_point) - omega).abs()
+        };
+
+        let lower = SmithWilsonKernel::fit(omega, EIOPA_MIN_ALPHA, times, discounts)?;
+        if gap(&lower) <= CONVERGENCE_TOLERANCE {
+            return Ok(lower);
+        }
+
+        let mut hi_kernel = SmithWilsonKernel::fit(omega, MAX_ALPHA, times, discounts)?;
+        if gap(&hi_kernel) > CONVERGENCE_TOLERANCE {
+            return Err(CurveGenerationError::InvalidParameters(format!(
+                "Smith-Wilson forward does not converge to UFR within {} at {}Y for alpha <= {}",
+                CONVERGENCE_TOLERANCE, convergence_point, MAX_ALPHA,
+            )));
+        }
+
+        let (mut lo, mut hi) = (EIOPA_MIN_ALPHA, MAX_ALPHA);
+        while hi - lo > 1e-6 {
+            let mid = 0.5 * (lo + hi);
+            let kernel = SmithWilsonKernel::fit(omega, mid, times, discounts)?;
+            if gap(&kernel) <= CONVERGENCE_TOLERANCE {
+                hi = mid;
+                hi_kernel = kernel;
+            } else {
+                lo = mid;
+            }
+        }
+        Ok(hi_kernel)
+    }
+
+    /// 實際使用的 α（指定值或收斂準則求得的值）。
+    pub fn alpha(&self) -> f64 { self.inner.kernel.alpha }
+    /// 連續複利的 ultimate forward intensity ω = ln(1 + UFR)。
+    pub fn omega(&self) -> f64 { self.inner.kernel.omega }
+}
+
+impl InterestRateCurve for SmithWilsonCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.inner.yfc }
+
+    fn to_discount_curve(&self) -> Arc<dyn DiscountCurve> {
+        Arc::new(SmithWilsonDiscountCurve(Arc::clone(&self.inner)))
+    }
+
+    fn to_zero_rate_curve(&self) -> Arc<dyn ZeroRateCurve> {
+        Arc::new(SmithWilsonZeroRateCurve(Arc::clone(&self.inner)))
+    }
+
+    fn to_inst_forward_curve(&self) -> Arc<dyn InstForwardCurve> {
+        Arc::new(SmithWilsonInstForwardCurve(Arc::clone(&self.inner)))
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SmithWilsonCurveGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct SmithWilsonCurveGenerator {
+    day_counter_generator: Arc<DayCounterGenerator>,
+    ufr:                   f64,
+    alpha:                 Option<f64>,
+    last_liquid_point:     f64,
+    dates:                 Vec<NaiveDate>,
+}
+
+impl SmithWilsonCurveGenerator {
+    pub fn new(
+        day_counter_generator: Arc<DayCounterGenerator>,
+        ufr:                   f64,
+        alpha:                 Option<f64>,
+        last_liquid_point:     f64,
+    ) -> Self {
+        Self {
+            day_counter_generator,
+            ufr,
+            alpha,
+            last_liquid_point,
+            dates: Vec::new(),
+        }
+    }
+
+    pub fn ufr(&self)               -> f64          { self.ufr }
+    pub fn alpha(&self)             -> Option<f64>  { self.alpha }
+    pub fn last_liquid_point(&self) -> f64          { self.last_liquid_point }
+    pub fn dates(&self)             -> &[NaiveDate] { &self.dates }
+    pub fn day_counter_generator(&self) -> &Arc<DayCounterGenerator> { &self.day_counter_generator }
+
+    pub fn set_dates(&mut self, dates: Vec<NaiveDate>) {
+        self.dates = dates;
+    }
+}
+
+impl InterestRateCurveGenerator for SmithWilsonCurveGenerator {
+    fn generate(
+        &self,
+        reference_date: NaiveDate,
+        values:         Vec<f64>,
+    ) -> Result<Arc<dyn InterestRateCurve>, CurveGenerationError> {
+        self.generate_with_dates(reference_date, &self.dates, values)
+    }
+
+    fn generate_with_dates(
+        &self,
+        reference_date: NaiveDate,
+        dates:          &[NaiveDate],
+        values:         Vec<f64>,
+    ) -> Result<Arc<dyn InterestRateCurve>, CurveGenerationError> {
+        if values.len() != dates.len() {
+            return Err(CurveGenerationError::LengthMismatch {
+                values_len: values.len(),
+                dates_len:  dates.len(),
+            });
+        }
+
+        let day_counter = self.day_counter_generator
+            .generate(None)
+            .map_err(|e| CurveGenerationError::DayCounterGeneration(e.to_string()))?;
+
+        let yfc = YearFractionCalculator::new(reference_date, Arc::new(day_counter));
+        let times: Vec<f64> = dates.iter().map(|d| yfc.year_fraction(*d)).collect();
+
+        Ok(Arc::new(SmithWilsonCurve::new(
+            yfc,
+            self.ufr,
+            self.alpha,
+            self.last_liquid_point,
+            &times,
+            &values,
+        )?))
+    }
+}
+
+
+// ═════════════════════════════════════════════════════════════════════════════
+// SmithWilsonCurveGeneratorLoader
+// ═════════════════════════════════════════════════════════════════════════════
+//
+// JSON 範例：
+//   {
+//     "name": "EUR_SOLVENCY2",
+//     "day_counter_generator": "ACT365",
+//     "ufr": 0.0345,
+//     "last_liquid_point": 20.0,
+//     "alpha": 0.1
+//   }
+//
+// `alpha` 省略時依 EIOPA 收斂點準則求解。
+// 流動點日期不在 JSON 中，由校準時的節點日期決定。
+
+#[derive(Deserialize)]
+struct SmithWilsonCurveGeneratorJsonProp {
+    day_counter_generator: String,
+    ufr:                   f64,
+    last_liquid_point:     f64,
+    #[serde(default)]
+    alpha:                 Option<f64>,
+}
+
+/// [`SmithWilsonCurveGenerator`] 的 JSON 載入器，supports 為 day counter manager。
+pub struct SmithWilsonCurveGeneratorLoader;
+
+impl JsonLoader<SmithWilsonCurveGenerator, FrozenManager<DayCounterGenerator>>
+    for SmithWilsonCurveGeneratorLoader
+{
+    fn insert_obj_from_json(
+        &self,
+        builder: &mut ManagerBuilder<SmithWilsonCurveGenerator>,
+        json_value: serde_json::Value,
+        supports: &FrozenManager<DayCounterGenerator>,
+    ) -> Result<(), ManagerError> {
+        let named: Named<SmithWilsonCurveGeneratorJsonProp> = parse_json_value(json_value)?;
+        let prop = named.inner;
+
+        if prop.last_liquid_point <= 0.0 {
+            return Err(ManagerError::InvalidValue(format!(
+                "last_liquid_point must be positive, got {}", prop.last_liquid_point
+            )));
+        }
+        if let Some(a) = prop.alpha && a <= 0.0 {
+            return Err(ManagerError::InvalidValue(format!(
+                "alpha must be positive, got {}", a
+            )));
+        }
+
+        let day_counter_generator = supports.get(&prop.day_counter_generator)?;
+        let generator = SmithWilsonCurveGenerator::new(
+            day_counter_generator,
+            prop.ufr,
+            prop.alpha,
+            prop.last_liquid_point,
+        );
+        builder.insert(named.name, Arc::new(generator));
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+// ── spreadcurvebootstrapper.rs ────────────────────────────────────────────────
+//
+// 只校準 spread pillar 的逐點拔靴法（base curve 固定）。
+//
+// # 設計說明
+//
+// 與 IterativeBootstrapper 相同的逐點流程，差異在於：
+//
+//   1. 求解變數是 spread 的 pillar 值，曲線一律是
+//      SpreadInterestRateCurve(base, spread)，base 不動。
+//   2. 第一個 pillar 的 spread 以 FlatForwardCurve（常數 spread）表示，
+//      再依 spread generator 的 InterpolationTarget 轉換；
+//      因此 spread generator 的左外插必須為 FlatForwardRate。
+//   3. 初值為 0（零 spread），而非商品的 market_rate。
+//   4. 所有 pillar 與最終曲線都由建構時的 SpreadInterestRateCurveGenerator 產生，
+//      `calibrate` 傳入的 curve_generator 不使用，避免第一個 pillar 與後續 pillar
+//      疊在不同的 base 上。
+//
+// # 曲線對應
+//
+// 商品 curve_name_map 中的 curve name：
+//   - 若在 `fixed_curves`（`with_fixed_curve` 註冊）中，使用該固定曲線
+//     （例如 tenor basis 曲線校準時，折現曲線固定為 OIS）
+//   - 否則指向正在校準的複合曲線
+//
+// base curve 本身若也被商品直接引用（例如折現），以其 curve name 註冊為 fixed curve。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::configuration::InterestRateInstrumentGeneratorCollection;
+use crate::instrument::instrument::{Position, SimpleInstrument};
+use crate::marketdata::interestrate::interestratequotesheet::InterestRateQuoteSheet;
+use crate::math::rootsolver::{RootSolver, RootSolverConfig};
+use crate::model::interestrate::bootstrappingtrait::BootstrappingTrait;
+use crate::model::interestrate::flatforwardcurve::FlatForwardCurve;
+use crate::model::interestrate::interestratecurve::{
+    InterestRateCurve,
+    InterestRateCurveGenerator,
+};
+use crate::model::interestrate::interestratecurvecalibrator::{
+    CalibrationError,
+    InterestRateCurvePillar,
+    InterestRateCurveCalibrator,
+    calibration_pricing_condition,
+    make_yfc,
+};
+use crate::model::interestrate::piecewisepolyinterestratecurve::ExtrapolationMethod;
+use crate::model::interestrate::spreadinterestratecurve::SpreadInterestRateCurveGenerator;
+use crate::pricer::pricer::Pricer;
+use crate::pricer::simpleinstrumentpricer::SimpleInstrumentPricer;
+use crate::pricingcondition::PricingCondition;
+use crate::time::daycounter::daycounter::DayCounterGenerator;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SpreadCurveBootstrapper
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct SpreadCurveBootstrapper {
+    root_solver_config:    RootSolverConfig,
+    bootstrapping_trait:   BootstrappingTrait,
+    day_counter_generator: Arc<DayCounterGenerator>,
+    generator:             SpreadInterestRateCurveGenerator,
+    fixed_curves:          HashMap<String, Arc<dyn InterestRateCurve>>,
+}
+
+impl SpreadCurveBootstrapper {
+    /// 從 SpreadInterestRateCurveGenerator 建構 SpreadCurveBootstrapper。
+    ///
+    /// # Errors
+    ///
+    /// spread generator 的 left_extrapolation 不是 FlatForwardRate 時回傳
+    /// CalibrationError（理由同 IterativeBootstrapper）。
+    pub fn new(
+        root_solver_config: RootSolverConfig,
+        generator:          &SpreadInterestRateCurveGenerator,
+    ) -> Result<Self, CalibrationError> {
+        let spread_generator = generator.spread_generator();
+        if spread_generator.left_extrapolation() != ExtrapolationMethod::FlatForwardRate {
+            return Err(CalibrationError::CurveGeneration(
+                "SpreadCurveBootstrapper requires the spread generator's \
+                 left_extrapolation = FlatForwardRate".to_string()
+            ));
+        }
+
+        Ok(Self {
+            root_solver_config,
+            bootstrapping_trait: BootstrappingTrait::new(spread_generator.interpolation_target()),
+            day_counter_generator: spread_generator.day_counter_generator().clone(),
+            generator: SpreadInterestRateCurveGenerator::new(
+                generator.base_curve().clone(),
+                spread_generator.clone(),
+            ),
+            fixed_curves: HashMap::new(),
+        })
+    }
+
+    /// 使用預設 RootSolverConfig 的建構方式。
+    pub fn with_defaults(
+        generator: &SpreadInterestRateCurveGenerator,
+    ) -> Result<Self, CalibrationError> {
+        Self::new(RootSolverConfig::default(), generator)
+    }
+
+    /// 註冊校準期間固定不動的曲線（如折現用的 OIS 曲線）。
+    pub fn with_fixed_curve(
+        mut self,
+        curve_name: impl Into<String>,
+        curve:      Arc<dyn InterestRateCurve>,
+    ) -> Self {
+        self.fixed_curves.insert(curve_name.into(), curve);
+        self
+    }
+
+    pub fn base_curve(&self) -> &Arc<dyn InterestRateCurve> { self.generator.base_curve() }
+    pub fn fixed_curves(&self) -> &HashMap<String, Arc<dyn InterestRateCurve>> { &self.fixed_curves }
+
+    /// 固定曲線優先，其餘 curve name 指向正在校準的複合曲線。
+    fn build_market_data(
+        &self,
+        instrument: &dyn SimpleInstrument,
+        curve:      &Arc<dyn InterestRateCurve>,
+    ) -> HashMap<String, Arc<dyn InterestRateCurve>> {
+        instrument
+            .curve_name_map()
+            .values()
+            .map(|name| {
+                let resolved = self.fixed_curves.get(name).unwrap_or(curve);
+                (name.clone(), resolved.clone())
+            })
+            .collect()
+    }
+
+    /// 以指定的曲線建構方式對單一商品求解 spread pillar 值。
+    fn solve_pillar(
+        &self,
+        i:                 usize,
+        instrument:        &Arc<dyn SimpleInstrument>,
+        pillar_date:       NaiveDate,
+        build_curve:       &dyn Fn(f64) -> Option<Arc<dyn InterestRateCurve>>,
+        solver:            &RootSolver,
+        pricing_condition: &PricingCondition,
+    ) -> Result<f64, CalibrationError> {
+        let pricer = SimpleInstrumentPricer;
+        let initial_guess = 0.0;
+        let (lower, upper) = self.bootstrapping_trait.bracket(initial_guess);
+
+        let objective = |value: f64| -> f64 {
+            let Some(curve) = build_curve(value) else {
+                return f64::NAN;
+            };
+            let market_data = self.build_market_data(instrument.as_ref(), &curve);
+            pricer
+                .market_value(instrument.as_ref(), &market_data, pricing_condition)
+                .map(|npv| npv.amount())
+                .unwrap_or(f64::NAN)
+        };
+
+        solver
+            .solve(objective, initial_guess, Some(upper))
+            .or_else(|_| solver.solve(objective, lower, Some(upper)))
+            .map_err(|e| CalibrationError::CurveGeneration(
+                format!("spread pillar {} ({:?}) failed: {}", i, pillar_date, e)
+            ))
+    }
+}
+
+
+impl InterestRateCurveCalibrator for SpreadCurveBootstrapper {
+    /// 曲線由建構時的 SpreadInterestRateCurveGenerator 產生，`_curve_generator` 不使用。
+    fn calibrate(
+        &self,
+        _curve_generator:     Arc<dyn InterestRateCurveGenerator>,
+        reference_date:       NaiveDate,
+        pillars:              Vec<InterestRateCurvePillar>,
+        quote_book:           &HashMap<String, InterestRateQuoteSheet>,
+        generator_collection: &InterestRateInstrumentGeneratorCollection,
+        position:             Position,
+        horizon:              NaiveDate,
+    ) -> Result<Arc<dyn InterestRateCurve>, CalibrationError> {
+        // 1. 產生所有校準商品，按 max_date 排序
+        let mut helpers = Self::generate_calibration_set(
+            &pillars,
+            quote_book,
+            generator_collection,
+            position,
+            horizon,
+        )?;
+        helpers.sort_by_key(|h| h.instrument().max_date());
+
+        if helpers.is_empty() {
+            return Err(CalibrationError::CurveGeneration(
+                "no calibration instruments provided".to_string(),
+            ));
+        }
+
+        let pillar_dates: Vec<NaiveDate> = helpers
+            .iter()
+            .map(|h| h.instrument().max_date())
+            .collect();
+        let instruments: Vec<Arc<dyn SimpleInstrument>> = helpers
+            .into_iter()
+            .map(|h| h.into_instrument())
+            .collect();
+
+        // 2. 逐點求解 spread
+        let solver = RootSolver::new(self.root_solver_config.clone());
+        let pricing_condition = calibration_pricing_condition(horizon);
+        let yfc = make_yfc(&self.day_counter_generator, reference_date)?;
+
+        let mut solved_values: Vec<f64> = Vec::with_capacity(pillar_dates.len());
+
+        for (i, instrument) in instruments.iter().enumerate() {
+            let value = if i == 0 {
+                // 常數 spread：FlatForwardCurve 疊在 base 上
+                let build_first = |spread: f64| -> Option<Arc<dyn InterestRateCurve>> {
+                    let flat: Arc<dyn InterestRateCurve> =
+                        Arc::new(FlatForwardCurve::new(yfc.clone(), spread));
+                    Some(self.generator.compose(flat))
+                };
+                let solved = self.solve_pillar(
+                    i, instrument, pillar_dates[i], &build_first, &solver, &pricing_condition,
+                )?;
+                self.bootstrapping_trait.convert_flat_forward_to_target(solved, &yfc, pillar_dates[i])
+            } else {
+                let current_dates = &pillar_dates[..=i];
+                let build_next = |value: f64| -> Option<Arc<dyn InterestRateCurve>> {
+                    let mut trial_values = solved_values.clone();
+                    trial_values.push(value);
+                    self.generator
+                        .generate_with_dates(reference_date, current_dates, trial_values)
+                        .ok()
+                };
+                self.solve_pillar(
+                    i, instrument, pillar_dates[i], &build_next, &solver, &pricing_condition,
+                )?
+            };
+
+            solved_values.push(value);
+        }
+
+        // 3. 用完整的 spread pillar 值建構最終曲線
+        self.generator
+            .generate_with_dates(reference_date, &pillar_dates, solved_values)
+            .map_err(|e| CalibrationError::CurveGeneration(e.to_string()))
+    }
+
+    fn repricing_market_data(
+        &self,
+        instrument: &dyn SimpleInstrument,
+        curve:      &Arc<dyn InterestRateCurve>,
+    ) -> HashMap<String, Arc<dyn InterestRateCurve>> {
+        self.build_market_data(instrument, curve)
+    }
+}
@@ -0,0 +1,212 @@
+// ── spreadinterestratecurve.rs ────────────────────────────────────────────────
+//
+// Base curve 加上 spread curve 的複合利率曲線（spread-over-base）。
+//
+// # 數學定義
+//
+//   D(t) = D_base(t) × D_spread(t)
+//   R(t) = −ln D(t) / t            （同一 day counter 時 = R_base(t) + R_spread(t)）
+//   f(t) = f_base(t) + f_spread(t)
+//
+// spread 本身是一條 InterestRateCurve（通常為 PiecewisePolyInterestRateCurve），
+// 其 InterpolationTarget 決定 spread 加在哪一個量上：
+//   ZeroRate                 → zero rate spread（逐點插值 R_spread）
+//   InstantaneousForwardRate → inst forward spread（逐點插值 f_spread）
+//   LogDiscount              → log discount spread
+//
+// 三者都以乘法方式組合折現因子，因此 discount / zero / forward 三種視角自動一致。
+//
+// # 用途
+//
+// - 發行人曲線：政府債或 OIS 曲線 + 信用利差
+// - 擔保利率 vs. 無風險利率的拆分
+// - 穩定的 basis curve：OIS + tenor basis，OIS 重新校準時 basis 形狀不受影響
+//
+// # Generator
+//
+// `SpreadInterestRateCurveGenerator` 持有固定的 base curve 與 spread 的
+// PiecewisePolyInterestRateCurveGenerator，`values` 為 spread pillar 的值；
+// 搭配 SpreadCurveBootstrapper 只校準 spread pillar，base 保持不動。
+//
+// 回傳曲線的 YearFractionCalculator 取自 spread curve。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::model::interestrate::curvegenerationerror::CurveGenerationError;
+use crate::model::interestrate::interestratecurve::{
+    DiscountCurve, InstForwardCurve, InterestRateCurve, InterestRateCurveGenerator,
+    YearFractionCalculator, ZeroRateCurve, ZERO_TIME_EPSILON,
+};
+use crate::model::interestrate::piecewisepolyinterestratecurve::PiecewisePolyInterestRateCurveGenerator;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Sub-curve implementations
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct SpreadDiscountCurve {
+    yfc:    YearFractionCalculator,
+    base:   Arc<dyn DiscountCurve>,
+    spread: Arc<dyn DiscountCurve>,
+}
+
+impl DiscountCurve for SpreadDiscountCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn discount(&self, d: NaiveDate) -> f64 {
+        self.base.discount(d) * self.spread.discount(d)
+    }
+}
+
+
+struct SpreadZeroRateCurve {
+    yfc:      YearFractionCalculator,
+    discount: SpreadDiscountCurve,
+    forward:  SpreadInstForwardCurve,
+}
+
+impl ZeroRateCurve for SpreadZeroRateCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn zero_rate(&self, d: NaiveDate) -> f64 {
+        let t = self.yfc.year_fraction(d);
+        if t.abs() < ZERO_TIME_EPSILON {
+            return self.forward.inst_forward(d);
+        }
+        -self.discount.discount(d).ln() / t
+    }
+}
+
+
+struct SpreadInstForwardCurve {
+    yfc:    YearFractionCalculator,
+    base:   Arc<dyn InstForwardCurve>,
+    spread: Arc<dyn InstForwardCurve>,
+}
+
+impl InstForwardCurve for SpreadInstForwardCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn inst_forward(&self, d: NaiveDate) -> f64 {
+        self.base.inst_forward(d) + self.spread.inst_forward(d)
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SpreadInterestRateCurve
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct SpreadInterestRateCurve {
+    yfc:    YearFractionCalculator,
+    base:   Arc<dyn InterestRateCurve>,
+    spread: Arc<dyn InterestRateCurve>,
+}
+
+impl SpreadInterestRateCurve {
+    pub fn new(base: Arc<dyn InterestRateCurve>, spread: Arc<dyn InterestRateCurve>) -> Self {
+        Self {
+            yfc: spread.year_fraction_calculator().clone(),
+            base,
+            spread,
+        }
+    }
+
+    pub fn base_curve(&self)   -> &Arc<dyn InterestRateCurve> { &self.base }
+    pub fn spread_curve(&self) -> &Arc<dyn InterestRateCurve> { &self.spread }
+
+    fn discount_curve(&self) -> SpreadDiscountCurve {
+        SpreadDiscountCurve {
+            yfc:    self.yfc.clone(),
+            base:   self.base.to_discount_curve(),
+            spread: self.spread.to_discount_curve(),
+        }
+    }
+
+    fn inst_forward_curve(&self) -> SpreadInstForwardCurve {
+        SpreadInstForwardCurve {
+            yfc:    self.yfc.clone(),
+            base:   self.base.to_inst_forward_curve(),
+            spread: self.spread.to_inst_forward_curve(),
+        }
+    }
+}
+
+impl InterestRateCurve for SpreadInterestRateCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn to_discount_curve(&self) -> Arc<dyn DiscountCurve> {
+        Arc::new(self.discount_curve())
+    }
+
+    fn to_zero_rate_curve(&self) -> Arc<dyn ZeroRateCurve> {
+        Arc::new(SpreadZeroRateCurve {
+            yfc:      self.yfc.clone(),
+            discount: self.discount_curve(),
+            forward:  self.inst_forward_curve(),
+        })
+    }
+
+    fn to_inst_forward_curve(&self) -> Arc<dyn InstForwardCurve> {
+        Arc::new(self.inst_forward_curve())
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SpreadInterestRateCurveGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// base curve 固定（通常取自 MarketDataSet::get_curve），
+// `generate()` / `generate_with_dates()` 的 values 只描述 spread。
+
+pub struct SpreadInterestRateCurveGenerator {
+    base_curve:       Arc<dyn InterestRateCurve>,
+    spread_generator: Arc<PiecewisePolyInterestRateCurveGenerator>,
+}
+
+impl SpreadInterestRateCurveGenerator {
+    pub fn new(
+        base_curve:       Arc<dyn InterestRateCurve>,
+        spread_generator: Arc<PiecewisePolyInterestRateCurveGenerator>,
+    ) -> Self {
+        Self { base_curve, spread_generator }
+    }
+
+    pub fn base_curve(&self) -> &Arc<dyn InterestRateCurve> { &self.base_curve }
+    pub fn spread_generator(&self) -> &Arc<PiecewisePolyInterestRateCurveGenerator> { &self.spread_generator }
+
+    /// 以任意 spread curve 與固定 base 組合成複合曲線。
+    pub fn compose(&self, spread: Arc<dyn InterestRateCurve>) -> Arc<dyn InterestRateCurve> {
+        Arc::new(SpreadInterestRateCurve::new(self.base_curve.clone(), spread))
+    }
+}
+
+impl InterestRateCurveGenerator for SpreadInterestRateCurveGenerator {
+    fn generate(
+        &self,
+        reference_date: NaiveDate,
+        values:         Vec<f64>,
+    ) -> Result<Arc<dyn InterestRateCurve>, CurveGenerationError> {
+        self.generate_with_dates(reference_date, self.spread_generator.dates(), values)
+    }
+
+    fn generate_with_dates(
+        &self,
+        reference_date: NaiveDate,
+        dates:          &[NaiveDate],
+        values:         Vec<f64>,
+    ) -> Result<Arc<dyn InterestRateCurve>, CurveGenerationError> {
+        if self.base_curve.reference_date() != reference_date {
+            return Err(CurveGenerationError::InvalidParameters(format!(
+                "base curve reference date {} differs from spread reference date {}",
+                self.base_curve.reference_date(),
+                reference_date,
+            )));
+        }
+        let spread = self.spread_generator.generate_with_dates(reference_date, dates, values)?;
+        Ok(self.compose(spread))
+    }
+}
@@ -0,0 +1,435 @@
+// ── stepforwardcurve.rs ───────────────────────────────────────────────────────
+//
+// 央行會議日階梯遠期（meeting-date step forwards）加上跨年 / 月底跳躍的隔夜曲線。
+//
+// # 數學定義
+//
+// 瞬時遠期利率為階梯函數加上跳躍項：
+//
+//   f(t) = vᵢ + Σⱼ Jⱼ 1{sⱼ ≤ t < eⱼ},     t ∈ [kᵢ, kᵢ₊₁)
+//   D(t) = exp(−∫₀ᵗ f)
+//
+// t < k₁ 時 f = v₀（左側平坦），t ≥ kₙ 時 f = vₙ（右側平坦）。
+// 跳躍 Jⱼ（turn-of-year、month-end spread）由設定檔給定，不參與校準。
+//
+// # Knot 的決定（MeetingDateCurveGenerator）
+//
+// pillar 日期 dᵢ（校準商品 max_date）對應的 knot：
+//
+//   m  = 最後一個 m < dᵢ − pillar_lag_days 的會議日
+//   kᵢ = m        若 m > kᵢ₋₁（兩筆報價之間有新的會議）
+//   kᵢ = dᵢ₋₁     否則（退化為一般的 piecewise flat forward），d₋₁ = reference_date
+//
+// 也就是第 i 個商品決定「它新涵蓋的那一段」的遠期利率，且遠期只在會議日改變。
+// kᵢ 可能早於 dᵢ₋₁，但至多 pillar_lag_days 天。此時 vᵢ 也會影響前一個商品：
+// kᵢ 落在其計息期間內時影響最後幾天的計息，否則影響付款遞延期間的折現。
+// 因此 pillar 之間並非逐點獨立，須聯合校準（見下方「校準」）。
+//
+// `pillar_lag_days` 吸收付款遞延：meeting-dated OIS 的 max_date 通常是
+// 結束會議日 + T+2，不應把結束會議日本身當成 knot。
+//
+// 會議日以「新利率生效日」表示（Fed 為會議結束次一營業日）。
+//
+// # 校準
+//
+// 使用 IterativeBootstrapper::from_parts（InterpolationTarget::InstantaneousForwardRate）。
+// 本 generator 的 `builds_from_single_pillar()` 為 true，第一個 pillar 直接以
+// 本曲線求解（含跳躍），不經過 FlatForwardCurve。
+//
+// 逐點求解後前段商品受後段 vᵢ 影響而不再精確定價。本 generator 的 `is_pillar_local()`
+// 為 false，由 bootstrapper 的聯合修正（Gauss-Seidel 掃描）處理，
+// 並在最終曲線下檢查所有商品皆重新定價，否則回傳錯誤。
+// 後段對前段的影響只有數天，通常一至兩輪即收斂。
+
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDate};
+use serde::Deserialize;
+
+use crate::manager::manager::{FrozenManager, JsonLoader, ManagerBuilder};
+use crate::manager::managererror::{ManagerError, parse_json_value};
+use crate::manager::namedobject::Named;
+use crate::model::interestrate::curvegenerationerror::CurveGenerationError;
+use crate::model::interestrate::interestratecurve::{
+    DiscountCurve, InstForwardCurve, InterestRateCurve, InterestRateCurveGenerator,
+    YearFractionCalculator, ZeroRateCurve,
+};
+use crate::time::daycounter::daycounter::DayCounterGenerator;
+
+
+/// `pillar_lag_days` 未指定時的預設值（T+2 付款加週末）。
+const DEFAULT_PILLAR_LAG_DAYS: i64 = 5;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ForwardJump
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 遠期利率在 [start, end) 區間的額外加項（如跨年 spread）。
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct ForwardJump {
+    pub start: NaiveDate,
+    pub end:   NaiveDate,
+    pub size:  f64,
+}
+
+impl ForwardJump {
+    pub fn new(start: NaiveDate, end: NaiveDate, size: f64) -> Self {
+        Self { start, end, size }
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// StepForwardInner
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct StepForwardInner {
+    yfc:    YearFractionCalculator,
+    /// knot 年化時間（嚴格遞增），knots[0] 只作為紀錄，不影響 f(t)。
+    knots:  Vec<f64>,
+    values: Vec<f64>,
+    /// (start, end, size)，已轉為年化時間。
+    jumps:  Vec<(f64, f64, f64)>,
+    /// cumulative[i] = ∫₀^{max(kᵢ, 0)} 階梯部分，i ≥ 1；cumulative[0] = 0。
+    cumulative: Vec<f64>,
+}
+
+impl StepForwardInner {
+    fn new(
+        yfc:    YearFractionCalculator,
+        knots:  Vec<f64>,
+        values: Vec<f64>,
+        jumps:  Vec<(f64, f64, f64)>,
+    ) -> Self {
+        let mut cumulative = vec![0.0; knots.len()];
+        for i in 1..knots.len() {
+            let left  = if i == 1 { 0.0 } else { knots[i - 1].max(0.0) };
+            let right = knots[i].max(0.0);
+            cumulative[i] = cumulative[i - 1] + values[i - 1] * (right - left);
+        }
+        Self { yfc, knots, values, jumps, cumulative }
+    }
+
+    /// 最後一個 kᵢ ≤ t 的 i（t < k₁ 時為 0）。
+    fn segment(&self, t: f64) -> usize {
+        self.knots[1..].partition_point(|k| *k <= t)
+    }
+
+    fn step_integral(&self, t: f64) -> f64 {
+        let i = self.segment(t);
+        let left = if i == 0 { 0.0 } else { self.knots[i].max(0.0) };
+        self.cumulative[i] + self.values[i] * (t - left)
+    }
+
+    fn jump_integral(&self, t: f64) -> f64 {
+        self.jumps
+            .iter()
+            .map(|(s, e, size)| size * (t.clamp(*s, *e) - s.clamp(0.0, *e)).max(0.0))
+            .sum()
+    }
+
+    fn discount_at(&self, t: f64) -> f64 {
+        if t <= 0.0 { return 1.0; }
+        (-(self.step_integral(t) + self.jump_integral(t))).exp()
+    }
+
+    fn inst_forward_at(&self, t: f64) -> f64 {
+        let jump: f64 = self.jumps
+            .iter()
+            .filter(|(s, e, _)| t >= *s && t < *e)
+            .map(|(_, _, size)| size)
+            .sum();
+        self.values[self.segment(t)] + jump
+    }
+
+    fn zero_rate_at(&self, t: f64) -> f64 {
+        if t <= 0.0 { return self.inst_forward_at(0.0); }
+        (self.step_integral(t) + self.jump_integral(t)) / t
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Wrapper structs
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct StepForwardDiscountCurve(Arc<StepForwardInner>);
+pub struct StepForwardZeroRateCurve(Arc<StepForwardInner>);
+pub struct StepForwardInstForwardCurve(Arc<StepForwardInner>);
+
+impl DiscountCurve for StepForwardDiscountCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.0.yfc }
+    fn discount(&self, d: NaiveDate) -> f64 {
+        if d == self.0.yfc.reference_date() { return 1.0; }
+        self.0.discount_at(self.year_fraction(d))
+    }
+}
+
+impl ZeroRateCurve for StepForwardZeroRateCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.0.yfc }
+    fn zero_rate(&self, d: NaiveDate) -> f64 {
+        self.0.zero_rate_at(self.year_fraction(d))
+    }
+}
+
+impl InstForwardCurve for StepForwardInstForwardCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.0.yfc }
+    fn inst_forward(&self, d: NaiveDate) -> f64 {
+        self.0.inst_forward_at(self.year_fraction(d))
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// StepForwardCurve
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct StepForwardCurve {
+    inner: Arc<StepForwardInner>,
+}
+
+impl StepForwardCurve {
+    /// 以 knot 日期、各段遠期利率與跳躍建立曲線。
+    ///
+    /// # Errors
+    ///
+    /// knots 為空、長度與 values 不符、非嚴格遞增，或跳躍區間為空時回傳錯誤。
+    pub fn new(
+        yfc:    YearFractionCalculator,
+        knots:  &[NaiveDate],
+        values: Vec<f64>,
+        jumps:  &[ForwardJump],
+    ) -> Result<Self, CurveGenerationError> {
+        if knots.len() != values.len() {
+            return Err(CurveGenerationError::LengthMismatch {
+                values_len: values.len(),
+                dates_len:  knots.len(),
+            });
+        }
+        if knots.is_empty() {
+            return Err(CurveGenerationError::InsufficientPoints { required: 1, provided: 0 });
+        }
+        if knots.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(CurveGenerationError::InvalidParameters(
+                "step forward knots must be strictly increasing".to_string()
+            ));
+        }
+        if let Some(jump) = jumps.iter().find(|j| j.end <= j.start) {
+            return Err(CurveGenerationError::InvalidParameters(format!(
+                "forward jump [{}, {}) is empty", jump.start, jump.end
+            )));
+        }
+
+        let knot_times = knots.iter().map(|d| yfc.year_fraction(*d)).collect();
+        let jump_times = jumps
+            .iter()
+            .map(|j| (yfc.year_fraction(j.start), yfc.year_fraction(j.end), j.size))
+            .collect();
+
+        Ok(Self {
+            inner: Arc::new(StepForwardInner::new(yfc, knot_times, values, jump_times)),
+        })
+    }
+
+    pub fn values(&self) -> &[f64] { &self.inner.values }
+}
+
+impl InterestRateCurve for StepForwardCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.inner.yfc }
+
+    fn to_discount_curve(&self) -> Arc<dyn DiscountCurve> {
+        Arc::new(StepForwardDiscountCurve(Arc::clone(&self.inner)))
+    }
+
+    fn to_zero_rate_curve(&self) -> Arc<dyn ZeroRateCurve> {
+        Arc::new(StepForwardZeroRateCurve(Arc::clone(&self.inner)))
+    }
+
+    fn to_inst_forward_curve(&self) -> Arc<dyn InstForwardCurve> {
+        Arc::new(StepForwardInstForwardCurve(Arc::clone(&self.inner)))
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// MeetingDateCurveGenerator
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct MeetingDateCurveGenerator {
+    day_counter_generator: Arc<DayCounterGenerator>,
+    meeting_dates:         Vec<NaiveDate>,
+    jumps:                 Vec<ForwardJump>,
+    pillar_lag_days:       i64,
+    dates:                 Vec<NaiveDate>,
+}
+
+impl MeetingDateCurveGenerator {
+    pub fn new(
+        day_counter_generator: Arc<DayCounterGenerator>,
+        mut meeting_dates:     Vec<NaiveDate>,
+        jumps:                 Vec<ForwardJump>,
+        pillar_lag_days:       i64,
+    ) -> Self {
+        meeting_dates.sort();
+        meeting_dates.dedup();
+        Self {
+            day_counter_generator,
+            meeting_dates,
+            jumps,
+            pillar_lag_days,
+            dates: Vec::new(),
+        }
+    }
+
+    /// 預設 pillar_lag_days 的建構方式。
+    pub fn with_defaults(
+        day_counter_generator: Arc<DayCounterGenerator>,
+        meeting_dates:         Vec<NaiveDate>,
+        jumps:                 Vec<ForwardJump>,
+    ) -> Self {
+        Self::new(day_counter_generator, meeting_dates, jumps, DEFAULT_PILLAR_LAG_DAYS)
+    }
+
+    pub fn meeting_dates(&self)   -> &[NaiveDate]   { &self.meeting_dates }
+    pub fn jumps(&self)           -> &[ForwardJump] { &self.jumps }
+    pub fn pillar_lag_days(&self) -> i64            { self.pillar_lag_days }
+    pub fn dates(&self)           -> &[NaiveDate]   { &self.dates }
+    pub fn day_counter_generator(&self) -> &Arc<DayCounterGenerator> { &self.day_counter_generator }
+
+    pub fn set_dates(&mut self, dates: Vec<NaiveDate>) {
+        self.dates = dates;
+    }
+
+    /// pillar 日期 → 階梯 knot 日期，規則見檔頭。
+    pub fn knot_dates(&self, reference_date: NaiveDate, pillar_dates: &[NaiveDate]) -> Vec<NaiveDate> {
+        let mut previous_pillar = reference_date;
+        let mut previous_knot: Option<NaiveDate> = None;
+        pillar_dates
+            .iter()
+            .map(|pillar| {
+                let cutoff = *pillar - Duration::days(self.pillar_lag_days);
+                let meeting_index = self.meeting_dates.partition_point(|m| *m < cutoff);
+                let knot = meeting_index
+                    .checked_sub(1)
+                    .map(|i| self.meeting_dates[i])
+                    .filter(|m| previous_knot.is_none_or(|k| *m > k) && *m > reference_date)
+                    .unwrap_or(previous_pillar);
+                previous_pillar = *pillar;
+                previous_knot = Some(knot);
+                knot
+            })
+            .collect()
+    }
+}
+
+
+impl InterestRateCurveGenerator for MeetingDateCurveGenerator {
+    fn generate(
+        &self,
+        reference_date: NaiveDate,
+        values:         Vec<f64>,
+    ) -> Result<Arc<dyn InterestRateCurve>, CurveGenerationError> {
+        self.generate_with_dates(reference_date, &self.dates, values)
+    }
+
+    fn generate_with_dates(
+        &self,
+        reference_date: NaiveDate,
+        dates:          &[NaiveDate],
+        values:         Vec<f64>,
+    ) -> Result<Arc<dyn InterestRateCurve>, CurveGenerationError> {
+        if values.len() != dates.len() {
+            return Err(CurveGenerationError::LengthMismatch {
+                values_len: values.len(),
+                dates_len:  dates.len(),
+            });
+        }
+
+        let day_counter = self.day_counter_generator
+            .generate(None)
+            .map_err(|e| CurveGenerationError::DayCounterGeneration(e.to_string()))?;
+        let yfc = YearFractionCalculator::new(reference_date, Arc::new(day_counter));
+
+        let knots = self.knot_dates(reference_date, dates);
+        Ok(Arc::new(StepForwardCurve::new(yfc, &knots, values, &self.jumps)?))
+    }
+
+    fn builds_from_single_pillar(&self) -> bool {
+        true
+    }
+
+    fn is_pillar_local(&self) -> bool {
+        false
+    }
+}
+
+
+// ═════════════════════════════════════════════════════════════════════════════
+// MeetingDateCurveGeneratorLoader
+// ═════════════════════════════════════════════════════════════════════════════
+//
+// JSON 範例：
+//   {
+//     "name": "USD_SOFR_FOMC",
+//     "day_counter_generator": "ACT360",
+//     "meeting_dates": ["2025-01-30", "2025-03-20", "2025-05-08"],
+//     "jumps": [
+//       { "start": "2025-12-31", "end": "2026-01-02", "size": 0.0015 }
+//     ],
+//     "pillar_lag_days": 5
+//   }
+//
+// `jumps` 與 `pillar_lag_days` 可省略。
+
+fn default_pillar_lag_days() -> i64 {
+    DEFAULT_PILLAR_LAG_DAYS
+}
+
+#[derive(Deserialize)]
+struct MeetingDateCurveGeneratorJsonProp {
+    day_counter_generator: String,
+    meeting_dates:         Vec<NaiveDate>,
+    #[serde(default)]
+    jumps:                 Vec<ForwardJump>,
+    #[serde(default = "default_pillar_lag_days")]
+    pillar_lag_days:       i64,
+}
+
+/// [`MeetingDateCurveGenerator`] 的 JSON 載入器，supports 為 day counter manager。
+pub struct MeetingDateCurveGeneratorLoader;
+
+impl JsonLoader<MeetingDateCurveGenerator, FrozenManager<DayCounterGenerator>>
+    for MeetingDateCurveGeneratorLoader
+{
+    fn insert_obj_from_json(
+        &self,
+        builder: &mut ManagerBuilder<MeetingDateCurveGenerator>,
+        json_value: serde_json::Value,
+        supports: &FrozenManager<DayCounterGenerator>,
+    ) -> Result<(), ManagerError> {
+        let named: Named<MeetingDateCurveGeneratorJsonProp> = parse_json_value(json_value)?;
+        let prop = named.inner;
+
+        if prop.pillar_lag_days < 0 {
+            return Err(ManagerError::InvalidValue(format!(
+                "pillar_lag_days must be non-negative, got {}", prop.pillar_lag_days
+            )));
+        }
+        if let Some(jump) = prop.jumps.iter().find(|j| j.end <= j.start) {
+            return Err(ManagerError::InvalidValue(format!(
+                "forward jump [{}, {}) is empty", jump.start, jump.end
+            )));
+        }
+
+        let day_counter_generator = supports.get(&prop.day_counter_generator)?;
+        let generator = MeetingDateCurveGenerator::new(
+            day_counter_generator,
+            prop.meeting_dates,
+            prop.jumps,
+            prop.pillar_lag_days,
+        );
+        builder.insert(named.name, Arc::new(generator));
+        Ok(())
+    }
+}
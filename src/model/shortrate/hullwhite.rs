@@ -0,0 +1,439 @@
+// ── hullwhite.rs ──────────────────────────────────────────────────────────────
+//
+// Hull-White 一因子模型（mean reversion a 為常數、σ(t) 為 piecewise constant），
+// 以 x(t) = r(t) − f^M(0, t) 表示狀態（Andersen & Piterbarg 的寫法）：
+//
+//   dx = (y(t) − a x) dt + σ(t) dW,    x(0) = 0
+//   y(t) = ∫₀ᵗ e^{−2a(t−u)} σ(u)² du
+//
+// 條件折現因子（Turfus 的 conditional discount curve）：
+//
+//   P(t, T | x) = P^M(0, T) / P^M(0, t) · exp(−B(t, T) x − ½ B(t, T)² y(t))
+//   B(t, T)     = (1 − e^{−a(T−t)}) / a
+//
+// 其中 P^M 為任一 InterestRateCurve；x = 0、t = 0 時即回到原曲線，θ(t) 由曲線隱含，
+// 不需另外校準。時間軸為曲線 day counter 自 reference date 起算的 year fraction。
+//
+// # 零息債券選擇權
+//
+// 以 P(t, T_s) 為 numeraire，P(T_e, T_m) / P(T_e, T_s) 為 lognormal：
+//
+//   σ_p = |B(T_e, T_m) − B(T_e, T_s)| √y(T_e)
+//   V   = P(0, T_s) · Black(ω, F = P(0, T_m) / P(0, T_s), K, σ_p)
+//
+// T_s = T_e 即標準的 ZCB option；T_s > T_e 供 Jamshidian 的遠期起息 swaption 使用。
+//
+// # Jamshidian
+//
+// coupon bond option 的 payoff 為 (ω (Σ c_k P(T_e, D_k) − K P(T_e, T_s)))⁺；
+// Σ c_k P(T_e, D_k | x) / P(T_e, T_s | x) 對 x 遞減，解出 x* 後拆為各 D_k 的 ZCB option，
+// strike 為 K_k = P(T_e, D_k | x*) / P(T_e, T_s | x*)。
+//
+// Swaption：payer = 以 N 於 T_s 賣出 fixed leg + 到期本金的 put，receiver 為 call。
+// floating leg 的 forward curve 與模型曲線不同時，basis（floating leg 現值減去
+// N (P(0, T_s) − P(0, T_end))）視為確定性，按 fixed leg 的 annuity 比例自各期票息扣除，
+// 使 t = 0 時 swap 現值與曲線一致。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::instrument::instrument::OptionType;
+use crate::instrument::interestrate::swaption::{Swaption, SwaptionSettlement, SwaptionType};
+use crate::math::rootsolver::{RootSolver, RootSolverError};
+use crate::model::interestrate::interestratecurve::{InterestRateCurve, YearFractionCalculator};
+use crate::model::shortrate::hullwhiteconditionalcurve::HullWhiteConditionalCurve;
+use crate::model::volatility::blackformula::{BlackFormula, VolatilityType};
+use crate::pricingcondition::PricingCondition;
+
+
+/// 視為 a = 0 的門檻，避免 B(t, T) 的 0 / 0。
+const MEAN_REVERSION_EPSILON: f64 = 1e-10;
+
+/// Jamshidian 求 x* 時的初始 bracket 與最大倍增次數。
+const STATE_BRACKET_INITIAL: f64 = 0.1;
+const STATE_BRACKET_MAX_DOUBLINGS: usize = 64;
+
+
+/// B(τ) = (1 − e^{−aτ}) / a；a → 0 時為 τ。
+pub fn b_function(mean_reversion: f64, tau: f64) -> f64 {
+    if mean_reversion.abs() < MEAN_REVERSION_EPSILON {
+        return tau;
+    }
+    -(-mean_reversion * tau).exp_m1() / mean_reversion
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// HullWhiteError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum HullWhiteError {
+    #[error("invalid Hull-White parameters: {0}")]
+    InvalidParameters(String),
+
+    #[error("option expired on {0}, before the curve reference date")]
+    Expired(NaiveDate),
+
+    #[error("invalid coupon bond: {0}")]
+    InvalidCashFlows(String),
+
+    #[error("Jamshidian decomposition supports physically settled swaptions only")]
+    UnsupportedSettlement,
+
+    #[error("no state solves the Jamshidian exercise boundary")]
+    NoExerciseBoundary,
+
+    #[error("exercise boundary solve failed: {0}")]
+    Solver(#[from] RootSolverError),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// HullWhiteParameters
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// σ 在 (sigma_dates[k−1], sigma_dates[k]] 上為 sigmas[k]；sigmas 比 sigma_dates 多一個，
+/// 最後一段之後維持不變。
+#[derive(Debug, Clone)]
+pub struct HullWhiteParameters {
+    mean_reversion: f64,
+    sigma_dates:    Vec<NaiveDate>,
+    sigmas:         Vec<f64>,
+}
+
+impl HullWhiteParameters {
+    pub fn new(mean_reversion: f64, sigma_dates: Vec<NaiveDate>, sigmas: Vec<f64>) -> Self {
+        Self { mean_reversion, sigma_dates, sigmas }
+    }
+
+    /// 常數 σ。
+    pub fn constant(mean_reversion: f64, sigma: f64) -> Self {
+        Self::new(mean_reversion, Vec::new(), vec![sigma])
+    }
+
+    pub fn mean_reversion(&self) -> f64 { self.mean_reversion }
+    pub fn sigma_dates(&self) -> &[NaiveDate] { &self.sigma_dates }
+    pub fn sigmas(&self) -> &[f64] { &self.sigmas }
+
+    fn validate(&self, reference_date: NaiveDate) -> Result<(), HullWhiteError> {
+        if !self.mean_reversion.is_finite() {
+            return Err(HullWhiteError::InvalidParameters("mean reversion must be finite".to_string()));
+        }
+        if self.sigmas.len() != self.sigma_dates.len() + 1 {
+            return Err(HullWhiteError::InvalidParameters(format!(
+                "{} sigmas for {} sigma dates; expected one more sigma than dates",
+                self.sigmas.len(),
+                self.sigma_dates.len(),
+            )));
+        }
+        if self.sigmas.iter().any(|sigma| !sigma.is_finite() || *sigma < 0.0) {
+            return Err(HullWhiteError::InvalidParameters("sigmas must be finite and non-negative".to_string()));
+        }
+        if self.sigma_dates.first().is_some_and(|&date| date <= reference_date)
+            || self.sigma_dates.windows(2).any(|dates| dates[0] >= dates[1])
+        {
+            return Err(HullWhiteError::InvalidParameters(
+                "sigma dates must be strictly increasing and after the curve reference date".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// HullWhiteModel
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct HullWhiteModel {
+    curve:       Arc<dyn InterestRateCurve>,
+    parameters:  HullWhiteParameters,
+    /// sigma_dates 對應的時間。
+    sigma_times: Vec<f64>,
+}
+
+impl HullWhiteModel {
+    /// # Errors
+    ///
+    /// - mean reversion 非有限值、σ 為負或個數與 sigma_dates 不符
+    /// - sigma_dates 未嚴格遞增或不晚於曲線 reference date
+    pub fn new(curve: Arc<dyn InterestRateCurve>, parameters: HullWhiteParameters) -> Result<Self, HullWhiteError> {
+        parameters.validate(curve.reference_date())?;
+        let sigma_times = parameters.sigma_dates.iter().map(|&date| curve.year_fraction(date)).collect();
+        Ok(Self { curve, parameters, sigma_times })
+    }
+
+    pub fn curve(&self) -> &Arc<dyn InterestRateCurve> { &self.curve }
+    pub fn parameters(&self) -> &HullWhiteParameters { &self.parameters }
+    pub fn mean_reversion(&self) -> f64 { self.parameters.mean_reversion }
+    pub fn reference_date(&self) -> NaiveDate { self.curve.reference_date() }
+    pub fn year_fraction_calculator(&self) -> &YearFractionCalculator { self.curve.year_fraction_calculator() }
+
+    /// 模型時間軸上的時間。
+    pub fn time(&self, date: NaiveDate) -> f64 {
+        self.curve.year_fraction(date)
+    }
+
+    /// B(t, T)。
+    pub fn b(&self, start_date: NaiveDate, end_date: NaiveDate) -> f64 {
+        b_function(self.mean_reversion(), self.time(end_date) - self.time(start_date))
+    }
+
+    /// date 所在區段的 σ。
+    pub fn sigma(&self, date: NaiveDate) -> f64 {
+        let k = self.parameters.sigma_dates.partition_point(|&sigma_date| sigma_date < date);
+        self.parameters.sigmas[k]
+    }
+
+    /// y(t) = Var[x(t)]；t 不晚於 reference date 時為 0。
+    pub fn state_variance(&self, date: NaiveDate) -> f64 {
+        self.state_variance_at(self.time(date))
+    }
+
+    fn state_variance_at(&self, time: f64) -> f64 {
+        let a = self.mean_reversion();
+        let mut variance = 0.0;
+        let mut lower = 0.0;
+        for (k, sigma) in self.parameters.sigmas.iter().enumerate() {
+            if lower >= time {
+                break;
+            }
+            let upper = self.sigma_times.get(k).copied().unwrap_or(f64::INFINITY).min(time);
+            variance += sigma * sigma * (-2.0 * a * (time - upper)).exp() * b_function(2.0 * a, upper - lower);
+            lower = upper;
+        }
+        variance
+    }
+
+    /// r(t) = f^M(0, t) + x。
+    pub fn short_rate(&self, date: NaiveDate, state: f64) -> f64 {
+        self.curve.to_inst_forward_curve().inst_forward(date) + state
+    }
+
+    /// P(t, T | x)。
+    pub fn discount_bond(&self, state_date: NaiveDate, maturity_date: NaiveDate, state: f64) -> f64 {
+        let discount_curve = self.curve.to_discount_curve();
+        let b = self.b(state_date, maturity_date);
+        discount_curve.discount(maturity_date) / discount_curve.discount(state_date)
+            * (-b * state - 0.5 * b * b * self.state_variance(state_date)).exp()
+    }
+
+    /// 以狀態 (state_date, x) 為條件的模型曲線，reference date 為 state_date。
+    pub fn conditional_curve(&self, state_date: NaiveDate, state: f64) -> Arc<dyn InterestRateCurve> {
+        self.conditional_curve_for(self.curve.clone(), state_date, state)
+    }
+
+    /// 以同一狀態移動另一條曲線（例如 forward curve），與模型曲線的 basis 視為確定性。
+    pub fn conditional_curve_for(
+        &self,
+        curve:      Arc<dyn InterestRateCurve>,
+        state_date: NaiveDate,
+        state:      f64,
+    ) -> Arc<dyn InterestRateCurve> {
+        Arc::new(HullWhiteConditionalCurve::new(
+            curve,
+            self.year_fraction_calculator().clone(),
+            self.mean_reversion(),
+            state_date,
+            state,
+            self.state_variance(state_date),
+        ))
+    }
+
+    // ── 零息債券選擇權 ───────────────────────────────────────────────────────
+
+    /// 於 expiry_date 以 strike 買入（call）/ 賣出（put）到期日為 maturity_date 的零息債券，
+    /// 回傳 reference date 的現值（每單位面額）；已到期時為 forward 的內含價值。
+    pub fn zero_coupon_bond_option(
+        &self,
+        option_type:   OptionType,
+        expiry_date:   NaiveDate,
+        maturity_date: NaiveDate,
+        strike:        f64,
+    ) -> f64 {
+        self.forward_bond_option(option_type, expiry_date, expiry_date, maturity_date, strike)
+    }
+
+    /// strike 於 strike_date（≥ expiry_date）支付的 ZCB option。
+    fn forward_bond_option(
+        &self,
+        option_type:   OptionType,
+        expiry_date:   NaiveDate,
+        strike_date:   NaiveDate,
+        maturity_date: NaiveDate,
+        strike:        f64,
+    ) -> f64 {
+        let discount_curve = self.curve.to_discount_curve();
+        let strike_discount = discount_curve.discount(strike_date);
+        let forward = discount_curve.discount(maturity_date) / strike_discount;
+        let std_dev = (self.b(expiry_date, maturity_date) - self.b(expiry_date, strike_date)).abs()
+            * self.state_variance(expiry_date).sqrt();
+        // expiry_time = 1 時 BlackFormula 的 vol 即為總標準差
+        strike_discount * BlackFormula::new(VolatilityType::lognormal(), forward, 1.0).price(option_type, strike, std_dev)
+    }
+
+    // ── Jamshidian ───────────────────────────────────────────────────────────
+
+    /// 於 expiry_date 以 strike（於 strike_date 支付）買入 / 賣出 coupon bond
+    /// （cash_flows 為 (付款日, 金額)）的選擇權，回傳 reference date 的現值。
+    ///
+    /// # Errors
+    ///
+    /// - expiry_date 早於 reference date
+    /// - 無現金流、strike_date 早於 expiry_date 或現金流不晚於 strike_date
+    /// - 找不到 exercise boundary x*（例如現金流正負相抵使 bond 價格對 x 非單調）
+    pub fn coupon_bond_option(
+        &self,
+        option_type: OptionType,
+        expiry_date: NaiveDate,
+        strike_date: NaiveDate,
+        cash_flows:  &[(NaiveDate, f64)],
+        strike:      f64,
+        solver:      &RootSolver,
+    ) -> Result<f64, HullWhiteError> {
+        if expiry_date < self.reference_date() {
+            return Err(HullWhiteError::Expired(expiry_date));
+        }
+        if cash_flows.is_empty() {
+            return Err(HullWhiteError::InvalidCashFlows("no cash flows".to_string()));
+        }
+        if strike_date < expiry_date || cash_flows.iter().any(|&(date, _)| date <= strike_date) {
+            return Err(HullWhiteError::InvalidCashFlows(format!(
+                "cash flows must be paid after the strike date {strike_date}, which must not precede expiry {expiry_date}"
+            )));
+        }
+
+        // R_k(x) = P(T_e, D_k | x) / P(T_e, T_s | x)
+        let variance = self.state_variance(expiry_date);
+        let strike_b = self.b(expiry_date, strike_date);
+        let discount_curve = self.curve.to_discount_curve();
+        let strike_discount = discount_curve.discount(strike_date);
+        let ratios: Vec<(f64, f64)> = cash_flows
+            .iter()
+            .map(|&(date, _)| (discount_curve.discount(date) / strike_discount, self.b(expiry_date, date)))
+            .collect();
+        let ratio = |(forward, b): (f64, f64), state: f64| {
+            forward * (-(b - strike_b) * state - 0.5 * (b * b - strike_b * strike_b) * variance).exp()
+        };
+        let exercise_value = |state: f64| {
+            cash_flows
+                .iter()
+                .zip(&ratios)
+                .map(|(&(_, amount), &r)| amount * ratio(r, state))
+                .sum::<f64>()
+                - strike
+        };
+
+        let (lower, upper) = Self::bracket_state(&exercise_value)?;
+        let boundary = solver.solve(exercise_value, lower, Some(upper))?;
+        let value = cash_flows
+            .iter()
+            .zip(&ratios)
+            .map(|(&(date, amount), &r)| {
+                amount * self.forward_bond_option(option_type, expiry_date, strike_date, date, ratio(r, boundary))
+            })
+            .sum();
+        Ok(value)
+    }
+
+    /// 自 ±STATE_BRACKET_INITIAL 倍增，直到 exercise_value 在兩端異號（遞減）。
+    fn bracket_state<F: Fn(f64) -> f64>(exercise_value: &F) -> Result<(f64, f64), HullWhiteError> {
+        let mut lower = -STATE_BRACKET_INITIAL;
+        let mut upper = STATE_BRACKET_INITIAL;
+        for _ in 0..STATE_BRACKET_MAX_DOUBLINGS {
+            let lower_value = exercise_value(lower);
+            let upper_value = exercise_value(upper);
+            if lower_value >= 0.0 && upper_value <= 0.0 {
+                return Ok((lower, upper));
+            }
+            if lower_value < 0.0 {
+                lower *= 2.0;
+            }
+            if upper_value > 0.0 {
+                upper *= 2.0;
+            }
+        }
+        Err(HullWhiteError::NoExerciseBoundary)
+    }
+
+    /// 實物交割 swaption 在模型 reference date 的現值（含 position 與名目本金）。
+    ///
+    /// `forward_curve` 為 floating leg 的 forward curve；pricing_condition 的 horizon
+    /// 應為模型曲線的 reference date。
+    ///
+    /// # Errors
+    ///
+    /// - 現金交割（CashParYield）
+    /// - 已到期或 Jamshidian 求解失敗（見 [`coupon_bond_option`](Self::coupon_bond_option)）
+    pub fn swaption_value(
+        &self,
+        swaption:          &Swaption,
+        forward_curve:     &Arc<dyn InterestRateCurve>,
+        pricing_condition: &PricingCondition,
+        solver:            &RootSolver,
+    ) -> Result<f64, HullWhiteError> {
+        if swaption.settlement() != SwaptionSettlement::Physical {
+            return Err(HullWhiteError::UnsupportedSettlement);
+        }
+        let discount_curve = self.curve.to_discount_curve();
+        let fixed_flows = swaption.fixed_leg_flow_observer_list();
+        let last_flow = fixed_flows
+            .last()
+            .ok_or_else(|| HullWhiteError::InvalidCashFlows("underlying swap has no fixed flows".to_string()))?;
+        let nominal = last_flow.nominal().abs();
+        let start_date = swaption.start_date();
+        let end_date = last_flow.payment_date();
+
+        // fixed leg 票息與 annuity 權重 |N_i| τ_i
+        let generic_characters = swaption.fixed_leg_characters().generic_characters();
+        let schedule_periods = generic_characters.schedule().schedule_periods();
+        let coupons: Vec<(NaiveDate, f64, f64)> = fixed_flows
+            .iter()
+            .map(|flow_observer| {
+                let calculation_period = schedule_periods[flow_observer.i()].calculation_period();
+                let tau = generic_characters
+                    .day_counter()
+                    .year_fraction(calculation_period.start_date(), calculation_period.end_date());
+                let amount = flow_observer.nominal().abs()
+                    * flow_observer.ref_leg_characters().evaluate_flow(flow_observer.i(), None, pricing_condition, None);
+                (flow_observer.payment_date(), amount, flow_observer.nominal().abs() * tau)
+            })
+            .collect();
+        let annuity = coupons
+            .iter()
+            .map(|&(date, _, weight)| weight * discount_curve.discount(date))
+            .sum::<f64>();
+
+        let floating_value = swaption
+            .floating_leg_flow_observer_list()
+            .iter()
+            .map(|flow_observer| {
+                flow_observer.nominal().abs()
+                    * flow_observer.ref_leg_characters().evaluate_flow(
+                        flow_observer.i(),
+                        Some(forward_curve),
+                        pricing_condition,
+                        None,
+                    )
+                    * discount_curve.discount(flow_observer.payment_date())
+            })
+            .sum::<f64>();
+        let basis = floating_value - nominal * (discount_curve.discount(start_date) - discount_curve.discount(end_date));
+
+        let mut cash_flows: Vec<(NaiveDate, f64)> = coupons
+            .iter()
+            .map(|&(date, amount, weight)| (date, amount - basis * weight / annuity))
+            .collect();
+        cash_flows.push((end_date, nominal));
+
+        let option_type = match swaption.swaption_type() {
+            SwaptionType::Payer    => OptionType::Put,
+            SwaptionType::Receiver => OptionType::Call,
+        };
+        let value = self.coupon_bond_option(option_type, swaption.expiry_date(), start_date, &cash_flows, nominal, solver)?;
+        Ok(swaption.sign() * value)
+    }
+}
@@ -0,0 +1,190 @@
+// ── hullwhiteconditionalcurve.rs ──────────────────────────────────────────────
+//
+// Hull-White 狀態 (t, x) 下的條件曲線 P(t, T | x)，以 InterestRateCurve 提供，
+// 讓既有的 leg / index 程式碼可在模擬或格點上的狀態直接 project flows。
+//
+// 令 base 為被移動的曲線、B = B(t, T)、y = y(t)：
+//
+//   D_t(T) = D(T) / D(t) · exp(−B x − ½ B² y)
+//   f_t(T) = f(T) + e^{−a(T−t)} (x + B y)
+//   R_t(T) = −ln D_t(T) / τ_t(T)
+//
+// B 與 e^{−a(T−t)} 使用模型時間軸（模型曲線的 day counter，自模型 reference date 起算）；
+// τ_t 為本曲線的 year fraction（reference date 為 t，day counter 沿用 base）。
+// base 不是模型曲線時（例如 forward curve），與模型曲線之間的 basis 視為確定性。
+// 由 HullWhiteModel::conditional_curve / conditional_curve_for 建立。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::model::interestrate::interestratecurve::{
+    DiscountCurve, InstForwardCurve, InterestRateCurve, YearFractionCalculator, ZeroRateCurve,
+    ZERO_TIME_EPSILON,
+};
+use crate::model::shortrate::hullwhite::b_function;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ConditionalState
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone)]
+struct ConditionalState {
+    /// 模型時間軸。
+    model_yfc:      YearFractionCalculator,
+    mean_reversion: f64,
+    /// 狀態日在模型時間軸上的時間 t。
+    state_time:     f64,
+    state:          f64,
+    state_variance: f64,
+}
+
+impl ConditionalState {
+    /// (B(t, T), e^{−a(T−t)})。
+    fn loadings(&self, d: NaiveDate) -> (f64, f64) {
+        let tau = self.model_yfc.year_fraction(d) - self.state_time;
+        (b_function(self.mean_reversion, tau), (-self.mean_reversion * tau).exp())
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Sub-curve implementations
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct HullWhiteConditionalDiscountCurve {
+    yfc:            YearFractionCalculator,
+    conditional:    ConditionalState,
+    state_discount: f64,
+    base:           Arc<dyn DiscountCurve>,
+}
+
+impl DiscountCurve for HullWhiteConditionalDiscountCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn discount(&self, d: NaiveDate) -> f64 {
+        let (b, _) = self.conditional.loadings(d);
+        let conditional = &self.conditional;
+        self.base.discount(d) / self.state_discount
+            * (-b * conditional.state - 0.5 * b * b * conditional.state_variance).exp()
+    }
+}
+
+
+struct HullWhiteConditionalZeroRateCurve {
+    yfc:      YearFractionCalculator,
+    discount: HullWhiteConditionalDiscountCurve,
+    forward:  HullWhiteConditionalInstForwardCurve,
+}
+
+impl ZeroRateCurve for HullWhiteConditionalZeroRateCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn zero_rate(&self, d: NaiveDate) -> f64 {
+        let t = self.yfc.year_fraction(d);
+        if t.abs() < ZERO_TIME_EPSILON {
+            return self.forward.inst_forward(d);
+        }
+        -self.discount.discount(d).ln() / t
+    }
+}
+
+
+struct HullWhiteConditionalInstForwardCurve {
+    yfc:         YearFractionCalculator,
+    conditional: ConditionalState,
+    base:        Arc<dyn InstForwardCurve>,
+}
+
+impl InstForwardCurve for HullWhiteConditionalInstForwardCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn inst_forward(&self, d: NaiveDate) -> f64 {
+        let (b, decay) = self.conditional.loadings(d);
+        let conditional = &self.conditional;
+        self.base.inst_forward(d) + decay * (conditional.state + b * conditional.state_variance)
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// HullWhiteConditionalCurve
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct HullWhiteConditionalCurve {
+    yfc:            YearFractionCalculator,
+    base:           Arc<dyn InterestRateCurve>,
+    conditional:    ConditionalState,
+    state_discount: f64,
+}
+
+impl HullWhiteConditionalCurve {
+    /// - `model_yfc`：模型時間軸（模型曲線的 YearFractionCalculator）
+    /// - `state_variance`：y(t)
+    pub fn new(
+        base:           Arc<dyn InterestRateCurve>,
+        model_yfc:      YearFractionCalculator,
+        mean_reversion: f64,
+        state_date:     NaiveDate,
+        state:          f64,
+        state_variance: f64,
+    ) -> Self {
+        let state_discount = base.to_discount_curve().discount(state_date);
+        let conditional = ConditionalState {
+            state_time: model_yfc.year_fraction(state_date),
+            model_yfc,
+            mean_reversion,
+            state,
+            state_variance,
+        };
+        Self {
+            yfc: YearFractionCalculator::new(state_date, base.day_counter().clone()),
+            base,
+            conditional,
+            state_discount,
+        }
+    }
+
+    pub fn base_curve(&self) -> &Arc<dyn InterestRateCurve> { &self.base }
+    pub fn state_date(&self) -> NaiveDate { self.yfc.reference_date() }
+    pub fn state(&self) -> f64 { self.conditional.state }
+    pub fn state_variance(&self) -> f64 { self.conditional.state_variance }
+
+    fn discount_curve(&self) -> HullWhiteConditionalDiscountCurve {
+        HullWhiteConditionalDiscountCurve {
+            yfc:            self.yfc.clone(),
+            conditional:    self.conditional.clone(),
+            state_discount: self.state_discount,
+            base:           self.base.to_discount_curve(),
+        }
+    }
+
+    fn inst_forward_curve(&self) -> HullWhiteConditionalInstForwardCurve {
+        HullWhiteConditionalInstForwardCurve {
+            yfc:         self.yfc.clone(),
+            conditional: self.conditional.clone(),
+            base:        self.base.to_inst_forward_curve(),
+        }
+    }
+}
+
+impl InterestRateCurve for HullWhiteConditionalCurve {
+    fn year_fraction_calculator(&self) -> &YearFractionCalculator { &self.yfc }
+
+    fn to_discount_curve(&self) -> Arc<dyn DiscountCurve> {
+        Arc::new(self.discount_curve())
+    }
+
+    fn to_zero_rate_curve(&self) -> Arc<dyn ZeroRateCurve> {
+        Arc::new(HullWhiteConditionalZeroRateCurve {
+            yfc:      self.yfc.clone(),
+            discount: self.discount_curve(),
+            forward:  self.inst_forward_curve(),
+        })
+    }
+
+    fn to_inst_forward_curve(&self) -> Arc<dyn InstForwardCurve> {
+        Arc::new(self.inst_forward_curve())
+    }
+}
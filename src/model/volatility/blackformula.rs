@@ -0,0 +1,241 @@
+// ── blackformula.rs ───────────────────────────────────────────────────────────
+//
+// 利率選擇權（swaption、cap / floor）共用的 forward 選擇權公式。
+// 令 F 為 forward rate、K 為 strike、τ 為 horizon 至到期日的年化時間、ω = ±1：
+//
+//   ShiftedLognormal（Black，位移 s）：F̃ = F + s，K̃ = K + s，σ̂ = σ √τ
+//     d1 = (ln(F̃ / K̃) + σ̂² / 2) / σ̂，d2 = d1 − σ̂
+//     V  = ω [F̃ N(ω d1) − K̃ N(ω d2)]
+//   Normal（Bachelier）：σ̂ = σ √τ，d = (F − K) / σ̂
+//     V  = ω (F − K) N(ω d) + σ̂ n(d)
+//
+// V 為未折現、每單位 annuity 的價值；乘上 annuity（或 caplet 的 τ · DF）即為現值。
+// s = 0 時即為標準 Black 公式。
+//
+// # Implied volatility
+//
+// V 對 σ 嚴格遞增：σ = 0 時為內含價值，σ → ∞ 時 lognormal 趨近 F̃（call）/ K̃（put）、
+// normal 無上界。先自初始上界倍增至 V(σ_max) 超過目標價，再以 RootSolver 在 [0, σ_max]
+// 內求解（有 bracket 時走 Brent）；同一流程以 solve_volatility 提供給 cap 的 flat vol
+// 與 caplet stripping 等「價值對 vol 遞增」的問題。
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::instrument::instrument::OptionType;
+use crate::math::normaldistribution::{normal_cdf, normal_pdf};
+use crate::math::rootsolver::{RootSolver, RootSolverError};
+
+
+/// implied vol 求解時上界的最大倍增次數。
+const UPPER_BOUND_MAX_DOUBLINGS: usize = 64;
+/// 價格與內含價值之差小於此值時視為 σ = 0（吸收報價的捨入誤差）。
+const INTRINSIC_PRICE_EPSILON: f64 = 1e-12;
+
+
+/// 求解 `objective(σ) = 0`，objective 須對 σ 遞增且 objective(0) ≤ 0：
+/// 自 `initial_upper` 倍增至 objective 為正，再於 [0, σ_max] 內以 RootSolver 求解。
+pub fn solve_volatility<F>(objective: F, initial_upper: f64, solver: &RootSolver) -> Result<f64, RootSolverError>
+where
+    F: Fn(f64) -> f64,
+{
+    let mut upper = initial_upper;
+    for _ in 0..UPPER_BOUND_MAX_DOUBLINGS {
+        if objective(upper) > 0.0 {
+            break;
+        }
+        upper *= 2.0;
+    }
+    solver.solve(objective, 0.0, Some(upper))
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// VolatilityType
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// JSON：`{"type": "Normal"}` 或 `{"type": "ShiftedLognormal", "shift": 0.01}`。
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(tag = "type")]
+pub enum VolatilityType {
+    /// 位移 lognormal（Black），shift 為加在 forward 與 strike 上的位移。
+    ShiftedLognormal { shift: f64 },
+    /// normal（Bachelier），vol 以利率絕對值表示。
+    Normal,
+}
+
+impl VolatilityType {
+    /// 無位移的 Black。
+    pub fn lognormal() -> Self {
+        VolatilityType::ShiftedLognormal { shift: 0.0 }
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// BlackFormulaError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum BlackFormulaError {
+    #[error("shifted forward {forward} or strike {strike} is not positive (shift {shift})")]
+    NonPositiveShiftedRate {
+        forward: f64,
+        strike:  f64,
+        shift:   f64,
+    },
+
+    #[error("price {price} is outside the no-arbitrage range [{lower}, {upper})")]
+    PriceOutOfRange {
+        price: f64,
+        lower: f64,
+        upper: f64,
+    },
+
+    #[error("option has expired (expiry time {0})")]
+    Expired(f64),
+
+    #[error("implied volatility solve failed: {0}")]
+    Solver(#[from] RootSolverError),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// BlackFormula
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct BlackFormula {
+    volatility_type: VolatilityType,
+    forward:         f64,
+    expiry_time:     f64,
+}
+
+impl BlackFormula {
+    /// - `forward`：forward swap rate / forward rate
+    /// - `expiry_time`：horizon 至到期日的年化時間
+    pub fn new(volatility_type: VolatilityType, forward: f64, expiry_time: f64) -> Self {
+        Self {
+            volatility_type,
+            forward,
+            expiry_time,
+        }
+    }
+
+    pub fn volatility_type(&self) -> VolatilityType { self.volatility_type }
+    pub fn forward(&self) -> f64 { self.forward }
+    pub fn expiry_time(&self) -> f64 { self.expiry_time }
+
+    /// σ̂ = σ √τ（已到期時為 0）。
+    pub fn std_dev(&self, volatility: f64) -> f64 {
+        volatility * self.expiry_time.max(0.0).sqrt()
+    }
+
+    /// 內含價值 ω (F − K)⁺。
+    pub fn intrinsic_value(&self, option_type: OptionType, strike: f64) -> f64 {
+        (option_type.sign() * (self.forward - strike)).max(0.0)
+    }
+
+    /// 未折現價值；lognormal 下位移後的 F 或 K 非正時回傳 NaN。
+    pub fn price(&self, option_type: OptionType, strike: f64, volatility: f64) -> f64 {
+        let omega = option_type.sign();
+        let std_dev = self.std_dev(volatility);
+        match self.volatility_type {
+            VolatilityType::ShiftedLognormal { shift } => {
+                let forward = self.forward + shift;
+                let strike = strike + shift;
+                if forward <= 0.0 || strike <= 0.0 {
+                    return f64::NAN;
+                }
+                if std_dev <= 0.0 {
+                    return (omega * (forward - strike)).max(0.0);
+                }
+                let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+                let d2 = d1 - std_dev;
+                omega * (forward * normal_cdf(omega * d1) - strike * normal_cdf(omega * d2))
+            }
+            VolatilityType::Normal => {
+                let moneyness = self.forward - strike;
+                if std_dev <= 0.0 {
+                    return (omega * moneyness).max(0.0);
+                }
+                let d = moneyness / std_dev;
+                omega * moneyness * normal_cdf(omega * d) + std_dev * normal_pdf(d)
+            }
+        }
+    }
+
+    /// vega（每 1.00 vol，未折現）。
+    pub fn vega(&self, strike: f64, volatility: f64) -> f64 {
+        let sqrt_time = self.expiry_time.max(0.0).sqrt();
+        let std_dev = self.std_dev(volatility);
+        match self.volatility_type {
+            VolatilityType::ShiftedLognormal { shift } => {
+                let forward = self.forward + shift;
+                let strike = strike + shift;
+                if forward <= 0.0 || strike <= 0.0 || std_dev <= 0.0 {
+                    return 0.0;
+                }
+                let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+                forward * normal_pdf(d1) * sqrt_time
+            }
+            VolatilityType::Normal => {
+                if std_dev <= 0.0 {
+                    return 0.0;
+                }
+                normal_pdf((self.forward - strike) / std_dev) * sqrt_time
+            }
+        }
+    }
+
+    /// 由未折現價值反推 implied vol。
+    ///
+    /// # Errors
+    ///
+    /// - 已到期、lognormal 下位移後的 F 或 K 非正
+    /// - 價格不在 [內含價值, 上界) 內
+    /// - RootSolver 求解失敗
+    pub fn implied_volatility(
+        &self,
+        option_type: OptionType,
+        strike:      f64,
+        price:       f64,
+        solver:      &RootSolver,
+    ) -> Result<f64, BlackFormulaError> {
+        if self.expiry_time <= 0.0 {
+            return Err(BlackFormulaError::Expired(self.expiry_time));
+        }
+        let (upper_price, initial_upper) = match self.volatility_type {
+            VolatilityType::ShiftedLognormal { shift } => {
+                let forward = self.forward + shift;
+                let shifted_strike = strike + shift;
+                if forward <= 0.0 || shifted_strike <= 0.0 {
+                    return Err(BlackFormulaError::NonPositiveShiftedRate {
+                        forward: self.forward,
+                        strike,
+                        shift,
+                    });
+                }
+                let upper_price = match option_type {
+                    OptionType::Call => forward,
+                    OptionType::Put  => shifted_strike,
+                };
+                (upper_price, 1.0)
+            }
+            VolatilityType::Normal => {
+                let scale = self.forward.abs().max(strike.abs()).max(0.01);
+                (f64::INFINITY, scale)
+            }
+        };
+
+        let lower_price = self.intrinsic_value(option_type, strike);
+        if !(price >= lower_price - INTRINSIC_PRICE_EPSILON && price < upper_price) {
+            return Err(BlackFormulaError::PriceOutOfRange { price, lower: lower_price, upper: upper_price });
+        }
+        if price - lower_price <= INTRINSIC_PRICE_EPSILON {
+            return Ok(0.0);
+        }
+
+        let objective = |volatility: f64| self.price(option_type, strike, volatility) - price;
+        Ok(solve_volatility(objective, initial_upper, solver)?)
+    }
+}
@@ -0,0 +1,283 @@
+// ── capletvolatilitystripper.rs ───────────────────────────────────────────────
+//
+// 由 cap 的 flat vol 報價逐段剝離 caplet vol term structure（piecewise constant）：
+//
+//   1. 報價依 cap 到期期限排序；strike 為 None 者取 ATM（CapFloorPricer::atm_strike）
+//   2. 目標價 = 以 flat vol 評價整個 cap（CapFloorPricer::value_with_flat_volatility）
+//   3. 到期日不晚於前一段終點的 caplet 以已剝離的 vol 評價；其餘「新」caplet 共用
+//      一個待求的 vol σ_k，解
+//
+//        Σ_known caplet(σ(T_i)) + Σ_new caplet(σ_k) = 目標價
+//
+//   4. 第 k 段終點為最後一個新 caplet 的到期日
+//
+// 各報價的 strike 可不同（例如各自的 ATM），剝出的曲線不隨 strike 變化，
+// 是常見的 ATM caplet curve 簡化；smile 需另以各 strike 分別剝離。
+// 後置複利 caplet 的到期日為計息期間結束日，vol 衰減由 CapFloorPricer 的 variance time 處理。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::instrument::instrument::Position;
+use crate::instrument::interestrate::capfloor::{CapFloor, CapFloorGenerator, CapFloorType};
+use crate::marketdata::marketdataset::MarketDataSet;
+use crate::math::rootsolver::{RootSolver, RootSolverConfig, RootSolverError};
+use crate::model::volatility::blackformula::{solve_volatility, VolatilityType};
+use crate::model::volatility::interestratevolatility::InterestRateVolatility;
+use crate::pricer::capfloorpricer::{initial_volatility_upper, Caplet, CapFloorPricer};
+use crate::pricingcondition::PricingCondition;
+use crate::time::period::Period;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CapletVolatilityStripperError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum CapletVolatilityStripperError {
+    #[error("no cap volatility quotes")]
+    MissingQuotes,
+
+    #[error("cap generation failed: {0}")]
+    Generation(String),
+
+    #[error("discount or forward curve of the cap is missing")]
+    MissingMarketData,
+
+    #[error("cap {0} adds no unfixed caplet beyond the previous quote")]
+    NoNewCaplets(String),
+
+    #[error("cap {maturity} flat value {target} is below the value {lower} implied by earlier quotes")]
+    InconsistentQuote {
+        maturity: String,
+        target:   f64,
+        lower:    f64,
+    },
+
+    #[error("caplet volatility solve for cap {maturity} failed: {source}")]
+    Solver {
+        maturity: String,
+        source:   RootSolverError,
+    },
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CapVolatilityQuote
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// cap 的 flat vol 報價；strike 為 None 表示 ATM cap。
+#[derive(Clone, Copy)]
+pub struct CapVolatilityQuote {
+    pub maturity:   Period,
+    pub volatility: f64,
+    pub strike:     Option<f64>,
+}
+
+impl CapVolatilityQuote {
+    pub fn atm(maturity: Period, volatility: f64) -> Self {
+        Self { maturity, volatility, strike: None }
+    }
+
+    pub fn with_strike(maturity: Period, volatility: f64, strike: f64) -> Self {
+        Self { maturity, volatility, strike: Some(strike) }
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CapletVolatilityCurve
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 依 caplet 到期日的 piecewise constant vol：第 k 段涵蓋 (expiry_dates[k−1], expiry_dates[k]]，
+/// 最後一段之後平坦外插；與 tenor、forward、strike 無關。
+pub struct CapletVolatilityCurve {
+    volatility_type: VolatilityType,
+    expiry_dates:    Vec<NaiveDate>,
+    volatilities:    Vec<f64>,
+}
+
+impl CapletVolatilityCurve {
+    pub fn new(volatility_type: VolatilityType, expiry_dates: Vec<NaiveDate>, volatilities: Vec<f64>) -> Self {
+        Self { volatility_type, expiry_dates, volatilities }
+    }
+
+    pub fn expiry_dates(&self) -> &[NaiveDate] { &self.expiry_dates }
+    pub fn volatilities(&self) -> &[f64] { &self.volatilities }
+
+    /// 空曲線時為 None。
+    fn segment_volatility(&self, expiry_date: NaiveDate) -> Option<f64> {
+        let k = self.expiry_dates.partition_point(|&end_date| end_date < expiry_date);
+        self.volatilities.get(k).or(self.volatilities.last()).copied()
+    }
+}
+
+impl InterestRateVolatility for CapletVolatilityCurve {
+    fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+
+    /// 空曲線時為 NaN。
+    fn volatility(&self, expiry_date: NaiveDate, _tenor: f64, _forward: f64, _strike: f64) -> f64 {
+        self.segment_volatility(expiry_date).unwrap_or(f64::NAN)
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CapletVolatilityStripper
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct CapletVolatilityStripper {
+    root_solver_config: RootSolverConfig,
+    generator:          Arc<CapFloorGenerator>,
+    volatility_type:    VolatilityType,
+}
+
+impl CapletVolatilityStripper {
+    /// `volatility_type`：報價與剝出的 caplet vol 的類型。
+    pub fn new(
+        root_solver_config: RootSolverConfig,
+        generator:          Arc<CapFloorGenerator>,
+        volatility_type:    VolatilityType,
+    ) -> Self {
+        Self { root_solver_config, generator, volatility_type }
+    }
+
+    /// 使用預設 RootSolverConfig 的建構方式。
+    pub fn with_defaults(generator: Arc<CapFloorGenerator>, volatility_type: VolatilityType) -> Self {
+        Self::new(RootSolverConfig::default(), generator, volatility_type)
+    }
+
+    pub fn generator(&self) -> &Arc<CapFloorGenerator> { &self.generator }
+    pub fn volatility_type(&self) -> VolatilityType { self.volatility_type }
+
+    /// 產生報價對應的 cap（買方）；ATM 報價先以 strike 0 產生，再以 ATM strike 重新產生。
+    pub fn quote_cap(
+        &self,
+        quote:             &CapVolatilityQuote,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Result<CapFloor, CapletVolatilityStripperError> {
+        let generate = |strike: f64| {
+            self.generator
+                .generate_with_maturity_tenor(
+                    Position::Buy,
+                    CapFloorType::Cap,
+                    strike,
+                    *pricing_condition.horizon(),
+                    quote.maturity,
+                    None,
+                )
+                .map_err(CapletVolatilityStripperError::Generation)
+        };
+        match quote.strike {
+            Some(strike) => generate(strike),
+            None => {
+                let probe = generate(0.0)?;
+                let atm_strike = CapFloorPricer::atm_strike(&probe, market_data, pricing_condition)
+                    .ok_or(CapletVolatilityStripperError::NoNewCaplets(quote.maturity.to_string()))?;
+                generate(atm_strike)
+            }
+        }
+    }
+
+    /// 剝離 caplet vol；報價的順序不拘。
+    ///
+    /// # Errors
+    ///
+    /// - 無報價、cap 產生失敗或曲線不存在
+    /// - 某報價未新增任何未決定的 caplet（期限重複或過短）
+    /// - 報價低於前段 caplet 已隱含的價值，或求解失敗
+    pub fn strip(
+        &self,
+        quotes:            &[CapVolatilityQuote],
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Result<CapletVolatilityCurve, CapletVolatilityStripperError> {
+        if quotes.is_empty() {
+            return Err(CapletVolatilityStripperError::MissingQuotes);
+        }
+        let horizon = *pricing_condition.horizon();
+        let mut sorted_quotes = quotes.to_vec();
+        sorted_quotes.sort_by_key(|quote| horizon + quote.maturity);
+
+        let solver = RootSolver::new(self.root_solver_config.clone());
+        let mut curve = CapletVolatilityCurve::new(self.volatility_type, Vec::new(), Vec::new());
+        for quote in &sorted_quotes {
+            let cap = self.quote_cap(quote, market_data, pricing_condition)?;
+            let (segment_end, volatility) = self.strip_segment(&cap, quote, &curve, market_data, pricing_condition, &solver)?;
+            curve.expiry_dates.push(segment_end);
+            curve.volatilities.push(volatility);
+        }
+        Ok(curve)
+    }
+
+    /// 求解下一段的 vol，回傳 (段終點, vol)。
+    fn strip_segment(
+        &self,
+        cap:               &CapFloor,
+        quote:             &CapVolatilityQuote,
+        curve:             &CapletVolatilityCurve,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+        solver:            &RootSolver,
+    ) -> Result<(NaiveDate, f64), CapletVolatilityStripperError> {
+        let target = CapFloorPricer::value_with_flat_volatility(
+            cap,
+            market_data,
+            pricing_condition,
+            self.volatility_type,
+            quote.volatility,
+        )
+        .ok_or(CapletVolatilityStripperError::MissingMarketData)?;
+
+        let previous_end = curve.expiry_dates.last().copied();
+        let option_type = CapFloorType::Cap.option_type();
+        let (known, new): (Vec<Caplet>, Vec<Caplet>) = CapFloorPricer::caplets(cap, market_data, pricing_condition)
+            .ok_or(CapletVolatilityStripperError::MissingMarketData)?
+            .into_iter()
+            .filter(|caplet| !caplet.paid)
+            .partition(|caplet| caplet.is_fixed() || previous_end.is_some_and(|end| caplet.expiry_date <= end));
+        let segment_end = new
+            .iter()
+            .map(|caplet| caplet.expiry_date)
+            .max()
+            .ok_or(CapletVolatilityStripperError::NoNewCaplets(quote.maturity.to_string()))?;
+
+        // 已決定的 caplet 為內含價值，不查詢 vol（第一個報價時曲線仍為空）；
+        // 其餘已知 caplet 都落在前段內，曲線必不為空
+        let known_value = known
+            .iter()
+            .map(|caplet| {
+                let volatility = if caplet.is_fixed() {
+                    0.0
+                } else {
+                    curve.segment_volatility(caplet.expiry_date).unwrap_or(f64::NAN)
+                };
+                caplet.value(self.volatility_type, option_type, volatility)
+            })
+            .sum::<f64>();
+        let value = |volatility: f64| {
+            known_value
+                + new
+                    .iter()
+                    .map(|caplet| caplet.value(self.volatility_type, option_type, volatility))
+                    .sum::<f64>()
+        };
+        let lower = value(0.0);
+        if target < lower {
+            return Err(CapletVolatilityStripperError::InconsistentQuote { maturity: quote.maturity.to_string(), target, lower });
+        }
+
+        let volatility = solve_volatility(
+            |volatility| value(volatility) - target,
+            initial_volatility_upper(self.volatility_type),
+            solver,
+        )
+        .map_err(|source| CapletVolatilityStripperError::Solver { maturity: quote.maturity.to_string(), source })?;
+        Ok((segment_end, volatility))
+    }
+}
@@ -0,0 +1,47 @@
+// ── interestratevolatility.rs ─────────────────────────────────────────────────
+//
+// 利率選擇權（swaption、cap / floor）共用的 vol 來源：依到期日、underlying 年限、
+// forward 與 strike 查詢，vol 的意義（normal / shifted lognormal）由 volatility_type 決定。
+//
+// underlying 年限：swaption 為 underlying swap 的年限，caplet 為其計息期間長度。
+//
+// 實作：FlatInterestRateVolatility（單一 vol）、SwaptionVolatilityCube（ATM 矩陣 + smile）、
+// CapletVolatilityCurve（由 cap 報價剝離的 caplet vol）。
+// 實際使用的 vol 存放於 MarketDataSet 的 volatility 區塊，key 由 pricer 指定。
+
+use chrono::NaiveDate;
+
+use crate::model::volatility::blackformula::VolatilityType;
+
+
+pub trait InterestRateVolatility: Send + Sync {
+    fn volatility_type(&self) -> VolatilityType;
+
+    fn volatility(&self, expiry_date: NaiveDate, tenor: f64, forward: f64, strike: f64) -> f64;
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// FlatInterestRateVolatility
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct FlatInterestRateVolatility {
+    volatility_type: VolatilityType,
+    volatility:      f64,
+}
+
+impl FlatInterestRateVolatility {
+    pub fn new(volatility_type: VolatilityType, volatility: f64) -> Self {
+        Self { volatility_type, volatility }
+    }
+}
+
+impl InterestRateVolatility for FlatInterestRateVolatility {
+    fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+
+    fn volatility(&self, _expiry_date: NaiveDate, _tenor: f64, _forward: f64, _strike: f64) -> f64 {
+        self.volatility
+    }
+}
@@ -0,0 +1,469 @@
+// ── sabr.rs ───────────────────────────────────────────────────────────────────
+//
+// SABR stochastic volatility model（Hagan, Kumar, Lesniewski & Woodward, 2002）：
+//
+//   dF = α̂ F^β dW₁，dα̂ = ν α̂ dW₂，⟨dW₁, dW₂⟩ = ρ dt，α̂(0) = α
+//
+// Black（lognormal）implied vol 的 Hagan 展開，令 m = ln(F / K)、f = (F K)^{(1−β)/2}：
+//
+//   σ_B(K) = α / [f (1 + (1−β)² m² / 24 + (1−β)⁴ m⁴ / 1920)] · z / x(z)
+//            · [1 + ((1−β)² α² / (24 f²) + ρ β ν α / (4 f) + (2 − 3ρ²) ν² / 24) τ]
+//   z = ν f m / α，x(z) = ln[(√(1 − 2ρz + z²) + z − ρ) / (1 − ρ)]
+//
+// Normal（Bachelier）implied vol 的 Hagan 展開，令 ζ = (F^{1−β} − K^{1−β}) / (1−β)
+// （β = 1 時為 ln(F / K)）、f = √(F K)：
+//
+//   σ_N(K) = α (F − K) / ζ · z / x(z)
+//            · [1 + (β (β − 2) α² / (24 f^{2−2β}) + ρ β ν α / (4 f^{1−β}) + (2 − 3ρ²) ν² / 24) τ]
+//   z = ν ζ / α；F → K 時 (F − K) / ζ → f^β
+//
+// Shifted SABR：以 F + s、K + s 取代 F、K（s 為 SabrParameters::shift），使負利率下仍可使用
+// β > 0；lognormal 展開此時給出位移 s 的 shifted Black vol，normal 展開不受位移影響其意義。
+//
+// # Calibration（SabrCalibrator）
+//
+// β 固定，最小化 ½ Σ (σ_B(K_i) − σ_i)²，兩階段（argmin）：
+//   1. Nelder-Mead：不需導數，對初始值不敏感
+//   2. TrustRegion + Steihaug（Gauss-Newton Hessian JᵀJ，前向差分 Jacobian）：
+//      Nelder-Mead 的精度受限於目標函數值的差異（參數約 1e-7），以此修正到殘差的數值精度
+// 報價可為 lognormal 或 normal vol（VolatilityType）：ShiftedLognormal 報價的 SABR 位移
+// 固定為報價的 shift，Normal 報價的位移取 SabrCalibratorConfig::shift。
+// 參數轉換確保限制：α = e^a、ν = e^n、ρ = tanh(r)，最佳化在無限制空間進行。
+
+use argmin::core::{CostFunction, Error as ArgminError, Executor, Gradient, Hessian, State};
+use argmin::solver::neldermead::NelderMead;
+use argmin::solver::trustregion::{Steihaug, TrustRegion};
+use nalgebra::{DMatrix, DVector};
+use thiserror::Error;
+
+use crate::model::volatility::blackformula::VolatilityType;
+
+
+/// SABR calibration 所需的最少報價數（α、ρ、ν 三個參數）。
+const MINIMUM_QUOTES: usize = 3;
+/// |z| 小於此值時以 z / x(z) 的一階展開 1 − ρz / 2 取代。
+const SMALL_Z: f64 = 1e-7;
+/// ρ = tanh(r) 的 r 上限，避免 ρ = ±1 使 x(z) 發散。
+const MAX_TANH_ARGUMENT: f64 = 10.0;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SabrError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum SabrError {
+    #[error("SABR calibration needs at least {required} quotes, got {count}")]
+    InsufficientQuotes { count: usize, required: usize },
+
+    #[error("strikes and volatilities differ in length ({strikes} vs {volatilities})")]
+    LengthMismatch { strikes: usize, volatilities: usize },
+
+    #[error("SABR optimization failed: {0}")]
+    Optimization(String),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SabrParameters
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SabrParameters {
+    alpha: f64,
+    beta:  f64,
+    rho:   f64,
+    nu:    f64,
+    shift: f64,
+}
+
+impl SabrParameters {
+    /// 無位移的 SABR；shifted SABR 以 [`with_shift`](Self::with_shift) 設定位移。
+    pub fn new(alpha: f64, beta: f64, rho: f64, nu: f64) -> Self {
+        Self { alpha, beta, rho, nu, shift: 0.0 }
+    }
+
+    pub fn with_shift(mut self, shift: f64) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    pub fn alpha(&self) -> f64 { self.alpha }
+    pub fn beta(&self) -> f64 { self.beta }
+    pub fn rho(&self) -> f64 { self.rho }
+    pub fn nu(&self) -> f64 { self.nu }
+    pub fn shift(&self) -> f64 { self.shift }
+
+    /// 依 vol 類型回傳 Hagan 展開的 implied vol。
+    ///
+    /// ShiftedLognormal 時回傳位移為 `self.shift` 的 Black vol（calibration 確保與報價的 shift 一致）。
+    pub fn volatility(&self, volatility_type: VolatilityType, forward: f64, strike: f64, expiry_time: f64) -> f64 {
+        match volatility_type {
+            VolatilityType::ShiftedLognormal { .. } => self.lognormal_volatility(forward, strike, expiry_time),
+            VolatilityType::Normal                  => self.normal_volatility(forward, strike, expiry_time),
+        }
+    }
+
+    /// Hagan lognormal 展開的（shifted）Black vol；位移後的 F 或 K 非正時回傳 NaN。
+    pub fn lognormal_volatility(&self, forward: f64, strike: f64, expiry_time: f64) -> f64 {
+        let forward = forward + self.shift;
+        let strike = strike + self.shift;
+        if forward <= 0.0 || strike <= 0.0 {
+            return f64::NAN;
+        }
+        let one_minus_beta = 1.0 - self.beta;
+        let log_moneyness = (forward / strike).ln();
+        let f = (forward * strike).powf(0.5 * one_minus_beta);
+
+        let m2 = log_moneyness * log_moneyness;
+        let b2 = one_minus_beta * one_minus_beta;
+        let denominator = f * (1.0 + b2 * m2 / 24.0 + b2 * b2 * m2 * m2 / 1920.0);
+
+        let z = self.nu / self.alpha * f * log_moneyness;
+        let z_over_x = if z.abs() < SMALL_Z {
+            1.0 - 0.5 * self.rho * z
+        } else {
+            let x = (((1.0 - 2.0 * self.rho * z + z * z).sqrt() + z - self.rho) / (1.0 - self.rho)).ln();
+            z / x
+        };
+
+        let correction = 1.0
+            + (b2 * self.alpha * self.alpha / (24.0 * f * f)
+                + 0.25 * self.rho * self.beta * self.nu * self.alpha / f
+                + (2.0 - 3.0 * self.rho * self.rho) * self.nu * self.nu / 24.0)
+                * expiry_time;
+
+        self.alpha / denominator * z_over_x * correction
+    }
+
+    /// Hagan normal 展開的 Bachelier vol；位移後的 F 或 K 非正時回傳 NaN。
+    pub fn normal_volatility(&self, forward: f64, strike: f64, expiry_time: f64) -> f64 {
+        let forward = forward + self.shift;
+        let strike = strike + self.shift;
+        if forward <= 0.0 || strike <= 0.0 {
+            return f64::NAN;
+        }
+        let one_minus_beta = 1.0 - self.beta;
+        let f = (forward * strike).sqrt();
+
+        let zeta = if one_minus_beta.abs() < SMALL_Z {
+            (forward / strike).ln()
+        } else {
+            (forward.powf(one_minus_beta) - strike.powf(one_minus_beta)) / one_minus_beta
+        };
+        let scale = if (forward - strike).abs() < SMALL_Z * f {
+            f.powf(self.beta)
+        } else {
+            (forward - strike) / zeta
+        };
+
+        let z = self.nu / self.alpha * zeta;
+        let z_over_x = if z.abs() < SMALL_Z {
+            1.0 - 0.5 * self.rho * z
+        } else {
+            let x = (((1.0 - 2.0 * self.rho * z + z * z).sqrt() + z - self.rho) / (1.0 - self.rho)).ln();
+            z / x
+        };
+
+        let f_power = f.powf(one_minus_beta);
+        let correction = 1.0
+            + (self.beta * (self.beta - 2.0) * self.alpha * self.alpha / (24.0 * f_power * f_power)
+                + 0.25 * self.rho * self.beta * self.nu * self.alpha / f_power
+                + (2.0 - 3.0 * self.rho * self.rho) * self.nu * self.nu / 24.0)
+                * expiry_time;
+
+        self.alpha * scale * z_over_x * correction
+    }
+
+    // ── 無限制參數空間 ───────────────────────────────────────────────────────
+
+    fn to_unconstrained(self) -> DVector<f64> {
+        DVector::from_vec(vec![self.alpha.ln(), self.rho.atanh(), self.nu.ln()])
+    }
+
+    fn from_unconstrained(values: &DVector<f64>, beta: f64, shift: f64) -> Self {
+        let rho_argument = values[1].clamp(-MAX_TANH_ARGUMENT, MAX_TANH_ARGUMENT);
+        Self::new(values[0].exp(), beta, rho_argument.tanh(), values[2].exp()).with_shift(shift)
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SabrProblem（argmin 目標函數）
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct SabrProblem<'a> {
+    volatility_type: VolatilityType,
+    forward:         f64,
+    expiry_time:     f64,
+    beta:            f64,
+    shift:           f64,
+    strikes:         &'a [f64],
+    volatilities:    &'a [f64],
+    fd_step:         f64,
+}
+
+impl SabrProblem<'_> {
+    /// σ_B(K_i) − σ_i；展開失效（NaN）時回傳 None。
+    fn residuals(&self, values: &DVector<f64>) -> Option<DVector<f64>> {
+        let parameters = SabrParameters::from_unconstrained(values, self.beta, self.shift);
+        let residuals = DVector::from_iterator(
+            self.strikes.len(),
+            self.strikes
+                .iter()
+                .zip(self.volatilities)
+                .map(|(&strike, &volatility)| {
+                    parameters.volatility(self.volatility_type, self.forward, strike, self.expiry_time) - volatility
+                }),
+        );
+        residuals.iter().all(|r| r.is_finite()).then_some(residuals)
+    }
+
+    /// 前向差分 Jacobian ∂r_i / ∂v_j。
+    fn residuals_and_jacobian(
+        &self,
+        values: &DVector<f64>,
+    ) -> Result<(DVector<f64>, DMatrix<f64>), ArgminError> {
+        let failed = || ArgminError::msg("SABR expansion is not finite");
+        let residuals = self.residuals(values).ok_or_else(failed)?;
+        let mut jacobian = DMatrix::zeros(residuals.len(), values.len());
+        for j in 0..values.len() {
+            let mut bumped = values.clone();
+            bumped[j] += self.fd_step;
+            let bumped_residuals = self.residuals(&bumped).ok_or_else(failed)?;
+            jacobian.set_column(j, &((bumped_residuals - &residuals) / self.fd_step));
+        }
+        Ok((residuals, jacobian))
+    }
+}
+
+impl CostFunction for SabrProblem<'_> {
+    type Param  = DVector<f64>;
+    type Output = f64;
+
+    fn cost(&self, values: &Self::Param) -> Result<Self::Output, ArgminError> {
+        // 展開失效時回傳 +∞，讓 simplex 遠離該區域、trust region 拒絕該步
+        Ok(self.residuals(values).map_or(f64::INFINITY, |r| 0.5 * r.norm_squared()))
+    }
+}
+
+impl Gradient for SabrProblem<'_> {
+    type Param    = DVector<f64>;
+    type Gradient = DVector<f64>;
+
+    fn gradient(&self, values: &Self::Param) -> Result<Self::Gradient, ArgminError> {
+        let (residuals, jacobian) = self.residuals_and_jacobian(values)?;
+        Ok(jacobian.transpose() * residuals)
+    }
+}
+
+impl Hessian for SabrProblem<'_> {
+    type Param   = DVector<f64>;
+    type Hessian = DMatrix<f64>;
+
+    fn hessian(&self, values: &Self::Param) -> Result<Self::Hessian, ArgminError> {
+        let (_, jacobian) = self.residuals_and_jacobian(values)?;
+        Ok(jacobian.transpose() * &jacobian)
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SabrCalibratorConfig
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone)]
+pub struct SabrCalibratorConfig {
+    /// Nelder-Mead 的最大迭代次數。
+    pub max_iter:               u64,
+    /// simplex 各頂點目標函數值的標準差門檻。
+    pub sd_tolerance:           f64,
+    /// 初始 simplex 在 (ln α, atanh ρ, ln ν) 各方向的邊長。
+    pub simplex_step:           f64,
+    /// 未提供初始值時 ν 的起點。
+    pub initial_nu:             f64,
+    /// trust region 修正的最大迭代次數（0 = 只跑 Nelder-Mead）。
+    pub polish_max_iter:        u64,
+    /// Jacobian 前向差分步長（無限制參數空間）。
+    pub finite_difference_step: f64,
+    /// Normal 報價時 SABR 的位移（ShiftedLognormal 報價一律採用報價的 shift）。
+    pub shift:                  f64,
+}
+
+impl Default for SabrCalibratorConfig {
+    fn default() -> Self {
+        Self {
+            max_iter:               2000,
+            sd_tolerance:           f64::EPSILON,
+            simplex_step:           0.25,
+            initial_nu:             0.5,
+            polish_max_iter:        50,
+            finite_difference_step: 1e-7,
+            shift:                  0.0,
+        }
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SabrCalibrator
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct SabrCalibrator {
+    config: SabrCalibratorConfig,
+}
+
+impl SabrCalibrator {
+    pub fn new(config: SabrCalibratorConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn with_defaults() -> Self {
+        Self::new(SabrCalibratorConfig::default())
+    }
+
+    pub fn config(&self) -> &SabrCalibratorConfig { &self.config }
+
+    /// 固定 β，將無位移的 (α, ρ, ν) 擬合到單一到期的 Black vol smile。
+    ///
+    /// 初始值：ρ = 0、ν = `initial_nu`，α 由最接近 forward 的報價 σ 換算（σ F^{1−β}）。
+    ///
+    /// # Errors
+    ///
+    /// - 報價少於 3 筆，或 strikes 與 vols 長度不同
+    /// - Nelder-Mead 執行失敗
+    pub fn calibrate(
+        &self,
+        forward:      f64,
+        expiry_time:  f64,
+        beta:         f64,
+        strikes:      &[f64],
+        volatilities: &[f64],
+    ) -> Result<SabrParameters, SabrError> {
+        self.calibrate_smile(VolatilityType::lognormal(), forward, expiry_time, beta, strikes, volatilities)
+    }
+
+    /// 固定 β，將 (α, ρ, ν) 擬合到 `volatility_type` 報價的 smile（可為 shifted SABR）。
+    ///
+    /// 初始值：ρ = 0、ν = `initial_nu`，α 由最接近 forward 的報價 σ 換算
+    /// （lognormal 為 σ (F + s)^{1−β}，normal 為 σ (F + s)^{−β}）。
+    pub fn calibrate_smile(
+        &self,
+        volatility_type: VolatilityType,
+        forward:         f64,
+        expiry_time:     f64,
+        beta:            f64,
+        strikes:         &[f64],
+        volatilities:    &[f64],
+    ) -> Result<SabrParameters, SabrError> {
+        let shift = match volatility_type {
+            VolatilityType::ShiftedLognormal { shift } => shift,
+            VolatilityType::Normal                     => self.config.shift,
+        };
+        let atm_volatility = strikes
+            .iter()
+            .zip(volatilities)
+            .min_by(|(a, _), (b, _)| (*a - forward).abs().total_cmp(&(*b - forward).abs()))
+            .map_or(f64::NAN, |(_, &volatility)| volatility);
+        let alpha = match volatility_type {
+            VolatilityType::ShiftedLognormal { .. } => atm_volatility * (forward + shift).powf(1.0 - beta),
+            VolatilityType::Normal                  => atm_volatility * (forward + shift).powf(-beta),
+        };
+        let initial = SabrParameters::new(alpha, beta, 0.0, self.config.initial_nu).with_shift(shift);
+        self.calibrate_smile_from(initial, volatility_type, forward, expiry_time, strikes, volatilities)
+    }
+
+    /// 由指定的初始參數開始擬合 Black vol（β 與位移取自 `initial`），適合相鄰到期或重複校準時 warm start。
+    pub fn calibrate_from(
+        &self,
+        initial:      SabrParameters,
+        forward:      f64,
+        expiry_time:  f64,
+        strikes:      &[f64],
+        volatilities: &[f64],
+    ) -> Result<SabrParameters, SabrError> {
+        let volatility_type = VolatilityType::ShiftedLognormal { shift: initial.shift() };
+        self.calibrate_smile_from(initial, volatility_type, forward, expiry_time, strikes, volatilities)
+    }
+
+    /// 由指定的初始參數開始擬合 `volatility_type` 報價（β 取自 `initial`；
+    /// ShiftedLognormal 時位移改用報價的 shift，Normal 時沿用 `initial` 的位移）。
+    pub fn calibrate_smile_from(
+        &self,
+        initial:         SabrParameters,
+        volatility_type: VolatilityType,
+        forward:         f64,
+        expiry_time:     f64,
+        strikes:         &[f64],
+        volatilities:    &[f64],
+    ) -> Result<SabrParameters, SabrError> {
+        let initial = match volatility_type {
+            VolatilityType::ShiftedLognormal { shift } => initial.with_shift(shift),
+            VolatilityType::Normal                     => initial,
+        };
+        if strikes.len() != volatilities.len() {
+            return Err(SabrError::LengthMismatch {
+                strikes:      strikes.len(),
+                volatilities: volatilities.len(),
+            });
+        }
+        if strikes.len() < MINIMUM_QUOTES {
+            return Err(SabrError::InsufficientQuotes { count: strikes.len(), required: MINIMUM_QUOTES });
+        }
+
+        let problem = || SabrProblem {
+            volatility_type,
+            forward,
+            expiry_time,
+            beta:    initial.beta(),
+            shift:   initial.shift(),
+            strikes,
+            volatilities,
+            fd_step: self.config.finite_difference_step,
+        };
+
+        let solver = NelderMead::new(self.simplex(&initial.to_unconstrained()))
+            .with_sd_tolerance(self.config.sd_tolerance)
+            .map_err(|e| SabrError::Optimization(e.to_string()))?;
+        let result = Executor::new(problem(), solver)
+            .configure(|state| state.max_iters(self.config.max_iter))
+            .run()
+            .map_err(|e| SabrError::Optimization(e.to_string()))?;
+        let best = result
+            .state()
+            .get_best_param()
+            .cloned()
+            .ok_or_else(|| SabrError::Optimization("Nelder-Mead returned no parameters".to_string()))?;
+        let best_cost = result.state().get_best_cost();
+
+        let best = match self.polish(problem(), &best) {
+            Some((polished, cost)) if cost < best_cost => polished,
+            _ => best,
+        };
+        Ok(SabrParameters::from_unconstrained(&best, initial.beta(), initial.shift()))
+    }
+
+    /// 以 trust region（Gauss-Newton）自 Nelder-Mead 結果修正；失敗時回傳 None，沿用原結果。
+    fn polish(&self, problem: SabrProblem<'_>, start: &DVector<f64>) -> Option<(DVector<f64>, f64)> {
+        if self.config.polish_max_iter == 0 {
+            return None;
+        }
+        let result = Executor::new(problem, TrustRegion::new(Steihaug::new()))
+            .configure(|state| state.param(start.clone()).max_iters(self.config.polish_max_iter))
+            .run()
+            .ok()?;
+        let cost = result.state().get_best_cost();
+        Some((result.state().get_best_param()?.clone(), cost))
+    }
+
+    /// 以 `center` 為頂點、沿各座標軸延伸 `simplex_step` 的初始 simplex。
+    fn simplex(&self, center: &DVector<f64>) -> Vec<DVector<f64>> {
+        let mut vertices = vec![center.clone()];
+        for i in 0..center.len() {
+            let mut vertex = center.clone();
+            vertex[i] += self.config.simplex_step;
+            vertices.push(vertex);
+        }
+        vertices
+    }
+}
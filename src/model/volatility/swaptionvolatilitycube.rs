@@ -0,0 +1,432 @@
+// ── swaptionvolatilitycube.rs ─────────────────────────────────────────────────
+//
+// Swaption vol cube（expiry × tenor × strike），由 SwaptionVolatilityQuoteSheet 建構：
+//
+//   σ(T_e, n, K) = σ_ATM(τ_e, n) + s(τ_e, n, K − F)
+//
+//   τ_e   — reference date 至到期日的年化時間（volatility_time），到期日依 swap generator
+//           的 SwaptionGenerator::with_defaults 由 expiry tenor 產生
+//   n     — underlying 年限（年）
+//   σ_ATM — ATM 矩陣在 (τ_e, n) 上的雙線性插值
+//   s     — smile，兩種模型：
+//           Spread：smile 格點先在 (τ_e, n) 上雙線性插值出各 strike offset 的 spread，
+//                   再對 K − F 線性插值
+//           SABR  ：各 smile 節點以 SabrCalibrator 擬合 (α, ρ, ν)（β 固定），查詢時在 (τ_e, n)
+//                   上雙線性插值參數，s = σ_SABR(F, K) − σ_SABR(F, F)
+//           沒有 smile 報價時為 0
+//
+// 所有方向在格點外皆為 flat extrapolation。vol 的意義由 volatility_type 決定，
+// swaption 與 cap / floor pricer 透過 InterestRateVolatility 共用。
+//
+// SABR 版本（from_quote_sheet_with_sabr）以 reference date 的曲線計算各節點的 forward swap rate，
+// smile 的 strike 為 F + offset；SABR smile 在 strike 方向平滑，報價範圍外不再是 flat spread。
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::configuration::InterestRateInstrumentGeneratorCollection;
+use crate::instrument::instrument::Position;
+use crate::instrument::interestrate::swaption::{SwaptionError, SwaptionGenerator, SwaptionSettlement};
+use crate::manager::managererror::ManagerError;
+use crate::marketdata::interestrate::swaptionvolatilityquotesheet::{
+    parse_expiry_tenor_key,
+    SwaptionVolatilityQuoteSheet,
+};
+use crate::marketdata::marketdataset::MarketDataSet;
+use crate::model::fx::fxvolatility::volatility_time;
+use crate::model::volatility::blackformula::VolatilityType;
+use crate::model::volatility::interestratevolatility::InterestRateVolatility;
+use crate::model::volatility::sabr::{SabrCalibrator, SabrError, SabrParameters};
+use crate::pricer::swaptionpricer::SwaptionPricer;
+use crate::pricingcondition::PricingCondition;
+use crate::time::period::{Period, TimeUnit};
+
+
+/// Days / Weeks 換算成年的天數。
+const TENOR_DAYS_IN_YEAR: f64 = 365.0;
+
+
+/// tenor 換算成年：Months / 12、Years、Days / 365、Weeks × 7 / 365。
+pub fn tenor_years(tenor: Period) -> f64 {
+    let number = tenor.number() as f64;
+    match tenor.unit() {
+        TimeUnit::Days   => number / TENOR_DAYS_IN_YEAR,
+        TimeUnit::Weeks  => 7.0 * number / TENOR_DAYS_IN_YEAR,
+        TimeUnit::Months => number / 12.0,
+        TimeUnit::Years  => number,
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SwaptionVolatilityCubeError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum SwaptionVolatilityCubeError {
+    #[error(transparent)]
+    Manager(#[from] ManagerError),
+
+    #[error("no {0} swaption volatility quotes")]
+    MissingQuotes(&'static str),
+
+    #[error("failed to parse key \"{0}\": {1}")]
+    KeyParse(String, String),
+
+    #[error("key {0} expires on or before the reference date")]
+    ExpiredTenor(String),
+
+    #[error("{grid} quotes do not form a complete expiry x tenor grid: missing expiry {expiry_time:.4}Y x tenor {tenor}Y")]
+    IncompleteGrid {
+        grid:        &'static str,
+        expiry_time: f64,
+        tenor:       f64,
+    },
+
+    #[error("{grid} quotes contain more than one node at expiry {expiry_time:.4}Y x tenor {tenor}Y")]
+    DuplicateNode {
+        grid:        &'static str,
+        expiry_time: f64,
+        tenor:       f64,
+    },
+
+    #[error("strike offsets must be strictly increasing")]
+    UnsortedStrikeOffsets,
+
+    #[error("smile {key} has {spreads} spreads but {offsets} strike offsets")]
+    SpreadCountMismatch {
+        key:     String,
+        spreads: usize,
+        offsets: usize,
+    },
+
+    #[error(transparent)]
+    Swaption(#[from] SwaptionError),
+
+    #[error("forward swap rate of {0} cannot be computed from the market data")]
+    MissingForward(String),
+
+    #[error("SABR calibration of {key} failed: {source}")]
+    Sabr {
+        key:    String,
+        source: SabrError,
+    },
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// VolatilityGrid
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// expiry × tenor 格點，每個節點存一組值（ATM 為單一 vol，smile 為各 strike offset 的 spread）。
+struct VolatilityGrid {
+    expiry_times: Vec<f64>,
+    tenors:       Vec<f64>,
+    /// values[i][j] 對應 (expiry_times[i], tenors[j])。
+    values:       Vec<Vec<Vec<f64>>>,
+}
+
+impl VolatilityGrid {
+    fn build(
+        grid:  &'static str,
+        nodes: Vec<(f64, f64, Vec<f64>)>,
+    ) -> Result<Self, SwaptionVolatilityCubeError> {
+        let axis = |select: fn(&(f64, f64, Vec<f64>)) -> f64| {
+            let mut axis: Vec<f64> = nodes.iter().map(select).collect();
+            axis.sort_by(f64::total_cmp);
+            axis.dedup();
+            axis
+        };
+        let expiry_times = axis(|node| node.0);
+        let tenors = axis(|node| node.1);
+
+        let mut values = vec![vec![None; tenors.len()]; expiry_times.len()];
+        for (expiry_time, tenor, node_values) in nodes {
+            let i = expiry_times.partition_point(|t| *t < expiry_time);
+            let j = tenors.partition_point(|n| *n < tenor);
+            // 例如 "12M" 與 "1Y" 落在同一節點
+            if values[i][j].replace(node_values).is_some() {
+                return Err(SwaptionVolatilityCubeError::DuplicateNode { grid, expiry_time, tenor });
+            }
+        }
+
+        let values = values
+            .into_iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .map(|(j, node_values)| {
+                        node_values.ok_or(SwaptionVolatilityCubeError::IncompleteGrid {
+                            grid,
+                            expiry_time: expiry_times[i],
+                            tenor:       tenors[j],
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { expiry_times, tenors, values })
+    }
+
+    /// 雙線性插值，格點外 flat extrapolation。
+    fn interpolate(&self, expiry_time: f64, tenor: f64) -> Vec<f64> {
+        let (i0, i1, wi) = interpolation_weight(&self.expiry_times, expiry_time);
+        let (j0, j1, wj) = interpolation_weight(&self.tenors, tenor);
+        let node = |i: usize, j: usize| &self.values[i][j];
+        (0..node(i0, j0).len())
+            .map(|k| {
+                let lower = (1.0 - wj) * node(i0, j0)[k] + wj * node(i0, j1)[k];
+                let upper = (1.0 - wj) * node(i1, j0)[k] + wj * node(i1, j1)[k];
+                (1.0 - wi) * lower + wi * upper
+            })
+            .collect()
+    }
+}
+
+/// 線性插值的 (左節點, 右節點, 右節點權重)；超出範圍時取端點。
+fn interpolation_weight(axis: &[f64], x: f64) -> (usize, usize, f64) {
+    let last = axis.len() - 1;
+    if x <= axis[0] {
+        return (0, 0, 0.0);
+    }
+    if x >= axis[last] {
+        return (last, last, 0.0);
+    }
+    let upper = axis.partition_point(|node| *node <= x);
+    let lower = upper - 1;
+    (lower, upper, (x - axis[lower]) / (axis[upper] - axis[lower]))
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SmileModel
+// ─────────────────────────────────────────────────────────────────────────────
+
+enum SmileModel {
+    /// 各節點為各 strike offset 的 vol spread。
+    Spread(VolatilityGrid),
+    /// 各節點為 (α, ρ, ν)。
+    Sabr {
+        parameters: VolatilityGrid,
+        beta:       f64,
+        shift:      f64,
+    },
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SwaptionVolatilityCube
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct SwaptionVolatilityCube {
+    volatility_type: VolatilityType,
+    reference_date:  NaiveDate,
+    atm:             VolatilityGrid,
+    strike_offsets:  Vec<f64>,
+    smile:           Option<SmileModel>,
+}
+
+impl SwaptionVolatilityCube {
+    /// 以 quote sheet 的 swap generator 產生各 expiry tenor 的到期日，建構 cube。
+    ///
+    /// # Errors
+    ///
+    /// - swap generator 不存在、key 無法解析或到期日不晚於 reference date
+    /// - ATM / smile 報價不構成完整格點或有重複節點、strike offsets 未遞增
+    /// - smile 報價的 spread 個數與 strike offsets 不符
+    pub fn from_quote_sheet(
+        quote_sheet:          &SwaptionVolatilityQuoteSheet,
+        reference_date:       NaiveDate,
+        generator_collection: &InterestRateInstrumentGeneratorCollection,
+    ) -> Result<Self, SwaptionVolatilityCubeError> {
+        let swaption_generator = Self::swaption_generator(quote_sheet, generator_collection)?;
+
+        let node = |key: &String| -> Result<(f64, f64), SwaptionVolatilityCubeError> {
+            let (expiry_tenor, swap_tenor) = parse_expiry_tenor_key(key)
+                .map_err(|e| SwaptionVolatilityCubeError::KeyParse(key.clone(), e))?;
+            let expiry_date = swaption_generator.expiry_date(reference_date, expiry_tenor);
+            if expiry_date <= reference_date {
+                return Err(SwaptionVolatilityCubeError::ExpiredTenor(key.clone()));
+            }
+            Ok((volatility_time(reference_date, expiry_date), tenor_years(swap_tenor)))
+        };
+
+        let atm_nodes = quote_sheet
+            .atm_quotes()
+            .map(|(key, volatility)| {
+                let (expiry_time, tenor) = node(key)?;
+                Ok((expiry_time, tenor, vec![*volatility]))
+            })
+            .collect::<Result<Vec<_>, SwaptionVolatilityCubeError>>()?;
+        if atm_nodes.is_empty() {
+            return Err(SwaptionVolatilityCubeError::MissingQuotes("ATM"));
+        }
+
+        let strike_offsets = quote_sheet.strike_offsets().to_vec();
+        if strike_offsets.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(SwaptionVolatilityCubeError::UnsortedStrikeOffsets);
+        }
+        let smile_nodes = quote_sheet
+            .smile_quotes()
+            .map(|(key, spreads)| {
+                if spreads.len() != strike_offsets.len() {
+                    return Err(SwaptionVolatilityCubeError::SpreadCountMismatch {
+                        key:     key.clone(),
+                        spreads: spreads.len(),
+                        offsets: strike_offsets.len(),
+                    });
+                }
+                let (expiry_time, tenor) = node(key)?;
+                Ok((expiry_time, tenor, spreads.clone()))
+            })
+            .collect::<Result<Vec<_>, SwaptionVolatilityCubeError>>()?;
+        let smile = if smile_nodes.is_empty() || strike_offsets.is_empty() {
+            None
+        } else {
+            Some(SmileModel::Spread(VolatilityGrid::build("smile", smile_nodes)?))
+        };
+
+        Ok(Self {
+            volatility_type: quote_sheet.volatility_type(),
+            reference_date,
+            atm: VolatilityGrid::build("ATM", atm_nodes)?,
+            strike_offsets,
+            smile,
+        })
+    }
+
+    /// 與 [`from_quote_sheet`](Self::from_quote_sheet) 相同的 ATM 矩陣，smile 改由 SABR 表示：
+    /// 各 smile 節點以 `pricing_condition` horizon（即 reference date）的曲線計算 forward swap rate F，
+    /// 將 (F + offset, σ_ATM + spread) 以 `calibrator` 擬合，β 固定為 `beta`。
+    ///
+    /// # Errors
+    ///
+    /// - 同 [`from_quote_sheet`](Self::from_quote_sheet)，且須有 smile 報價
+    /// - underlying swap 無法產生、曲線不存在，或 SABR calibration 失敗
+    pub fn from_quote_sheet_with_sabr(
+        quote_sheet:          &SwaptionVolatilityQuoteSheet,
+        market_data:          &MarketDataSet,
+        pricing_condition:    &PricingCondition,
+        generator_collection: &InterestRateInstrumentGeneratorCollection,
+        calibrator:           &SabrCalibrator,
+        beta:                 f64,
+    ) -> Result<Self, SwaptionVolatilityCubeError> {
+        let reference_date = *pricing_condition.horizon();
+        let mut cube = Self::from_quote_sheet(quote_sheet, reference_date, generator_collection)?;
+        if cube.smile.is_none() {
+            return Err(SwaptionVolatilityCubeError::MissingQuotes("smile"));
+        }
+
+        let swaption_generator = Self::swaption_generator(quote_sheet, generator_collection)?;
+        // forward swap rate 只用到曲線，vol 名稱不會被查詢
+        let pricer = SwaptionPricer::new(quote_sheet.generator_name());
+        let volatility_type = cube.volatility_type;
+
+        let mut sabr_nodes = Vec::new();
+        for (key, spreads) in quote_sheet.smile_quotes() {
+            let (expiry_tenor, swap_tenor) = parse_expiry_tenor_key(key)
+                .map_err(|e| SwaptionVolatilityCubeError::KeyParse(key.clone(), e))?;
+            let swaption = swaption_generator.generate_with_tenor(
+                Position::Buy,
+                0.0,
+                reference_date,
+                expiry_tenor,
+                swap_tenor,
+            )?;
+            let forward = pricer
+                .forward_swap_rate(&swaption, market_data, pricing_condition)
+                .ok_or_else(|| SwaptionVolatilityCubeError::MissingForward(key.clone()))?;
+
+            let expiry_time = volatility_time(reference_date, swaption.expiry_date());
+            let tenor = tenor_years(swap_tenor);
+            let atm_volatility = cube.atm_volatility(swaption.expiry_date(), tenor);
+            let strikes: Vec<f64> = cube.strike_offsets.iter().map(|offset| forward + offset).collect();
+            let volatilities: Vec<f64> = spreads.iter().map(|spread| atm_volatility + spread).collect();
+
+            let parameters = calibrator
+                .calibrate_smile(volatility_type, forward, expiry_time, beta, &strikes, &volatilities)
+                .map_err(|source| SwaptionVolatilityCubeError::Sabr { key: key.clone(), source })?;
+            sabr_nodes.push((expiry_time, tenor, vec![parameters.alpha(), parameters.rho(), parameters.nu()]));
+        }
+
+        let shift = match volatility_type {
+            VolatilityType::ShiftedLognormal { shift } => shift,
+            VolatilityType::Normal                     => calibrator.config().shift,
+        };
+        cube.smile = Some(SmileModel::Sabr {
+            parameters: VolatilityGrid::build("SABR", sabr_nodes)?,
+            beta,
+            shift,
+        });
+        Ok(cube)
+    }
+
+    fn swaption_generator(
+        quote_sheet:          &SwaptionVolatilityQuoteSheet,
+        generator_collection: &InterestRateInstrumentGeneratorCollection,
+    ) -> Result<SwaptionGenerator, SwaptionVolatilityCubeError> {
+        let swap_generator = generator_collection
+            .swap_generator_manager
+            .get(quote_sheet.generator_name())?;
+        Ok(SwaptionGenerator::with_defaults(swap_generator, SwaptionSettlement::Physical))
+    }
+
+    pub fn reference_date(&self) -> NaiveDate { self.reference_date }
+    pub fn strike_offsets(&self) -> &[f64] { &self.strike_offsets }
+
+    pub fn atm_volatility(&self, expiry_date: NaiveDate, tenor: f64) -> f64 {
+        self.atm.interpolate(volatility_time(self.reference_date, expiry_date), tenor)[0]
+    }
+
+    /// SABR smile 時，(到期日, 年限) 上插值後的 SABR 參數；Spread smile 時回傳 None。
+    pub fn sabr_parameters(&self, expiry_date: NaiveDate, tenor: f64) -> Option<SabrParameters> {
+        let Some(SmileModel::Sabr { parameters, beta, shift }) = &self.smile else {
+            return None;
+        };
+        let expiry_time = volatility_time(self.reference_date, expiry_date);
+        Some(interpolate_sabr_parameters(parameters, *beta, *shift, expiry_time, tenor))
+    }
+
+    /// strike K 相對 ATM vol 的 smile spread。
+    pub fn smile_spread(&self, expiry_date: NaiveDate, tenor: f64, forward: f64, strike: f64) -> f64 {
+        let expiry_time = volatility_time(self.reference_date, expiry_date);
+        match &self.smile {
+            None => 0.0,
+            Some(SmileModel::Spread(grid)) => {
+                let spreads = grid.interpolate(expiry_time, tenor);
+                let (k0, k1, wk) = interpolation_weight(&self.strike_offsets, strike - forward);
+                (1.0 - wk) * spreads[k0] + wk * spreads[k1]
+            }
+            Some(SmileModel::Sabr { parameters, beta, shift }) => {
+                let parameters = interpolate_sabr_parameters(parameters, *beta, *shift, expiry_time, tenor);
+                parameters.volatility(self.volatility_type, forward, strike, expiry_time)
+                    - parameters.volatility(self.volatility_type, forward, forward, expiry_time)
+            }
+        }
+    }
+}
+
+/// (α, ρ, ν) 格點在 (τ_e, n) 上插值後的 SABR 參數。
+fn interpolate_sabr_parameters(
+    parameters:  &VolatilityGrid,
+    beta:        f64,
+    shift:       f64,
+    expiry_time: f64,
+    tenor:       f64,
+) -> SabrParameters {
+    let values = parameters.interpolate(expiry_time, tenor);
+    SabrParameters::new(values[0], beta, values[1], values[2]).with_shift(shift)
+}
+
+impl InterestRateVolatility for SwaptionVolatilityCube {
+    fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+
+    fn volatility(&self, expiry_date: NaiveDate, tenor: f64, forward: f64, strike: f64) -> f64 {
+        self.atm_volatility(expiry_date, tenor) + self.smile_spread(expiry_date, tenor, forward, strike)
+    }
+}
+
@@ -0,0 +1,384 @@
+// ── capfloorpricer.rs ─────────────────────────────────────────────────────────
+//
+// Cap / floor 以 caplet 逐期加總的 Black（shifted lognormal）/ Bachelier（normal）評價：
+//
+//   V(h) = p Σ_i N τ_i leverage · DF(T_i) / DF(h) · BlackFormula(F_i, t_i).price(ω, K′, σ_i)
+//
+//   F_i — 第 i 期 index 的 forward（leg 的 FixingRateCalculator；已部分實現的複利期間
+//         由 index 混合 past fixings 與 forward curve）
+//   K′  — index 上的 strike（CapFloor::index_strike）
+//   σ_i — vol 來源在 (caplet 到期日, τ_i, F_i, K′) 的 vol
+//
+// # Variance time t_i
+//
+//   TermRate：t_i = (T_fix − h) / 365
+//   後置複利（Lyashenko & Mercurio, 2019）：利率於計息期間內逐日決定，vol 在 [S_i, E_i]
+//   內線性遞減至 0，令 s = max(S_i − h, 0) / 365、e = (E_i − h) / 365、δ = (E_i − S_i) / 365：
+//
+//     t_i = s + (e − s)³ / (3 δ²)
+//
+//   h ≤ S_i 時即 (S_i − h) / 365 + δ / 3；期間內 t_i 隨 h 遞減，h ≥ E_i 時為 0。
+//
+// t_i = 0 的 caplet 已決定，以內含價值計；付款日不晚於 horizon 者列入 past。
+//
+// # Flat vol
+//
+// cap 報價慣例為所有 caplet 共用一個 flat vol：value_with_flat_volatility 與
+// implied_flat_volatility 提供此口徑（CapletVolatilityStripper 的輸入）。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::instrument::instrument::{Instrument, OptionType};
+use crate::instrument::interestrate::capfloor::CapFloor;
+use crate::instrument::leg::legcharacters::LegCharacters;
+use crate::marketdata::marketdataset::MarketDataSet;
+use crate::math::rootsolver::{RootSolver, RootSolverError};
+use crate::model::fx::fxvolatility::volatility_time;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::model::volatility::blackformula::{solve_volatility, BlackFormula, BlackFormulaError, VolatilityType};
+use crate::model::volatility::interestratevolatility::InterestRateVolatility;
+use crate::pricer::pricer::Pricer;
+use crate::pricingcondition::PricingCondition;
+use crate::value::npv::NPV;
+
+
+/// ATM strike 的 scale 權重總和小於此值時，視為沒有未決定的 caplet。
+const MIN_ATM_WEIGHT: f64 = 1e-14;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CapFloorPricerError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum CapFloorPricerError {
+    #[error("discount or forward curve of the cap/floor is missing")]
+    MissingMarketData,
+
+    #[error("all caplets of the cap/floor have been fixed")]
+    Fixed,
+
+    #[error(transparent)]
+    Formula(#[from] BlackFormulaError),
+
+    #[error("flat volatility solve failed: {0}")]
+    Solver(#[from] RootSolverError),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Caplet
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// 單一 caplet 的評價資料（不含 position）。
+#[derive(Debug, Clone)]
+pub struct Caplet {
+    /// 在 leg schedule 中的期數。
+    pub index:         usize,
+    pub expiry_date:   NaiveDate,
+    pub payment_date:  NaiveDate,
+    /// 計息期間的 year fraction τ_i，亦作為 vol 查詢的年限。
+    pub tenor:         f64,
+    /// index 的 forward（已決定時為實現值）。
+    pub forward:       f64,
+    /// index 上的 strike K′。
+    pub strike:        f64,
+    pub variance_time: f64,
+    /// N τ_i leverage · DF(T_i) / DF(h)；已付款時 DF 比值為 1。
+    pub scale:         f64,
+    /// 付款日不晚於 horizon。
+    pub paid:          bool,
+}
+
+impl Caplet {
+    pub fn is_fixed(&self) -> bool {
+        self.variance_time <= 0.0
+    }
+
+    /// 不含 position 的價值；已決定時為內含價值。
+    pub fn value(&self, volatility_type: VolatilityType, option_type: OptionType, volatility: f64) -> f64 {
+        if self.is_fixed() {
+            return self.scale * (option_type.sign() * (self.forward - self.strike)).max(0.0);
+        }
+        let model = BlackFormula::new(volatility_type, self.forward, self.variance_time);
+        self.scale * model.price(option_type, self.strike, volatility)
+    }
+
+    /// 不含 position 的 vega（每 1.00 vol）。
+    pub fn vega(&self, volatility_type: VolatilityType, volatility: f64) -> f64 {
+        if self.is_fixed() {
+            return 0.0;
+        }
+        let model = BlackFormula::new(volatility_type, self.forward, self.variance_time);
+        self.scale * model.vega(self.strike, volatility)
+    }
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CapFloorPricer
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct CapFloorPricer {
+    volatility_name: String,
+}
+
+impl CapFloorPricer {
+    /// `volatility_name`：MarketDataSet 中 InterestRateVolatility 的 key。
+    pub fn new(volatility_name: impl Into<String>) -> Self {
+        Self { volatility_name: volatility_name.into() }
+    }
+
+    pub fn volatility_name(&self) -> &str { &self.volatility_name }
+
+    fn volatility_source<'a>(&self, market_data: &'a MarketDataSet) -> Option<&'a Arc<dyn InterestRateVolatility>> {
+        market_data.volatility().get_interest_rate_volatility(&self.volatility_name)
+    }
+
+    fn discount_curve<'a>(
+        instrument:  &CapFloor,
+        market_data: &'a MarketDataSet,
+    ) -> Option<&'a Arc<dyn InterestRateCurve>> {
+        market_data.get_curve(instrument.profit_and_loss_market().discount_curve_name())
+    }
+
+    fn forward_curve<'a>(
+        instrument:  &CapFloor,
+        market_data: &'a MarketDataSet,
+    ) -> Option<&'a Arc<dyn InterestRateCurve>> {
+        market_data.get_curve(instrument.leg_characters().reference_curve_name()?)
+    }
+
+    fn is_projected(payment_date: NaiveDate, pricing_condition: &PricingCondition) -> bool {
+        let horizon = *pricing_condition.horizon();
+        payment_date > horizon || (payment_date == horizon && *pricing_condition.include_horizon_flow())
+    }
+
+    /// 第 i 期 caplet 自 horizon 起算的 variance time（已決定時為 0）。
+    pub fn caplet_variance_time(instrument: &CapFloor, i: usize, pricing_condition: &PricingCondition) -> f64 {
+        let horizon = *pricing_condition.horizon();
+        if !instrument.is_in_arrears() {
+            let fixing_date = instrument.caplet_expiry_date(i);
+            if fixing_date < horizon || (fixing_date == horizon && !*pricing_condition.estimate_horizon_index()) {
+                return 0.0;
+            }
+            return volatility_time(horizon, fixing_date);
+        }
+
+        let end_time = volatility_time(horizon, instrument.accrual_end_date(i));
+        if end_time <= 0.0 {
+            return 0.0;
+        }
+        let start_time = volatility_time(horizon, instrument.accrual_start_date(i));
+        let accrual_time = end_time - start_time;
+        let remaining_start = start_time.max(0.0);
+        remaining_start + (end_time - remaining_start).powi(3) / (3.0 * accrual_time * accrual_time)
+    }
+
+    /// 納入評價的各期 caplet；曲線不存在時回傳 None。
+    pub fn caplets(
+        instrument:        &CapFloor,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<Vec<Caplet>> {
+        let discount_curve = Self::discount_curve(instrument, market_data)?.to_discount_curve();
+        let forward_curve = Self::forward_curve(instrument, market_data)?;
+        let horizon_discount = discount_curve.discount(*pricing_condition.horizon());
+        let leg_characters = instrument.leg_characters();
+        let nominal_scale = instrument.nominal() * leg_characters.leverage();
+
+        let caplets = instrument
+            .caplet_indices()
+            .map(|i| {
+                let payment_date = instrument.payment_date(i);
+                let paid = !Self::is_projected(payment_date, pricing_condition);
+                let discount = if paid { 1.0 } else { discount_curve.discount(payment_date) / horizon_discount };
+                let tau = leg_characters.taus()[i];
+                Caplet {
+                    index:         i,
+                    expiry_date:   instrument.caplet_expiry_date(i),
+                    payment_date,
+                    tenor:         tau,
+                    forward:       leg_characters.fixing_rate_calculator().fixing(i, forward_curve, pricing_condition),
+                    strike:        instrument.index_strike(),
+                    variance_time: Self::caplet_variance_time(instrument, i, pricing_condition),
+                    scale:         nominal_scale * tau * discount,
+                    paid,
+                }
+            })
+            .collect();
+        Some(caplets)
+    }
+
+    /// ATM strike：尚未決定的 caplets 以 scale 加權的平均 forward，換算回票息利率（leverage、spread）。
+    pub fn atm_strike(
+        instrument:        &CapFloor,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        let caplets = Self::caplets(instrument, market_data, pricing_condition)?;
+        let (weighted_forward, weight) = caplets
+            .iter()
+            .filter(|caplet| !caplet.is_fixed())
+            .fold((0.0, 0.0), |(sum, weight), caplet| (sum + caplet.scale * caplet.forward, weight + caplet.scale));
+        if weight.abs() < MIN_ATM_WEIGHT {
+            return None;
+        }
+        let leg_characters = instrument.leg_characters();
+        Some(leg_characters.leverage() * weighted_forward / weight + leg_characters.spread())
+    }
+
+    /// vol 來源在 caplet 的 (到期日, τ_i, F_i, K′) 的 vol。
+    pub fn caplet_volatility(&self, caplet: &Caplet, market_data: &MarketDataSet) -> Option<f64> {
+        let volatility = self
+            .volatility_source(market_data)?
+            .volatility(caplet.expiry_date, caplet.tenor, caplet.forward, caplet.strike);
+        Some(volatility)
+    }
+
+    /// 回傳 (未付款 caplets 在 horizon 的現值, 已付款 caplets 加總)。
+    fn values_at_horizon(
+        &self,
+        instrument:        &CapFloor,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<(f64, f64)> {
+        let volatility_type = self.volatility_source(market_data)?.volatility_type();
+        let option_type = instrument.cap_floor_type().option_type();
+        let mut value = 0.0;
+        let mut past = 0.0;
+        for caplet in Self::caplets(instrument, market_data, pricing_condition)? {
+            let volatility = self.caplet_volatility(&caplet, market_data)?;
+            let caplet_value = instrument.sign() * caplet.value(volatility_type, option_type, volatility);
+            if caplet.paid {
+                past += caplet_value;
+            } else {
+                value += caplet_value;
+            }
+        }
+        Some((value, past))
+    }
+
+    // ── Greeks（含 position 與名目本金）──────────────────────────────────────
+
+    /// 所有 caplet vol 平行移動的 vega（每 1.00 vol，於 horizon）。
+    pub fn vega(
+        &self,
+        instrument:        &CapFloor,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        let volatility_type = self.volatility_source(market_data)?.volatility_type();
+        let mut vega = 0.0;
+        for caplet in Self::caplets(instrument, market_data, pricing_condition)? {
+            if !caplet.paid {
+                let volatility = self.caplet_volatility(&caplet, market_data)?;
+                vega += caplet.vega(volatility_type, volatility);
+            }
+        }
+        Some(instrument.sign() * vega)
+    }
+
+    // ── Flat vol ─────────────────────────────────────────────────────────────
+
+    /// 所有 caplet 使用同一 flat vol 時，未付款部分在 horizon 的現值（含 position）。
+    pub fn value_with_flat_volatility(
+        instrument:        &CapFloor,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+        volatility_type:   VolatilityType,
+        volatility:        f64,
+    ) -> Option<f64> {
+        let option_type = instrument.cap_floor_type().option_type();
+        let value = Self::caplets(instrument, market_data, pricing_condition)?
+            .iter()
+            .filter(|caplet| !caplet.paid)
+            .map(|caplet| caplet.value(volatility_type, option_type, volatility))
+            .sum::<f64>();
+        Some(instrument.sign() * value)
+    }
+
+    /// 由 market value（與 [`Pricer::market_value`] 同口徑，含 position）反推 flat vol。
+    ///
+    /// # Errors
+    ///
+    /// - discount / forward curve 不存在
+    /// - 所有 caplet 皆已決定
+    /// - 價格低於內含價值或求解失敗
+    pub fn implied_flat_volatility(
+        instrument:        &CapFloor,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+        market_value:      f64,
+        volatility_type:   VolatilityType,
+        solver:            &RootSolver,
+    ) -> Result<f64, CapFloorPricerError> {
+        let caplets: Vec<Caplet> = Self::caplets(instrument, market_data, pricing_condition)
+            .ok_or(CapFloorPricerError::MissingMarketData)?
+            .into_iter()
+            .filter(|caplet| !caplet.paid)
+            .collect();
+        if caplets.iter().all(Caplet::is_fixed) {
+            return Err(CapFloorPricerError::Fixed);
+        }
+        let discount_curve = Self::discount_curve(instrument, market_data)
+            .ok_or(CapFloorPricerError::MissingMarketData)?
+            .to_discount_curve();
+
+        // market value 是 settlement date 的金額，先折回 horizon
+        let settlement_date = instrument.profit_and_loss_market().settlement_date(*pricing_condition.horizon());
+        let price = market_value * discount_curve.discount(settlement_date) / instrument.sign();
+
+        let option_type = instrument.cap_floor_type().option_type();
+        let value = |volatility: f64| {
+            caplets
+                .iter()
+                .map(|caplet| caplet.value(volatility_type, option_type, volatility))
+                .sum::<f64>()
+        };
+        let intrinsic_value = value(0.0);
+        if price.is_nan() || price < intrinsic_value {
+            return Err(BlackFormulaError::PriceOutOfRange { price, lower: intrinsic_value, upper: f64::INFINITY }.into());
+        }
+        Ok(solve_volatility(|volatility| value(volatility) - price, initial_volatility_upper(volatility_type), solver)?)
+    }
+}
+
+/// flat vol / caplet vol 求解的初始上界：lognormal 100%，normal 100bp。
+pub fn initial_volatility_upper(volatility_type: VolatilityType) -> f64 {
+    match volatility_type {
+        VolatilityType::ShiftedLognormal { .. } => 1.0,
+        VolatilityType::Normal                  => 0.01,
+    }
+}
+
+
+impl Pricer<CapFloor, MarketDataSet> for CapFloorPricer {
+    fn market_value(
+        &self,
+        instrument:        &CapFloor,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let (value_at_horizon, _) = self.values_at_horizon(instrument, market_data, pricing_condition)?;
+        let market = instrument.profit_and_loss_market();
+        let discount_curve = market_data.get_curve(market.discount_curve_name())?.to_discount_curve();
+        let settlement_date = market.settlement_date(*pricing_condition.horizon());
+        let npv_value = value_at_horizon / discount_curve.discount(settlement_date);
+        Some(NPV::new(market.settlement_currency().clone(), npv_value, settlement_date))
+    }
+
+    fn econ_profit_and_loss(
+        &self,
+        instrument:        &CapFloor,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let (value_at_horizon, past_cash_proceeds) = self.values_at_horizon(instrument, market_data, pricing_condition)?;
+        let settlement_currency = instrument.profit_and_loss_market().settlement_currency().clone();
+        Some(NPV::new(settlement_currency, value_at_horizon + past_cash_proceeds, *pricing_condition.horizon()))
+    }
+}
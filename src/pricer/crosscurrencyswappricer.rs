@@ -0,0 +1,132 @@
+// ── crosscurrencyswappricer.rs ────────────────────────────────────────────────
+//
+// CrossCurrencySwap 的評價：
+//
+//   1. 由 MarketDataSet 建立 FX market 的 FxForwardCurve（spot + 兩幣別折現曲線）
+//   2. MTM reset 的 FX fixing：
+//        fixing date 已過（或等於 horizon 且不估計 horizon index）→ 歷史 fixing
+//        否則 → F(spot date of fixing date)
+//   3. 各 leg 的 projected flows 以該 leg market 的 discount curve 折現到 horizon
+//      （leg 幣別），再以 horizon 匯率 X₀ 換算成 P&L 幣別後加總
+//
+// market value 與 SimpleInstrumentPricer 一致，除以 P&L discount curve 的 DF(settlement)；
+// econ P&L 另加已發生的 flows（同樣以 X₀ 換算）。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::instrument::instrument::{CurveFunction, Instrument};
+use crate::instrument::interestrate::crosscurrencyswap::{CrossCurrencySwap, CrossCurrencySwapLegSide};
+use crate::market::market::Market;
+use crate::marketdata::marketdataset::MarketDataSet;
+use crate::model::fx::fxforwardcurve::FxForwardCurve;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::pricer::pricer::Pricer;
+use crate::pricingcondition::PricingCondition;
+use crate::value::npv::NPV;
+
+
+pub struct CrossCurrencySwapPricer;
+
+impl CrossCurrencySwapPricer {
+    fn forward_curve<'a>(
+        &self,
+        instrument:  &CrossCurrencySwap,
+        side:        CrossCurrencySwapLegSide,
+        market_data: &'a MarketDataSet,
+    ) -> Option<&'a Arc<dyn InterestRateCurve>> {
+        let curve_function = match side {
+            CrossCurrencySwapLegSide::ReceiveLeg => CurveFunction::ReceiveForward,
+            CrossCurrencySwapLegSide::PayLeg     => CurveFunction::PayForward,
+        };
+        instrument
+            .curve_name_map()
+            .get(&curve_function)
+            .and_then(|curve_name| market_data.get_curve(curve_name))
+    }
+
+    /// 回傳 (projected flows 在 horizon 的現值, 已發生 flows 加總)，皆已換算成 P&L 幣別。
+    fn leg_values(
+        &self,
+        instrument:        &CrossCurrencySwap,
+        side:              CrossCurrencySwapLegSide,
+        market_data:       &MarketDataSet,
+        fx_forward_curve:  &FxForwardCurve,
+        pricing_condition: &PricingCondition,
+    ) -> Option<(f64, f64)> {
+        let horizon = *pricing_condition.horizon();
+        let fx_market = instrument.fx_market();
+        let pair_code = fx_market.currency_pair().code();
+        let fx_fixing = |fixing_date: NaiveDate| {
+            if fixing_date < horizon || (fixing_date == horizon && !*pricing_condition.estimate_horizon_index()) {
+                market_data.fx().fixing(&pair_code, fixing_date)
+            } else {
+                Some(fx_forward_curve.forward(fx_market.settlement_date(fixing_date)))
+            }
+        };
+        let nominals = instrument.leg_nominals(side, &fx_fixing);
+
+        let leg = instrument.leg(side);
+        let forward_curve_opt = self.forward_curve(instrument, side, market_data);
+        let discount_curve = market_data.get_curve(leg.discount_curve_name())?;
+        let projected_flows = instrument.leg_flows(side, true, forward_curve_opt, &nominals, pricing_condition);
+        let past_flows = instrument.leg_flows(side, false, forward_curve_opt, &nominals, pricing_condition);
+
+        let conversion = instrument.conversion_factor(
+            leg.currency(),
+            instrument.profit_and_loss_market().settlement_currency(),
+            fx_forward_curve.horizon_rate(),
+        );
+        Some((
+            projected_flows.npv(discount_curve, Some(horizon)) * conversion,
+            past_flows.sum() * conversion,
+        ))
+    }
+
+    fn values_at_horizon(
+        &self,
+        instrument:        &CrossCurrencySwap,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<(f64, f64)> {
+        let fx_forward_curve = market_data.fx_forward_curve(instrument.fx_market(), *pricing_condition.horizon())?;
+        let (receive_value, receive_past) = self.leg_values(
+            instrument, CrossCurrencySwapLegSide::ReceiveLeg, market_data, &fx_forward_curve, pricing_condition,
+        )?;
+        let (pay_value, pay_past) = self.leg_values(
+            instrument, CrossCurrencySwapLegSide::PayLeg, market_data, &fx_forward_curve, pricing_condition,
+        )?;
+        Some((receive_value + pay_value, receive_past + pay_past))
+    }
+}
+
+impl Pricer<CrossCurrencySwap, MarketDataSet> for CrossCurrencySwapPricer {
+    fn market_value(
+        &self,
+        instrument:        &CrossCurrencySwap,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let (value_at_horizon, _) = self.values_at_horizon(instrument, market_data, pricing_condition)?;
+        let discount_curve = instrument
+            .curve_name_map()
+            .get(&CurveFunction::ProfitAndLossDiscount)
+            .and_then(|curve_name| market_data.get_curve(curve_name))?;
+        let settlement_date = instrument.profit_and_loss_market().settlement_date(*pricing_condition.horizon());
+        let npv_value = value_at_horizon / discount_curve.to_discount_curve().discount(settlement_date);
+        let settlement_currency = instrument.profit_and_loss_market().settlement_currency().clone();
+        Some(NPV::new(settlement_currency, npv_value, settlement_date))
+    }
+
+    fn econ_profit_and_loss(
+        &self,
+        instrument:        &CrossCurrencySwap,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let (value_at_horizon, past_cash_proceeds) = self.values_at_horizon(instrument, market_data, pricing_condition)?;
+        let settlement_currency = instrument.profit_and_loss_market().settlement_currency().clone();
+        Some(NPV::new(settlement_currency, value_at_horizon + past_cash_proceeds, *pricing_condition.horizon()))
+    }
+}
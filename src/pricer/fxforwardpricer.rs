@@ -0,0 +1,277 @@
+// ── fxforwardpricer.rs ────────────────────────────────────────────────────────
+//
+// FxForward / FxSwap / NonDeliverableForward 的評價與 forward analytics：
+//
+//   1. 由 MarketDataSet 建立 FX market 的 FxForwardCurve（spot + 兩幣別折現曲線）
+//   2. 未交割的 flows 以 domestic discount curve 折現到 horizon（幣別 ccy2）：
+//        outright：p (N F(T) − N K) D_d(T) / D_d(h)
+//        NDF     ：p N (R − K) D_d(T) / D_d(h)
+//   3. NDF fixing：
+//        fixing date 已過（或等於 horizon 且不估計 horizon index）→ fixing source 的歷史 fixing
+//        否則 → F(spot date of fixing date)
+//
+// market value 除以 domestic discount curve 的 DF(spot date)，與其他 pricer 一致；
+// econ P&L 另加已交割的 flows（ccy1 部位以 horizon 匯率 X₀ 換算）。
+
+use chrono::NaiveDate;
+
+use crate::instrument::fx::fxforward::{is_projected, FxForward};
+use crate::instrument::fx::fxswap::FxSwap;
+use crate::instrument::fx::nondeliverableforward::NonDeliverableForward;
+use crate::instrument::instrument::Instrument;
+use crate::market::fxmarket::FxMatket;
+use crate::market::market::Market;
+use crate::marketdata::marketdataset::MarketDataSet;
+use crate::math::round::round;
+use crate::model::fx::fxforwardcurve::FxForwardCurve;
+use crate::pricer::pricer::Pricer;
+use crate::pricingcondition::PricingCondition;
+use crate::value::npv::NPV;
+
+
+pub struct FxForwardPricer;
+
+impl FxForwardPricer {
+    // ── Analytics ─────────────────────────────────────────────────────────────
+
+    /// 交割日為 `value_date` 的 outright 匯率（ccy2 per ccy1）。
+    pub fn outright_rate(
+        &self,
+        fx_market:   &FxMatket,
+        value_date:  NaiveDate,
+        market_data: &MarketDataSet,
+        horizon:     NaiveDate,
+    ) -> Option<f64> {
+        let fx_forward_curve = market_data.fx_forward_curve(fx_market, horizon)?;
+        Some(fx_forward_curve.forward(value_date))
+    }
+
+    /// 交割日為 `value_date` 的 forward points：outright − spot（未乘上報價的 point 單位）。
+    pub fn forward_points(
+        &self,
+        fx_market:   &FxMatket,
+        value_date:  NaiveDate,
+        market_data: &MarketDataSet,
+        horizon:     NaiveDate,
+    ) -> Option<f64> {
+        let fx_forward_curve = market_data.fx_forward_curve(fx_market, horizon)?;
+        Some(fx_forward_curve.forward_points(value_date))
+    }
+
+    /// FX swap 的市場 swap points：F(T_far) − F(T_near)。
+    pub fn swap_points(
+        &self,
+        instrument:  &FxSwap,
+        market_data: &MarketDataSet,
+        horizon:     NaiveDate,
+    ) -> Option<f64> {
+        let fx_forward_curve = market_data.fx_forward_curve(instrument.fx_market(), horizon)?;
+        Some(
+            fx_forward_curve.forward(instrument.far().value_date())
+                - fx_forward_curve.forward(instrument.near().value_date()),
+        )
+    }
+
+    /// NDF 的 fixing 匯率：已發生取歷史 fixing，否則以 FX forward 估計。
+    pub fn fixing_rate(
+        &self,
+        instrument:        &NonDeliverableForward,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        if Self::is_fixed(instrument, pricing_condition) {
+            market_data.fx().fixing(instrument.fixing_source(), instrument.fixing_date())
+        } else {
+            let fx_forward_curve = market_data.fx_forward_curve(instrument.fx_market(), *pricing_condition.horizon())?;
+            let fx_market = instrument.fx_market();
+            Some(fx_forward_curve.forward(fx_market.settlement_date(instrument.fixing_date())))
+        }
+    }
+
+    // ── Valuation ─────────────────────────────────────────────────────────────
+
+    fn is_fixed(instrument: &NonDeliverableForward, pricing_condition: &PricingCondition) -> bool {
+        let horizon = *pricing_condition.horizon();
+        let fixing_date = instrument.fixing_date();
+        fixing_date < horizon || (fixing_date == horizon && !*pricing_condition.estimate_horizon_index())
+    }
+
+    /// 交割日 `value_date` 的 ccy2 flow 折現到 horizon 的因子 D_d(T) / D_d(h)。
+    fn domestic_discount_factor(
+        &self,
+        fx_market:         &FxMatket,
+        value_date:        NaiveDate,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        let discount_curve = market_data.get_curve(fx_market.domestic_discount_curve_name())?.to_discount_curve();
+        Some(discount_curve.discount(value_date) / discount_curve.discount(*pricing_condition.horizon()))
+    }
+
+    /// 回傳 (未交割 flows 在 horizon 的現值, 已交割 flows 加總)，皆為 ccy2。
+    pub(crate) fn forward_values(
+        &self,
+        instrument:        &FxForward,
+        market_data:       &MarketDataSet,
+        fx_forward_curve:  &FxForwardCurve,
+        pricing_condition: &PricingCondition,
+    ) -> Option<(f64, f64)> {
+        let pair = instrument.fx_market().currency_pair();
+        let rounded = |flow: f64, digits: u32| {
+            pricing_condition.fixed_flow_rounding_digits(digits).map_or(flow, |d| round(flow, d))
+        };
+        let ccy1_amount = rounded(instrument.sign() * instrument.nominal(), pair.ccy1().digits());
+        let ccy2_amount = rounded(instrument.sign() * instrument.counter_nominal(), pair.ccy2().digits());
+
+        let value_date = instrument.value_date();
+        if is_projected(value_date, pricing_condition) {
+            let discount_factor = self.domestic_discount_factor(
+                instrument.fx_market(), value_date, market_data, pricing_condition,
+            )?;
+            let forward = fx_forward_curve.forward(value_date);
+            Some(((ccy1_amount * forward - ccy2_amount) * discount_factor, 0.0))
+        } else {
+            Some((0.0, ccy1_amount * fx_forward_curve.horizon_rate() - ccy2_amount))
+        }
+    }
+
+    fn swap_values(
+        &self,
+        instrument:        &FxSwap,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<(f64, f64)> {
+        let fx_forward_curve = market_data.fx_forward_curve(instrument.fx_market(), *pricing_condition.horizon())?;
+        let (near_value, near_past) = self.forward_values(
+            instrument.near(), market_data, &fx_forward_curve, pricing_condition,
+        )?;
+        let (far_value, far_past) = self.forward_values(
+            instrument.far(), market_data, &fx_forward_curve, pricing_condition,
+        )?;
+        Some((near_value + far_value, near_past + far_past))
+    }
+
+    fn non_deliverable_forward_values(
+        &self,
+        instrument:        &NonDeliverableForward,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<(f64, f64)> {
+        let fixing_rate = self.fixing_rate(instrument, market_data, pricing_condition)?;
+        let digits = instrument.fx_market().currency_pair().ccy2().digits();
+        let digits_opt = if Self::is_fixed(instrument, pricing_condition) {
+            pricing_condition.fixed_flow_rounding_digits(digits)
+        } else {
+            pricing_condition.floating_flow_rounding_digits(digits)
+        };
+        let amount = instrument.settlement_amount(fixing_rate);
+        let amount = digits_opt.map_or(amount, |d| round(amount, d));
+
+        let value_date = instrument.value_date();
+        if is_projected(value_date, pricing_condition) {
+            let discount_factor = self.domestic_discount_factor(
+                instrument.fx_market(), value_date, market_data, pricing_condition,
+            )?;
+            Some((amount * discount_factor, 0.0))
+        } else {
+            Some((0.0, amount))
+        }
+    }
+
+    fn to_market_value(
+        &self,
+        instrument:        &dyn Instrument,
+        fx_market:         &FxMatket,
+        value_at_horizon:  f64,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let discount_curve = market_data.get_curve(fx_market.domestic_discount_curve_name())?;
+        let settlement_date = instrument.profit_and_loss_market().settlement_date(*pricing_condition.horizon());
+        let npv_value = value_at_horizon / discount_curve.to_discount_curve().discount(settlement_date);
+        let settlement_currency = instrument.profit_and_loss_market().settlement_currency().clone();
+        Some(NPV::new(settlement_currency, npv_value, settlement_date))
+    }
+
+    fn to_econ_profit_and_loss(
+        &self,
+        instrument:        &dyn Instrument,
+        values:            (f64, f64),
+        pricing_condition: &PricingCondition,
+    ) -> NPV {
+        let (value_at_horizon, past_cash_proceeds) = values;
+        let settlement_currency = instrument.profit_and_loss_market().settlement_currency().clone();
+        NPV::new(settlement_currency, value_at_horizon + past_cash_proceeds, *pricing_condition.horizon())
+    }
+}
+
+
+impl Pricer<FxForward, MarketDataSet> for FxForwardPricer {
+    fn market_value(
+        &self,
+        instrument:        &FxForward,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let fx_forward_curve = market_data.fx_forward_curve(instrument.fx_market(), *pricing_condition.horizon())?;
+        let (value_at_horizon, _) = self.forward_values(instrument, market_data, &fx_forward_curve, pricing_condition)?;
+        self.to_market_value(instrument, instrument.fx_market(), value_at_horizon, market_data, pricing_condition)
+    }
+
+    fn econ_profit_and_loss(
+        &self,
+        instrument:        &FxForward,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let fx_forward_curve = market_data.fx_forward_curve(instrument.fx_market(), *pricing_condition.horizon())?;
+        let values = self.forward_values(instrument, market_data, &fx_forward_curve, pricing_condition)?;
+        Some(self.to_econ_profit_and_loss(instrument, values, pricing_condition))
+    }
+}
+
+
+impl Pricer<FxSwap, MarketDataSet> for FxForwardPricer {
+    fn market_value(
+        &self,
+        instrument:        &FxSwap,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let (value_at_horizon, _) = self.swap_values(instrument, market_data, pricing_condition)?;
+        self.to_market_value(instrument, instrument.fx_market(), value_at_horizon, market_data, pricing_condition)
+    }
+
+    fn econ_profit_and_loss(
+        &self,
+        instrument:        &FxSwap,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let values = self.swap_values(instrument, market_data, pricing_condition)?;
+        Some(self.to_econ_profit_and_loss(instrument, values, pricing_condition))
+    }
+}
+
+
+impl Pricer<NonDeliverableForward, MarketDataSet> for FxForwardPricer {
+    fn market_value(
+        &self,
+        instrument:        &NonDeliverableForward,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let (value_at_horizon, _) = self.non_deliverable_forward_values(instrument, market_data, pricing_condition)?;
+        self.to_market_value(instrument, instrument.fx_market(), value_at_horizon, market_data, pricing_condition)
+    }
+
+    fn econ_profit_and_loss(
+        &self,
+        instrument:        &NonDeliverableForward,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let values = self.non_deliverable_forward_values(instrument, market_data, pricing_condition)?;
+        Some(self.to_econ_profit_and_loss(instrument, values, pricing_condition))
+    }
+}
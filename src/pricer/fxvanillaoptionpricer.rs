@@ -0,0 +1,183 @@
+// ── fxvanillaoptionpricer.rs ──────────────────────────────────────────────────
+//
+// FxVanillaOption 的 Garman-Kohlhagen 評價與 greeks：
+//
+//   1. 由 MarketDataSet 建立 FX market 的 FxForwardCurve，取得 F(T_d) 與 spot date s
+//   2. D_d / D_f 取 s 至交割日 T_d 的折現因子，τ = (T_e − horizon) / 365
+//   3. σ 由 MarketDataSet volatility 區塊中該貨幣對的 FxVolatility 依到期日與 strike 查詢
+//   4. V(h) = p N · GarmanKohlhagen::price · D_d(s) / D_d(h)（幣別 ccy2）
+//
+// 到期日已過（或等於 horizon 且不估計 horizon index）時，以 FX market 的歷史 fixing
+// 判斷是否履約；履約後等同以 K 成交的 outright forward（見 FxForwardPricer）。
+//
+// delta 依 FxMatket::delta_convention（短 / 長天期、premium-adjusted、spot / forward）。
+
+use chrono::NaiveDate;
+
+use crate::instrument::fx::fxforward::FxForward;
+use crate::instrument::fx::fxvanillaoption::FxVanillaOption;
+use crate::instrument::instrument::Position;
+use crate::market::market::Market;
+use crate::marketdata::marketdataset::MarketDataSet;
+use crate::model::fx::fxforwardcurve::FxForwardCurve;
+use crate::model::fx::fxvolatility::volatility_time;
+use crate::model::fx::garmankohlhagen::GarmanKohlhagen;
+use crate::pricer::fxforwardpricer::FxForwardPricer;
+use crate::pricer::pricer::Pricer;
+use crate::pricingcondition::PricingCondition;
+use crate::value::npv::NPV;
+
+
+pub struct FxVanillaOptionPricer;
+
+impl FxVanillaOptionPricer {
+    /// 該 option 的 Garman-Kohlhagen 模型（vol 已依 strike 查詢）。
+    ///
+    /// FX forward curve 或該貨幣對的 vol 不存在時回傳 None。
+    pub fn model(
+        &self,
+        instrument:  &FxVanillaOption,
+        market_data: &MarketDataSet,
+        horizon:     NaiveDate,
+    ) -> Option<GarmanKohlhagen> {
+        let fx_forward_curve = market_data.fx_forward_curve(instrument.fx_market(), horizon)?;
+        self.model_with_curve(instrument, market_data, &fx_forward_curve, horizon)
+    }
+
+    fn model_with_curve(
+        &self,
+        instrument:       &FxVanillaOption,
+        market_data:      &MarketDataSet,
+        fx_forward_curve: &FxForwardCurve,
+        horizon:          NaiveDate,
+    ) -> Option<GarmanKohlhagen> {
+        let fx_market = instrument.fx_market();
+        let domestic_curve = market_data.get_curve(fx_market.domestic_discount_curve_name())?.to_discount_curve();
+        let foreign_curve = market_data.get_curve(fx_market.foreign_discount_curve_name())?.to_discount_curve();
+        let spot_date = fx_forward_curve.spot_date();
+        let delivery_date = instrument.delivery_date();
+        let expiry_time = volatility_time(horizon, instrument.expiry_date());
+        let volatility = market_data.volatility().get_fx_volatility(&fx_market.currency_pair().code())?;
+
+        Some(GarmanKohlhagen::new(
+            fx_forward_curve.forward(delivery_date),
+            volatility.volatility(instrument.expiry_date(), instrument.strike()),
+            expiry_time,
+            domestic_curve.discount(delivery_date) / domestic_curve.discount(spot_date),
+            foreign_curve.discount(delivery_date) / foreign_curve.discount(spot_date),
+        ))
+    }
+
+    fn is_expired(instrument: &FxVanillaOption, pricing_condition: &PricingCondition) -> bool {
+        let horizon = *pricing_condition.horizon();
+        let expiry_date = instrument.expiry_date();
+        expiry_date < horizon || (expiry_date == horizon && !*pricing_condition.estimate_horizon_index())
+    }
+
+    /// 回傳 (未交割部分在 horizon 的現值, 已交割 flows 加總)，皆為 ccy2。
+    fn values_at_horizon(
+        &self,
+        instrument:        &FxVanillaOption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<(f64, f64)> {
+        let horizon = *pricing_condition.horizon();
+        let fx_market = instrument.fx_market();
+        let fx_forward_curve = market_data.fx_forward_curve(fx_market, horizon)?;
+
+        if !Self::is_expired(instrument, pricing_condition) {
+            let model = self.model_with_curve(instrument, market_data, &fx_forward_curve, horizon)?;
+            let domestic_curve = market_data.get_curve(fx_market.domestic_discount_curve_name())?.to_discount_curve();
+            let spot_factor = domestic_curve.discount(fx_forward_curve.spot_date()) / domestic_curve.discount(horizon);
+            let value = instrument.sign()
+                * instrument.nominal()
+                * model.price(instrument.option_type(), instrument.strike())
+                * spot_factor;
+            return Some((value, 0.0));
+        }
+
+        // 已到期：依到期日 fixing 決定是否履約
+        let fixing = market_data.fx().fixing(&fx_market.currency_pair().code(), instrument.expiry_date())?;
+        let omega = instrument.option_type().sign();
+        if omega * (fixing - instrument.strike()) <= 0.0 {
+            return Some((0.0, 0.0));
+        }
+        let forward_position = if instrument.sign() * omega > 0.0 { Position::Buy } else { Position::Sell };
+        let exercised = FxForward::new(
+            forward_position,
+            fx_market.clone(),
+            instrument.nominal(),
+            instrument.strike(),
+            instrument.delivery_date(),
+        );
+        FxForwardPricer.forward_values(&exercised, market_data, &fx_forward_curve, pricing_condition)
+    }
+
+    // ── Greeks（含 position 與名目本金）──────────────────────────────────────
+
+    /// 依 FX market delta 慣例的 delta（ccy1 金額）。
+    pub fn delta(
+        &self,
+        instrument:  &FxVanillaOption,
+        market_data: &MarketDataSet,
+        horizon:     NaiveDate,
+    ) -> Option<f64> {
+        let model = self.model(instrument, market_data, horizon)?;
+        let convention = instrument.fx_market().delta_convention(horizon, instrument.expiry_date());
+        Some(self.scale(instrument) * model.delta(instrument.option_type(), instrument.strike(), convention))
+    }
+
+    /// spot gamma（每 1 單位 spot 變動的 ccy1 delta 變動）。
+    pub fn gamma(
+        &self,
+        instrument:  &FxVanillaOption,
+        market_data: &MarketDataSet,
+        horizon:     NaiveDate,
+    ) -> Option<f64> {
+        let model = self.model(instrument, market_data, horizon)?;
+        Some(self.scale(instrument) * model.gamma(instrument.strike()))
+    }
+
+    /// vega（每 1.00 vol，ccy2 於 spot date）。
+    pub fn vega(
+        &self,
+        instrument:  &FxVanillaOption,
+        market_data: &MarketDataSet,
+        horizon:     NaiveDate,
+    ) -> Option<f64> {
+        let model = self.model(instrument, market_data, horizon)?;
+        Some(self.scale(instrument) * model.vega(instrument.strike()))
+    }
+
+    fn scale(&self, instrument: &FxVanillaOption) -> f64 {
+        instrument.sign() * instrument.nominal()
+    }
+}
+
+
+impl Pricer<FxVanillaOption, MarketDataSet> for FxVanillaOptionPricer {
+    fn market_value(
+        &self,
+        instrument:        &FxVanillaOption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let (value_at_horizon, _) = self.values_at_horizon(instrument, market_data, pricing_condition)?;
+        let fx_market = instrument.fx_market();
+        let discount_curve = market_data.get_curve(fx_market.domestic_discount_curve_name())?;
+        let settlement_date = fx_market.settlement_date(*pricing_condition.horizon());
+        let npv_value = value_at_horizon / discount_curve.to_discount_curve().discount(settlement_date);
+        Some(NPV::new(fx_market.settlement_currency().clone(), npv_value, settlement_date))
+    }
+
+    fn econ_profit_and_loss(
+        &self,
+        instrument:        &FxVanillaOption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let (value_at_horizon, past_cash_proceeds) = self.values_at_horizon(instrument, market_data, pricing_condition)?;
+        let settlement_currency = instrument.fx_market().settlement_currency().clone();
+        Some(NPV::new(settlement_currency, value_at_horizon + past_cash_proceeds, *pricing_condition.horizon()))
+    }
+}
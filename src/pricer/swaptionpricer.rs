@@ -0,0 +1,387 @@
+// ── swaptionpricer.rs ─────────────────────────────────────────────────────────
+//
+// Swaption 的 Black（shifted lognormal）/ Bachelier（normal）評價：
+//
+//   1. discount curve 取 underlying 的 P&L market discount curve，forward curve 取
+//      floating leg 的 reference curve；DF 皆相對 horizon
+//   2. annuity A = Σ |N_i| τ_i DF(T_i)（fixed leg 的 year fraction 與付款日）
+//      forward swap rate F = floating leg 現值 / A，effective strike K = fixed leg 現值 / A
+//      （fixed leg 為 Simple compounding 時 K 即為 fixed rate）
+//   3. τ_e = (T_e − horizon) / 365，ω 依 SwaptionType（Payer = call）
+//        Physical     ：V(h) = p · A · BlackFormula::price(ω, K, σ)
+//        CashParYield ：V(h) = p · N · A_cash(F) · BlackFormula::price(ω, K, σ) · DF(T_s)
+//      T_s 為 underlying 起息日（現金交割日）
+//
+// 到期後依到期日 swap rate S 判斷是否履約（ω (S − K) > 0）：
+//   Physical     ：等同持有 underlying，V = p ω (floating leg − fixed leg)，已付款的 flows 列入 past
+//   CashParYield ：於 T_s 收付 p · N · A_cash(S) · ω (S − K)
+//
+// vol 取自 MarketDataSet 中名為 volatility_name 的 InterestRateVolatility（cube 或 flat vol），
+// 以 σ(T_e, n, F, K) 查詢，n 為 underlying 年限；vol 類型亦由該來源決定。
+// implied_volatility 由 market value 反推同類型的 vol。
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::instrument::instrument::Instrument;
+use crate::instrument::interestrate::flowobserver::FlowObserver;
+use crate::instrument::interestrate::swaption::{Swaption, SwaptionSettlement};
+use crate::marketdata::marketdataset::MarketDataSet;
+use crate::math::rootsolver::RootSolver;
+use crate::model::fx::fxvolatility::volatility_time;
+use crate::model::interestrate::interestratecurve::InterestRateCurve;
+use crate::model::volatility::blackformula::{BlackFormula, BlackFormulaError};
+use crate::model::volatility::interestratevolatility::InterestRateVolatility;
+use crate::pricer::pricer::Pricer;
+use crate::pricingcondition::PricingCondition;
+use crate::value::npv::NPV;
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SwaptionPricerError
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Error)]
+pub enum SwaptionPricerError {
+    #[error("discount or forward curve of the underlying swap, or the volatility, is missing")]
+    MissingMarketData,
+
+    #[error("swaption expired on {0}")]
+    Expired(NaiveDate),
+
+    #[error(transparent)]
+    Formula(#[from] BlackFormulaError),
+}
+
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SwaptionPricer
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct SwaptionPricer {
+    volatility_name: String,
+}
+
+impl SwaptionPricer {
+    /// `volatility_name`：MarketDataSet 中 InterestRateVolatility 的 key。
+    pub fn new(volatility_name: impl Into<String>) -> Self {
+        Self { volatility_name: volatility_name.into() }
+    }
+
+    pub fn volatility_name(&self) -> &str { &self.volatility_name }
+
+    fn volatility_source<'a>(&self, market_data: &'a MarketDataSet) -> Option<&'a Arc<dyn InterestRateVolatility>> {
+        market_data.volatility().get_interest_rate_volatility(&self.volatility_name)
+    }
+
+    fn discount_curve<'a>(
+        instrument:  &Swaption,
+        market_data: &'a MarketDataSet,
+    ) -> Option<&'a Arc<dyn InterestRateCurve>> {
+        market_data.get_curve(instrument.profit_and_loss_market().discount_curve_name())
+    }
+
+    fn forward_curve<'a>(
+        instrument:  &Swaption,
+        market_data: &'a MarketDataSet,
+    ) -> Option<&'a Arc<dyn InterestRateCurve>> {
+        market_data.get_curve(instrument.floating_leg_characters().reference_curve_name()?)
+    }
+
+    fn is_expired(instrument: &Swaption, pricing_condition: &PricingCondition) -> bool {
+        let horizon = *pricing_condition.horizon();
+        let expiry_date = instrument.expiry_date();
+        expiry_date < horizon || (expiry_date == horizon && !*pricing_condition.estimate_horizon_index())
+    }
+
+    fn is_projected(payment_date: NaiveDate, pricing_condition: &PricingCondition) -> bool {
+        let horizon = *pricing_condition.horizon();
+        payment_date > horizon || (payment_date == horizon && *pricing_condition.include_horizon_flow())
+    }
+
+    /// 回傳 (未付款 flows 在 horizon 的現值, 已付款 flows 加總)，名目本金取絕對值。
+    fn leg_values(
+        flow_observer_list: &[FlowObserver],
+        forward_curve_opt:  Option<&Arc<dyn InterestRateCurve>>,
+        discount_curve:     &Arc<dyn InterestRateCurve>,
+        pricing_condition:  &PricingCondition,
+    ) -> (f64, f64) {
+        let discount_curve = discount_curve.to_discount_curve();
+        let horizon_discount = discount_curve.discount(*pricing_condition.horizon());
+        let mut projected = 0.0;
+        let mut past = 0.0;
+        for flow_observer in flow_observer_list {
+            let flow = flow_observer.nominal().abs()
+                * flow_observer.ref_leg_characters().evaluate_flow(flow_observer.i(), forward_curve_opt, pricing_condition, None);
+            let payment_date = flow_observer.payment_date();
+            if Self::is_projected(payment_date, pricing_condition) {
+                projected += flow * discount_curve.discount(payment_date) / horizon_discount;
+            } else {
+                past += flow;
+            }
+        }
+        (projected, past)
+    }
+
+    // ── Analytics（不含 position）────────────────────────────────────────────
+
+    /// annuity A = Σ |N_i| τ_i DF(T_i) / DF(h)（僅含未付款的期數）。
+    pub fn annuity(
+        &self,
+        instrument:        &Swaption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        let discount_curve = Self::discount_curve(instrument, market_data)?;
+        let discount_curve = discount_curve.to_discount_curve();
+        let horizon_discount = discount_curve.discount(*pricing_condition.horizon());
+        let generic_characters = instrument.fixed_leg_characters().generic_characters();
+        let schedule_periods = generic_characters.schedule().schedule_periods();
+        let annuity = instrument
+            .fixed_leg_flow_observer_list()
+            .iter()
+            .filter(|flow_observer| Self::is_projected(flow_observer.payment_date(), pricing_condition))
+            .map(|flow_observer| {
+                let calculation_period = schedule_periods[flow_observer.i()].calculation_period();
+                let tau = generic_characters
+                    .day_counter()
+                    .year_fraction(calculation_period.start_date(), calculation_period.end_date());
+                flow_observer.nominal().abs() * tau * discount_curve.discount(flow_observer.payment_date())
+            })
+            .sum::<f64>();
+        Some(annuity / horizon_discount)
+    }
+
+    /// forward swap rate F = floating leg 現值 / A。
+    pub fn forward_swap_rate(
+        &self,
+        instrument:        &Swaption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        let discount_curve = Self::discount_curve(instrument, market_data)?;
+        let forward_curve = Self::forward_curve(instrument, market_data)?;
+        let (floating_value, _) = Self::leg_values(
+            instrument.floating_leg_flow_observer_list(),
+            Some(forward_curve),
+            discount_curve,
+            pricing_condition,
+        );
+        Some(floating_value / self.annuity(instrument, market_data, pricing_condition)?)
+    }
+
+    /// effective strike K = fixed leg 現值 / A。
+    pub fn strike(
+        &self,
+        instrument:        &Swaption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        let discount_curve = Self::discount_curve(instrument, market_data)?;
+        let (fixed_value, _) = Self::leg_values(
+            instrument.fixed_leg_flow_observer_list(),
+            None,
+            discount_curve,
+            pricing_condition,
+        );
+        Some(fixed_value / self.annuity(instrument, market_data, pricing_condition)?)
+    }
+
+    /// 該 swaption 的 BlackFormula（forward = F，τ = horizon 至到期日）。
+    pub fn model(
+        &self,
+        instrument:        &Swaption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<BlackFormula> {
+        let volatility_type = self.volatility_source(market_data)?.volatility_type();
+        let forward = self.forward_swap_rate(instrument, market_data, pricing_condition)?;
+        let expiry_time = volatility_time(*pricing_condition.horizon(), instrument.expiry_date());
+        Some(BlackFormula::new(volatility_type, forward, expiry_time))
+    }
+
+    /// vol 來源在 (到期日, underlying 年限, F, K) 的 vol。
+    pub fn volatility(
+        &self,
+        instrument:        &Swaption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        let forward = self.forward_swap_rate(instrument, market_data, pricing_condition)?;
+        let strike = self.strike(instrument, market_data, pricing_condition)?;
+        let volatility = self
+            .volatility_source(market_data)?
+            .volatility(instrument.expiry_date(), instrument.swap_tenor(), forward, strike);
+        Some(volatility)
+    }
+
+    /// 每單位 BlackFormula 價格對應的 horizon 現值（不含 position）：
+    /// Physical 為 A，CashParYield 為 N · A_cash(F) · DF(T_s) / DF(h)。
+    fn price_scale(
+        &self,
+        instrument:        &Swaption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+        forward:           f64,
+    ) -> Option<f64> {
+        match instrument.settlement() {
+            SwaptionSettlement::Physical => self.annuity(instrument, market_data, pricing_condition),
+            SwaptionSettlement::CashParYield => {
+                let discount_curve = Self::discount_curve(instrument, market_data)?;
+                let discount_curve = discount_curve.to_discount_curve();
+                let settlement_discount = discount_curve.discount(instrument.start_date())
+                    / discount_curve.discount(*pricing_condition.horizon());
+                let nominal = instrument.fixed_leg_flow_observer_list().first()?.nominal().abs();
+                Some(nominal * instrument.cash_annuity(forward) * settlement_discount)
+            }
+        }
+    }
+
+    /// 回傳 (未交割部分在 horizon 的現值, 已交割 flows 加總)。
+    fn values_at_horizon(
+        &self,
+        instrument:        &Swaption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<(f64, f64)> {
+        let option_type = instrument.swaption_type().option_type();
+        if !Self::is_expired(instrument, pricing_condition) {
+            let model = self.model(instrument, market_data, pricing_condition)?;
+            let strike = self.strike(instrument, market_data, pricing_condition)?;
+            let scale = self.price_scale(instrument, market_data, pricing_condition, model.forward())?;
+            let volatility = self.volatility(instrument, market_data, pricing_condition)?;
+            let value = instrument.sign() * scale * model.price(option_type, strike, volatility);
+            return Some((value, 0.0));
+        }
+
+        // 已到期：依到期日 swap rate 決定是否履約
+        let omega = option_type.sign();
+        let expiry_swap_rate = instrument.expiry_swap_rate()?;
+        let strike = self.strike(instrument, market_data, pricing_condition)?;
+        if omega * (expiry_swap_rate - strike) <= 0.0 {
+            return Some((0.0, 0.0));
+        }
+
+        let discount_curve = Self::discount_curve(instrument, market_data)?;
+        let forward_curve = Self::forward_curve(instrument, market_data)?;
+        match instrument.settlement() {
+            SwaptionSettlement::Physical => {
+                let (floating_value, floating_past) = Self::leg_values(
+                    instrument.floating_leg_flow_observer_list(),
+                    Some(forward_curve),
+                    discount_curve,
+                    pricing_condition,
+                );
+                let (fixed_value, fixed_past) = Self::leg_values(
+                    instrument.fixed_leg_flow_observer_list(),
+                    None,
+                    discount_curve,
+                    pricing_condition,
+                );
+                let sign = instrument.sign() * omega;
+                Some((sign * (floating_value - fixed_value), sign * (floating_past - fixed_past)))
+            }
+            SwaptionSettlement::CashParYield => {
+                let nominal = instrument.fixed_leg_flow_observer_list().first()?.nominal().abs();
+                let amount = instrument.sign()
+                    * nominal
+                    * instrument.cash_annuity(expiry_swap_rate)
+                    * omega
+                    * (expiry_swap_rate - strike);
+                let start_date = instrument.start_date();
+                if Self::is_projected(start_date, pricing_condition) {
+                    let discount_curve = discount_curve.to_discount_curve();
+                    let settlement_discount = discount_curve.discount(start_date)
+                        / discount_curve.discount(*pricing_condition.horizon());
+                    Some((amount * settlement_discount, 0.0))
+                } else {
+                    Some((0.0, amount))
+                }
+            }
+        }
+    }
+
+    // ── Greeks（含 position 與名目本金）──────────────────────────────────────
+
+    /// vega（每 1.00 vol，於 horizon）。
+    pub fn vega(
+        &self,
+        instrument:        &Swaption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<f64> {
+        let model = self.model(instrument, market_data, pricing_condition)?;
+        let strike = self.strike(instrument, market_data, pricing_condition)?;
+        let scale = self.price_scale(instrument, market_data, pricing_condition, model.forward())?;
+        let volatility = self.volatility(instrument, market_data, pricing_condition)?;
+        Some(instrument.sign() * scale * model.vega(strike, volatility))
+    }
+
+    /// 由 market value（與 [`Pricer::market_value`] 同口徑，含 position）反推 vol 來源所用類型的 implied vol。
+    ///
+    /// # Errors
+    ///
+    /// - discount / forward curve 或 vol 來源不存在
+    /// - 已到期
+    /// - 價格超出無套利範圍或求解失敗
+    pub fn implied_volatility(
+        &self,
+        instrument:        &Swaption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+        market_value:      f64,
+        solver:            &RootSolver,
+    ) -> Result<f64, SwaptionPricerError> {
+        if Self::is_expired(instrument, pricing_condition) {
+            return Err(SwaptionPricerError::Expired(instrument.expiry_date()));
+        }
+        let model = self
+            .model(instrument, market_data, pricing_condition)
+            .ok_or(SwaptionPricerError::MissingMarketData)?;
+        let strike = self
+            .strike(instrument, market_data, pricing_condition)
+            .ok_or(SwaptionPricerError::MissingMarketData)?;
+        let scale = self
+            .price_scale(instrument, market_data, pricing_condition, model.forward())
+            .ok_or(SwaptionPricerError::MissingMarketData)?;
+        let discount_curve = Self::discount_curve(instrument, market_data).ok_or(SwaptionPricerError::MissingMarketData)?;
+
+        // market value 是 settlement date 的金額，先折回 horizon
+        let discount_curve = discount_curve.to_discount_curve();
+        let settlement_date = instrument.profit_and_loss_market().settlement_date(*pricing_condition.horizon());
+        let value_at_horizon = market_value * discount_curve.discount(settlement_date);
+        let price = value_at_horizon / (instrument.sign() * scale);
+
+        Ok(model.implied_volatility(instrument.swaption_type().option_type(), strike, price, solver)?)
+    }
+}
+
+
+impl Pricer<Swaption, MarketDataSet> for SwaptionPricer {
+    fn market_value(
+        &self,
+        instrument:        &Swaption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let (value_at_horizon, _) = self.values_at_horizon(instrument, market_data, pricing_condition)?;
+        let market = instrument.profit_and_loss_market();
+        let discount_curve = market_data.get_curve(market.discount_curve_name())?.to_discount_curve();
+        let settlement_date = market.settlement_date(*pricing_condition.horizon());
+        let npv_value = value_at_horizon / discount_curve.discount(settlement_date);
+        Some(NPV::new(market.settlement_currency().clone(), npv_value, settlement_date))
+    }
+
+    fn econ_profit_and_loss(
+        &self,
+        instrument:        &Swaption,
+        market_data:       &MarketDataSet,
+        pricing_condition: &PricingCondition,
+    ) -> Option<NPV> {
+        let (value_at_horizon, past_cash_proceeds) = self.values_at_horizon(instrument, market_data, pricing_condition)?;
+        let settlement_currency = instrument.profit_and_loss_market().settlement_currency().clone();
+        Some(NPV::new(settlement_currency, value_at_horizon + past_cash_proceeds, *pricing_condition.horizon()))
+    }
+}
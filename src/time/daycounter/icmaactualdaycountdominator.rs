@@ -1,4 +1,3 @@
-use std::cmp::Ordering;
 use std::sync::Arc;
 
 use chrono::NaiveDate;
@@ -110,31 +109,24 @@ impl DayCounterDominator for ICMAActualDayCounterDominator {
         assert!(start_date >= self.periods[0].start_date());
         assert!(end_date <= self.last_period_end);
 
-        // Binary search 使用 actual end_date（包含 stub 的實際結束日）。
+        // 以 actual end_date 定位（包含 stub 的實際結束日）：
+        // 第一個 end_date 大於查詢日的 period，查詢日恰為 period 結束日時歸下一期。
         // 不再需要延伸超過 maturity 的 quasi-periods。
         let start_idx = self
             .periods
-            .binary_search_by(|p| match start_date.cmp(&p.end_date()) {
-                Ordering::Equal => Ordering::Less,
-                ord => ord,
-            })
-            .unwrap_err();
+            .partition_point(|p| p.end_date() <= start_date);
 
         let end_idx = if end_date < self.last_period_end {
-            self.periods
-                .binary_search_by(|p| match end_date.cmp(&p.end_date()) {
-                    Ordering::Equal => Ordering::Less,
-                    ord => ord,
-                })
-                .unwrap_err()
+            self.periods.partition_point(|p| p.end_date() <= end_date)
         } else {
             self.last_index
         };
 
         if start_idx == end_idx {
-            // 同一個 period 內：actual days / regular period length
+            // 同一個 period 內：actual days / regular period length，再換算成年
             numerator.days_between(start_date, end_date)
                 / self.period_lengths[start_idx]
+                / self.coupon_frequency
         } else {
             let p_start = self.periods[start_idx];
             let start_fraction = numerator.days_between(start_date, p_start.end_date())